@@ -8,7 +8,7 @@ File Name:
 
 Abstract:
 
-    ROM boot status codes.
+    Boot status codes reported by ROM, FMC and runtime.
 
 --*/
 
@@ -19,6 +19,8 @@ const FMCALIAS_BOOT_STATUS_BASE: u32 = 193;
 const COLD_RESET_BOOT_STATUS_BASE: u32 = 257;
 const UPDATE_RESET_BOOT_STATUS_BASE: u32 = 321;
 const ROM_GLOBAL_BOOT_STATUS_BASE: u32 = 385;
+const RTALIAS_BOOT_STATUS_BASE: u32 = 0x400;
+const RUNTIME_BOOT_STATUS_BASE: u32 = 0x600;
 
 /// Statuses used by ROM to log dice derivation progress.
 #[repr(u32)]
@@ -53,6 +55,7 @@ pub enum RomBootStatus {
     FwProcessorLoadImageComplete = FWPROCESSOR_BOOT_STATUS_BASE + 5,
     FwProcessorFirmwareDownloadTxComplete = FWPROCESSOR_BOOT_STATUS_BASE + 6,
     FwProcessorComplete = FWPROCESSOR_BOOT_STATUS_BASE + 7,
+    FwProcessorRecoveryImageRequested = FWPROCESSOR_BOOT_STATUS_BASE + 8,
 
     // FmcAlias Statuses
     FmcAliasDeriveCdiComplete = FMCALIAS_BOOT_STATUS_BASE,
@@ -75,6 +78,7 @@ pub enum RomBootStatus {
     UpdateResetLoadImageComplete = UPDATE_RESET_BOOT_STATUS_BASE + 5,
     UpdateResetOverwriteManifestComplete = UPDATE_RESET_BOOT_STATUS_BASE + 6,
     UpdateResetComplete = UPDATE_RESET_BOOT_STATUS_BASE + 7,
+    UpdateResetRollbackComplete = UPDATE_RESET_BOOT_STATUS_BASE + 8,
 
     // ROM Global Boot Statues
     CfiInitialized = ROM_GLOBAL_BOOT_STATUS_BASE,
@@ -88,3 +92,41 @@ impl From<RomBootStatus> for u32 {
         status as u32
     }
 }
+
+/// Statuses used by FMC to log dice derivation progress.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FmcBootStatus {
+    // RtAlias Statuses
+    RtMeasurementComplete = RTALIAS_BOOT_STATUS_BASE,
+    RtAliasDeriveCdiComplete = RTALIAS_BOOT_STATUS_BASE + 1,
+    RtAliasKeyPairDerivationComplete = RTALIAS_BOOT_STATUS_BASE + 2,
+    RtAliasSubjIdSnGenerationComplete = RTALIAS_BOOT_STATUS_BASE + 3,
+    RtAliasSubjKeyIdGenerationComplete = RTALIAS_BOOT_STATUS_BASE + 4,
+    RtAliasCertSigGenerationComplete = RTALIAS_BOOT_STATUS_BASE + 5,
+    RtAliasDerivationComplete = RTALIAS_BOOT_STATUS_BASE + 6,
+}
+
+impl From<FmcBootStatus> for u32 {
+    /// Converts to this type from the input type.
+    fn from(status: FmcBootStatus) -> u32 {
+        status as u32
+    }
+}
+
+/// Statuses used by runtime to log its progress.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RtBootStatus {
+    // RtAlias Statuses
+    RtReadyForCommands = RUNTIME_BOOT_STATUS_BASE,
+    RtFipSelfTestStarted = RUNTIME_BOOT_STATUS_BASE + 1,
+    RtFipSelfTestComplete = RUNTIME_BOOT_STATUS_BASE + 2,
+}
+
+impl From<RtBootStatus> for u32 {
+    /// Converts to this type from the input type.
+    fn from(status: RtBootStatus) -> u32 {
+        status as u32
+    }
+}
@@ -13,10 +13,43 @@ Abstract:
 --*/
 
 bitflags::bitflags! {
-    #[derive(Default, Copy, Clone, Debug)]
+    #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Capabilities : u128 {
+        // ROM capabilities occupy bits 0-31.
         // Represents base capabilities present in Caliptra ROM v1.0
         const ROM_BASE = 0b0001;
+
+        // FMC capabilities occupy bits 32-63.
+        // Represents base capabilities present in Caliptra FMC v1.0
+        const FMC_BASE = 1 << 32;
+
+        // Runtime capabilities occupy bits 64-127.
+        // Represents base capabilities present in Caliptra Runtime v1.0
+        const RT_BASE = 1 << 64;
+        // DPE is built with the P-256/SHA-256 profile
+        const RT_DPE_P256_SHA256 = 1 << 65;
+        // DPE is built with the P-384/SHA-384 profile
+        const RT_DPE_P384_SHA384 = 1 << 66;
+        // SELF_TEST_START and SELF_TEST_GET_RESULTS are supported
+        const RT_FIPS_SELF_TEST = 1 << 67;
+        // GET_PCR_QUOTE is supported
+        const RT_PCR_QUOTE = 1 << 68;
+        // LMS_VERIFY is supported
+        const RT_LMS_VERIFY = 1 << 69;
+        // SHA_DIGEST is supported
+        const RT_SHA_DIGEST = 1 << 70;
+        // The PAUSER command policy in the image manifest is supported
+        const RT_PAUSER_POLICY = 1 << 71;
+        // Test-only commands are enabled
+        const RT_TEST_ONLY_COMMANDS = 1 << 72;
+        // SET_AUTH_MANIFEST and AUTHORIZE_AND_STASH are supported
+        const RT_AUTH_MANIFEST = 1 << 73;
+        // GET_EVENT_LOG is supported
+        const RT_EVENT_LOG = 1 << 74;
+        // GET_BOOT_TIMELINE is supported
+        const RT_BOOT_TIMELINE = 1 << 75;
+        // SHA_INIT, SHA_UPDATE and SHA_FINAL are supported
+        const RT_SHA_STREAM = 1 << 76;
     }
 }
 
@@ -0,0 +1,517 @@
+// Licensed under the Apache-2.0 license
+
+//! Canonical Event Log (CEL) encoding of Caliptra's PCR measurements.
+//!
+//! `GET_EVENT_LOG` returns every logged PCR extension as a TLV-encoded CEL
+//! record. Each record holds the record number, the PCR index, a SHA-384
+//! digest and a TCG PC Client `EV_EVENT_TAG` event. The tagged event ID is the
+//! Caliptra `PcrLogEntryId` and the tagged event data is the data recorded in
+//! the PCR log.
+//!
+//! Caliptra extends the event data itself into the PCR. Almost every event's
+//! data is a 48-byte digest, which the record also carries as its digest, so
+//! standard CEL tooling replays these as `PCR = SHA384(PCR || digest)`.
+//!
+//! The exceptions are `DeviceStatus` (9 bytes) and `FwLoadFailure` (8 bytes),
+//! which ROM extends into PCR0 and PCR1. A CEL digest can't represent what was
+//! extended for them, so their records carry `SHA384(event data)` instead and
+//! standard tooling can't replay PCR0 and PCR1. [`ReplayedPcrs::replay`]
+//! replays both kinds of record.
+//!
+//! A PCR whose extensions didn't all fit in Caliptra's logs gets an
+//! `EV_NO_ACTION` record, which isn't extended. Its tagged event data is the
+//! number of extensions missing from the log, as a little-endian u32.
+//!
+//! A TLV is a 1-byte type, a 4-byte big-endian length and the value.
+
+use core::mem::size_of;
+
+/// CEL record number, a big-endian u64
+pub const CEL_TYPE_RECNUM: u8 = 0;
+/// CEL PCR index, a u8
+pub const CEL_TYPE_PCR: u8 = 1;
+/// CEL digests, a list of TLVs typed with the TPM_ALG_ID of the digest
+pub const CEL_TYPE_DIGESTS: u8 = 3;
+/// CEL content holding a TCG PC Client event
+pub const CEL_TYPE_PCCLIENT_STD: u8 = 5;
+
+/// PC Client event type, a big-endian u32
+pub const PCCLIENT_STD_TYPE: u8 = 0;
+/// PC Client event data
+pub const PCCLIENT_STD_CONTENT: u8 = 1;
+
+pub const TPM_ALG_SHA384: u8 = 0x0c;
+pub const EV_NO_ACTION: u32 = 0x0000_0003;
+pub const EV_EVENT_TAG: u32 = 0x0000_0006;
+
+pub const SHA384_DIGEST_SIZE: usize = 48;
+pub const PCR_COUNT: usize = 32;
+
+const TLV_HEADER_SIZE: usize = size_of::<u8>() + size_of::<u32>();
+const TAGGED_EVENT_HEADER_SIZE: usize = 2 * size_of::<u32>();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventLogError {
+    /// The log ends in the middle of a record
+    Truncated,
+    /// A record is missing a field or has a field of the wrong size
+    Malformed,
+    /// A record has no SHA-384 digest
+    MissingSha384Digest,
+    /// A record's PCR index is out of range
+    InvalidPcr(u32),
+    /// The digest of the record with this record number doesn't match its
+    /// event data
+    DigestMismatch(u64),
+    /// The replayed value of a PCR doesn't match the quoted value
+    PcrMismatch(u32),
+    /// The log is missing extensions of this PCR, so it can't be checked
+    IncompletePcr(u32),
+}
+
+/// One CEL record of a PCR extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventLogRecord<'a> {
+    pub recnum: u64,
+    pub pcr: u8,
+    /// `event_data` if it is a 48-byte digest, otherwise its SHA-384 digest.
+    /// Zero for `EV_NO_ACTION` records.
+    pub digest: [u8; SHA384_DIGEST_SIZE],
+    /// `EV_EVENT_TAG` or `EV_NO_ACTION`
+    pub event_type: u32,
+    /// Tagged event ID
+    pub event_id: u32,
+    /// Tagged event data
+    pub event_data: &'a [u8],
+}
+
+impl<'a> EventLogRecord<'a> {
+    pub const MAX_EVENT_DATA_SIZE: usize = 48;
+    pub const MAX_SIZE: usize = Self::size(Self::MAX_EVENT_DATA_SIZE);
+
+    const fn size(event_data_size: usize) -> usize {
+        // recnum
+        TLV_HEADER_SIZE + size_of::<u64>()
+        // pcr
+        + TLV_HEADER_SIZE + size_of::<u8>()
+        // digests
+        + TLV_HEADER_SIZE + TLV_HEADER_SIZE + SHA384_DIGEST_SIZE
+        // content, event type and event data
+        + TLV_HEADER_SIZE
+        + TLV_HEADER_SIZE + size_of::<u32>()
+        + TLV_HEADER_SIZE + TAGGED_EVENT_HEADER_SIZE + event_data_size
+    }
+
+    /// Encode the record into the start of `buf`, returning the encoded size.
+    /// Returns None if `buf` is too small or the event data is too large.
+    pub fn encode(&self, buf: &mut [u8]) -> Option<usize> {
+        if self.event_data.len() > Self::MAX_EVENT_DATA_SIZE {
+            return None;
+        }
+        let size = Self::size(self.event_data.len());
+        let mut w = Writer {
+            buf: buf.get_mut(..size)?,
+            pos: 0,
+        };
+
+        w.tlv_header(CEL_TYPE_RECNUM, size_of::<u64>());
+        w.bytes(&self.recnum.to_be_bytes());
+
+        w.tlv_header(CEL_TYPE_PCR, size_of::<u8>());
+        w.bytes(&[self.pcr]);
+
+        w.tlv_header(CEL_TYPE_DIGESTS, TLV_HEADER_SIZE + SHA384_DIGEST_SIZE);
+        w.tlv_header(TPM_ALG_SHA384, SHA384_DIGEST_SIZE);
+        w.bytes(&self.digest);
+
+        let event_size = TAGGED_EVENT_HEADER_SIZE + self.event_data.len();
+        w.tlv_header(
+            CEL_TYPE_PCCLIENT_STD,
+            2 * TLV_HEADER_SIZE + size_of::<u32>() + event_size,
+        );
+        w.tlv_header(PCCLIENT_STD_TYPE, size_of::<u32>());
+        w.bytes(&self.event_type.to_be_bytes());
+        w.tlv_header(PCCLIENT_STD_CONTENT, event_size);
+        // TCG_PCClientTaggedEvent
+        w.bytes(&self.event_id.to_le_bytes());
+        w.bytes(&(self.event_data.len() as u32).to_le_bytes());
+        w.bytes(self.event_data);
+
+        Some(size)
+    }
+
+    /// Decode the record at the start of `bytes`, returning it and the bytes
+    /// that follow it.
+    pub fn decode(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), EventLogError> {
+        let (recnum, rest) = read_tlv(bytes, CEL_TYPE_RECNUM)?;
+        let recnum = u64::from_be_bytes(recnum.try_into().map_err(|_| EventLogError::Malformed)?);
+
+        let (pcr, rest) = read_tlv(rest, CEL_TYPE_PCR)?;
+        let pcr = match pcr {
+            [pcr] => *pcr as u32,
+            [a, b, c, d] => u32::from_be_bytes([*a, *b, *c, *d]),
+            _ => return Err(EventLogError::Malformed),
+        };
+        if pcr as usize >= PCR_COUNT {
+            return Err(EventLogError::InvalidPcr(pcr));
+        }
+
+        let (mut digests, rest) = read_tlv(rest, CEL_TYPE_DIGESTS)?;
+        let mut digest = None;
+        while !digests.is_empty() {
+            let (ty, value, next) = read_any_tlv(digests)?;
+            if ty == TPM_ALG_SHA384 {
+                digest = Some(value.try_into().map_err(|_| EventLogError::Malformed)?);
+            }
+            digests = next;
+        }
+        let digest = digest.ok_or(EventLogError::MissingSha384Digest)?;
+
+        let (content, rest) = read_tlv(rest, CEL_TYPE_PCCLIENT_STD)?;
+        let (event_type, content) = read_tlv(content, PCCLIENT_STD_TYPE)?;
+        let (event, _) = read_tlv(content, PCCLIENT_STD_CONTENT)?;
+        let event_type = u32::from_be_bytes(
+            event_type
+                .try_into()
+                .map_err(|_| EventLogError::Malformed)?,
+        );
+        if event_type != EV_EVENT_TAG && event_type != EV_NO_ACTION {
+            return Err(EventLogError::Malformed);
+        }
+        let (event_id, event) = split_u32_le(event)?;
+        let (event_data_size, event_data) = split_u32_le(event)?;
+        let event_data = event_data
+            .get(..event_data_size as usize)
+            .ok_or(EventLogError::Malformed)?;
+        if event_data.len() > Self::MAX_EVENT_DATA_SIZE {
+            return Err(EventLogError::Malformed);
+        }
+
+        Ok((
+            Self {
+                recnum,
+                pcr: pcr as u8,
+                digest,
+                event_type,
+                event_id,
+                event_data,
+            },
+            rest,
+        ))
+    }
+}
+
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+impl Writer<'_> {
+    fn bytes(&mut self, data: &[u8]) {
+        self.buf[self.pos..self.pos + data.len()].copy_from_slice(data);
+        self.pos += data.len();
+    }
+    fn tlv_header(&mut self, ty: u8, len: usize) {
+        self.bytes(&[ty]);
+        self.bytes(&(len as u32).to_be_bytes());
+    }
+}
+
+fn read_any_tlv(bytes: &[u8]) -> Result<(u8, &[u8], &[u8]), EventLogError> {
+    if bytes.len() < TLV_HEADER_SIZE {
+        return Err(EventLogError::Truncated);
+    }
+    let (header, rest) = bytes.split_at(TLV_HEADER_SIZE);
+    let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+    if rest.len() < len {
+        return Err(EventLogError::Truncated);
+    }
+    let (value, rest) = rest.split_at(len);
+    Ok((header[0], value, rest))
+}
+
+fn read_tlv(bytes: &[u8], expected_type: u8) -> Result<(&[u8], &[u8]), EventLogError> {
+    let (ty, value, rest) = read_any_tlv(bytes)?;
+    if ty != expected_type {
+        return Err(EventLogError::Malformed);
+    }
+    Ok((value, rest))
+}
+
+fn split_u32_le(bytes: &[u8]) -> Result<(u32, &[u8]), EventLogError> {
+    if bytes.len() < size_of::<u32>() {
+        return Err(EventLogError::Malformed);
+    }
+    let (value, rest) = bytes.split_at(size_of::<u32>());
+    Ok((
+        u32::from_le_bytes([value[0], value[1], value[2], value[3]]),
+        rest,
+    ))
+}
+
+/// Iterator over the records of an encoded event log.
+pub struct EventLogIter<'a> {
+    rest: &'a [u8],
+}
+impl<'a> EventLogIter<'a> {
+    pub fn new(log: &'a [u8]) -> Self {
+        Self { rest: log }
+    }
+}
+impl<'a> Iterator for EventLogIter<'a> {
+    type Item = Result<EventLogRecord<'a>, EventLogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        match EventLogRecord::decode(self.rest) {
+            Ok((record, rest)) => {
+                self.rest = rest;
+                Some(Ok(record))
+            }
+            Err(err) => {
+                self.rest = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// PCR values computed by replaying an event log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayedPcrs {
+    pub pcrs: [[u8; SHA384_DIGEST_SIZE]; PCR_COUNT],
+    /// Bit N is set if the log holds at least one record for PCR N
+    pub logged_pcrs: u32,
+    /// Bit N is set if the log is missing extensions of PCR N
+    pub incomplete_pcrs: u32,
+}
+
+impl ReplayedPcrs {
+    /// Replay `log` starting from all-zero PCRs, checking the digest of
+    /// every record. `sha384` must return the SHA-384 digest of its input.
+    pub fn replay(
+        log: &[u8],
+        mut sha384: impl FnMut(&[u8]) -> [u8; SHA384_DIGEST_SIZE],
+    ) -> Result<Self, EventLogError> {
+        let mut result = Self {
+            pcrs: [[0u8; SHA384_DIGEST_SIZE]; PCR_COUNT],
+            logged_pcrs: 0,
+            incomplete_pcrs: 0,
+        };
+        let mut buf = [0u8; SHA384_DIGEST_SIZE + EventLogRecord::MAX_EVENT_DATA_SIZE];
+        for record in EventLogIter::new(log) {
+            let record = record?;
+            result.logged_pcrs |= 1 << record.pcr;
+            if record.event_type == EV_NO_ACTION {
+                result.incomplete_pcrs |= 1 << record.pcr;
+                continue;
+            }
+
+            let digest_matches = if record.event_data.len() == SHA384_DIGEST_SIZE {
+                record.event_data == record.digest
+            } else {
+                sha384(record.event_data) == record.digest
+            };
+            if !digest_matches {
+                return Err(EventLogError::DigestMismatch(record.recnum));
+            }
+
+            // Both kinds of event extend the event data, which for digest
+            // sized events is also the record's digest.
+            let extended = record.event_data;
+
+            let pcr = &mut result.pcrs[record.pcr as usize];
+            let len = SHA384_DIGEST_SIZE + extended.len();
+            buf[..SHA384_DIGEST_SIZE].copy_from_slice(pcr);
+            buf[SHA384_DIGEST_SIZE..len].copy_from_slice(extended);
+            *pcr = sha384(&buf[..len]);
+        }
+        Ok(result)
+    }
+
+    /// Check every PCR that appears in the log against `quoted_pcrs`, the
+    /// PCR values of a `GET_PCR_QUOTE` response. PCRs whose log is
+    /// incomplete can't be checked and fail with `IncompletePcr`.
+    pub fn check_quote(
+        &self,
+        quoted_pcrs: &[[u8; SHA384_DIGEST_SIZE]; PCR_COUNT],
+    ) -> Result<(), EventLogError> {
+        for (i, (replayed, quoted)) in self.pcrs.iter().zip(quoted_pcrs).enumerate() {
+            if self.incomplete_pcrs & (1 << i) != 0 {
+                return Err(EventLogError::IncompletePcr(i as u32));
+            }
+            if self.logged_pcrs & (1 << i) != 0 && replayed != quoted {
+                return Err(EventLogError::PcrMismatch(i as u32));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Stand-in for SHA-384; the replay doesn't depend on the hash function.
+    fn toy_hash(data: &[u8]) -> [u8; 48] {
+        let mut result = [data.len() as u8; 48];
+        for (i, b) in data.iter().enumerate() {
+            result[i % 48] = result[i % 48].wrapping_mul(31) ^ b;
+        }
+        result
+    }
+
+    fn toy_extend(pcr: &[u8; 48], data: &[u8]) -> [u8; 48] {
+        toy_hash(&[pcr.as_slice(), data].concat())
+    }
+
+    fn event_record(recnum: u64, pcr: u8, event_data: &[u8]) -> EventLogRecord<'_> {
+        EventLogRecord {
+            recnum,
+            pcr,
+            digest: event_data
+                .try_into()
+                .unwrap_or_else(|_| toy_hash(event_data)),
+            event_type: EV_EVENT_TAG,
+            event_id: 4,
+            event_data,
+        }
+    }
+
+    fn encode_log(records: &[EventLogRecord]) -> Vec<u8> {
+        let mut log = vec![];
+        for record in records {
+            let mut buf = [0u8; EventLogRecord::MAX_SIZE];
+            let len = record.encode(&mut buf).unwrap();
+            log.extend_from_slice(&buf[..len]);
+        }
+        log
+    }
+
+    #[test]
+    fn test_record_roundtrip() {
+        let records = [
+            EventLogRecord {
+                recnum: 0,
+                pcr: 0,
+                digest: [0x11; 48],
+                event_type: EV_EVENT_TAG,
+                event_id: 1,
+                event_data: &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            },
+            EventLogRecord {
+                recnum: 1,
+                pcr: 31,
+                digest: [0; 48],
+                event_type: EV_NO_ACTION,
+                event_id: 5,
+                event_data: &[1, 0, 0, 0],
+            },
+        ];
+
+        let log = encode_log(&records);
+        let decoded: Vec<_> = EventLogIter::new(&log).map(|r| r.unwrap()).collect();
+        assert_eq!(decoded, records);
+
+        assert_eq!(
+            EventLogIter::new(&log[..log.len() - 1]).last(),
+            Some(Err(EventLogError::Truncated))
+        );
+    }
+
+    #[test]
+    fn test_record_encoding() {
+        let record = EventLogRecord {
+            recnum: 2,
+            pcr: 3,
+            digest: [0xab; 48],
+            event_type: EV_EVENT_TAG,
+            event_id: 6,
+            event_data: &[0xcd; 4],
+        };
+        let mut buf = [0u8; EventLogRecord::MAX_SIZE];
+        let len = record.encode(&mut buf).unwrap();
+        assert_eq!(len, EventLogRecord::MAX_SIZE - 44);
+
+        let mut expected = vec![0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 2];
+        expected.extend_from_slice(&[1, 0, 0, 0, 1, 3]);
+        expected.extend_from_slice(&[3, 0, 0, 0, 53, 0x0c, 0, 0, 0, 48]);
+        expected.extend_from_slice(&[0xab; 48]);
+        expected.extend_from_slice(&[5, 0, 0, 0, 26]);
+        expected.extend_from_slice(&[0, 0, 0, 0, 4, 0, 0, 0, 6]);
+        expected.extend_from_slice(&[1, 0, 0, 0, 12, 6, 0, 0, 0, 4, 0, 0, 0]);
+        expected.extend_from_slice(&[0xcd; 4]);
+        assert_eq!(&buf[..len], expected);
+
+        assert_eq!(record.encode(&mut buf[..len - 1]), None);
+    }
+
+    #[test]
+    fn test_replay() {
+        let log = encode_log(&[
+            event_record(0, 0, &[1, 2, 3]),
+            event_record(1, 1, &[4; 48]),
+            event_record(2, 0, &[5; 48]),
+        ]);
+
+        let replayed = ReplayedPcrs::replay(&log, toy_hash).unwrap();
+        assert_eq!(replayed.logged_pcrs, 0b11);
+        assert_eq!(replayed.incomplete_pcrs, 0);
+
+        let mut quoted = [[0u8; 48]; PCR_COUNT];
+        quoted[0] = toy_extend(&toy_extend(&[0; 48], &[1, 2, 3]), &[5; 48]);
+        quoted[1] = toy_extend(&[0; 48], &[4; 48]);
+        // PCRs without records are not checked
+        quoted[2] = [0x5a; 48];
+        assert_eq!(replayed.check_quote(&quoted), Ok(()));
+
+        quoted[1][0] ^= 1;
+        assert_eq!(
+            replayed.check_quote(&quoted),
+            Err(EventLogError::PcrMismatch(1))
+        );
+    }
+
+    #[test]
+    fn test_replay_digest_mismatch() {
+        for event_data in [&[1, 2, 3][..], &[1; 48]] {
+            let log = encode_log(&[EventLogRecord {
+                digest: [0; 48],
+                ..event_record(7, 0, event_data)
+            }]);
+            assert_eq!(
+                ReplayedPcrs::replay(&log, toy_hash),
+                Err(EventLogError::DigestMismatch(7))
+            );
+        }
+    }
+
+    #[test]
+    fn test_replay_incomplete_pcr() {
+        let log = encode_log(&[
+            event_record(0, 2, &[1; 48]),
+            event_record(1, 31, &[2; 48]),
+            EventLogRecord {
+                digest: [0; 48],
+                event_type: EV_NO_ACTION,
+                event_data: &[1, 0, 0, 0],
+                ..event_record(2, 31, &[])
+            },
+        ]);
+
+        let replayed = ReplayedPcrs::replay(&log, toy_hash).unwrap();
+        assert_eq!(replayed.logged_pcrs, (1 << 31) | (1 << 2));
+        assert_eq!(replayed.incomplete_pcrs, 1 << 31);
+
+        // The EV_NO_ACTION record isn't extended.
+        let mut quoted = [[0u8; 48]; PCR_COUNT];
+        quoted[2] = toy_extend(&[0; 48], &[1; 48]);
+        quoted[31] = toy_extend(&[0; 48], &[2; 48]);
+        assert_eq!(replayed.pcrs, quoted);
+        assert_eq!(
+            replayed.check_quote(&quoted),
+            Err(EventLogError::IncompletePcr(31))
+        );
+    }
+}
@@ -2,10 +2,15 @@
 
 #![cfg_attr(not(test), no_std)]
 
+pub mod boot_status;
 mod capabilities;
 mod checksum;
+pub mod event_log;
 pub mod mailbox;
+pub mod pcr_log;
+mod permissions;
 
 pub use caliptra_error as error;
 pub use capabilities::Capabilities;
 pub use checksum::{calc_checksum, verify_checksum};
+pub use permissions::CommandPermissions;
@@ -1,5 +1,6 @@
 // Licensed under the Apache-2.0 license
 
+use crate::pcr_log::PcrLogEntryId;
 use caliptra_error::{CaliptraError, CaliptraResult};
 use core::mem::size_of;
 use zerocopy::{AsBytes, FromBytes, LayoutVerified};
@@ -14,13 +15,28 @@ impl CommandId {
     pub const POPULATE_IDEV_CERT: Self = Self(0x49444550); // "IDEP"
     pub const GET_LDEV_CERT: Self = Self(0x4C444556); // "LDEV"
     pub const ECDSA384_VERIFY: Self = Self(0x53494756); // "SIGV"
+    pub const LMS_VERIFY: Self = Self(0x4C4D_5356); // "LMSV"
     pub const STASH_MEASUREMENT: Self = Self(0x4D454153); // "MEAS"
     pub const INVOKE_DPE: Self = Self(0x44504543); // "DPEC"
     pub const DISABLE_ATTESTATION: Self = Self(0x4453424C); // "DSBL"
     pub const FW_INFO: Self = Self(0x494E464F); // "INFO"
+    pub const GET_PCR_QUOTE: Self = Self(0x5043_5251); // "PCRQ"
+    pub const EXTEND_PCR: Self = Self(0x5043_5245); // "PCRE"
+    pub const GET_PCR_LOG: Self = Self(0x504C_4F47); // "PLOG"
+    pub const GET_FUSE_LOG: Self = Self(0x464C_4F47); // "FLOG"
+    pub const GET_MEASUREMENT_LOG: Self = Self(0x4D4C_4F47); // "MLOG"
+    pub const GET_EVENT_LOG: Self = Self(0x454C_4F47); // "ELOG"
+    pub const GET_BOOT_TIMELINE: Self = Self(0x4254_4C4E); // "BTLN"
+    pub const GET_FMC_ALIAS_CERT: Self = Self(0x464D_4341); // "FMCA"
+    pub const GET_RT_ALIAS_CERT: Self = Self(0x5254_4143); // "RTAC"
+    pub const GET_CERT_CHAIN: Self = Self(0x4343_484E); // "CCHN"
+    pub const SHA_DIGEST: Self = Self(0x5348_4144); // "SHAD"
+    pub const SHA_INIT: Self = Self(0x5348_4149); // "SHAI"
+    pub const SHA_UPDATE: Self = Self(0x5348_4155); // "SHAU"
+    pub const SHA_FINAL: Self = Self(0x5348_4146); // "SHAF"
+    pub const SET_AUTH_MANIFEST: Self = Self(0x4154_4D4E); // "ATMN"
+    pub const AUTHORIZE_AND_STASH: Self = Self(0x4154_5348); // "ATSH"
 
-    // TODO: Remove this and merge with GET_LDEV_CERT once that is implemented
-    pub const TEST_ONLY_GET_LDEV_CERT: Self = Self(0x4345524c); // "CERL"
     pub const TEST_ONLY_GET_FMC_ALIAS_CERT: Self = Self(0x43455246); // "CERF"
     pub const TEST_ONLY_HMAC384_VERIFY: Self = Self(0x484D4143); // "HMAC"
 
@@ -55,6 +71,14 @@ impl From<CommandId> for u32 {
 pub trait Request: AsBytes + FromBytes {
     const ID: CommandId;
     type Resp: Response;
+
+    /// For requests whose response is sent as `ChunkedResp` chunks, the byte
+    /// offset into the payload of the first byte to return. `Resp` is then
+    /// the reassembled response: the header of the last chunk, followed by
+    /// the payload size and the payload.
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        None
+    }
 }
 
 pub trait Response: AsBytes + FromBytes
@@ -111,6 +135,118 @@ impl<T: ResponseVarSize> Response for T {
     const MIN_SIZE: usize = size_of::<MailboxRespHeaderVarSize>();
 }
 
+/// One chunk of a payload that is too large for a single mailbox response.
+/// The caller reads the payload by issuing the request repeatedly, advancing
+/// the offset (see `Request::chunk_offset_mut`) by the size of each returned
+/// chunk until a chunk without `ChunkedResp::FLAG_MORE` is returned.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ChunkedResp {
+    pub hdr: MailboxRespHeader,
+    /// Size in bytes of the whole payload
+    pub total_size: u32,
+    /// Offset in bytes of this chunk into the payload
+    pub offset: u32,
+    /// Bit 0: More chunks follow this one
+    pub flags: u32,
+    /// Checksum over the whole payload, computed as `calc_checksum(0, payload)`
+    pub payload_chksum: u32,
+    pub data_size: u32,
+    pub data: [u8; ChunkedResp::DATA_MAX_SIZE], // variable length
+}
+impl ChunkedResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+    pub const FLAG_MORE: u32 = 1 << 0;
+    const HEADER_SIZE: usize = size_of::<ChunkedResp>() - ChunkedResp::DATA_MAX_SIZE;
+
+    /// Build the chunk of `payload` that starts at `offset`.
+    pub fn from_payload(payload: &[u8], offset: u32) -> CaliptraResult<Self> {
+        let mut resp = Self::new_streamed(offset);
+        resp.append_payload(payload);
+        resp.finish_payload()
+    }
+
+    /// Start building the chunk that starts at `offset` of a payload that is
+    /// generated piece by piece with `append_payload()`, for payloads that
+    /// are never held in memory as a whole.
+    pub fn new_streamed(offset: u32) -> Self {
+        Self {
+            offset,
+            ..Default::default()
+        }
+    }
+
+    /// Append `bytes` to the payload, keeping the part that falls within
+    /// this chunk.
+    pub fn append_payload(&mut self, bytes: &[u8]) {
+        let start = self.total_size as usize;
+        let chunk_start = self.offset as usize;
+        let chunk_end = chunk_start + Self::DATA_MAX_SIZE;
+
+        let copy_start = start.max(chunk_start);
+        let copy_end = (start + bytes.len()).min(chunk_end);
+        if copy_start < copy_end {
+            let dst = copy_start - chunk_start..copy_end - chunk_start;
+            self.data[dst].copy_from_slice(&bytes[copy_start - start..copy_end - start]);
+            self.data_size = (copy_end - chunk_start) as u32;
+        }
+
+        self.total_size += bytes.len() as u32;
+        self.payload_chksum = self
+            .payload_chksum
+            .wrapping_add(crate::checksum::calc_checksum(0, bytes));
+    }
+
+    /// Finish the chunk once the whole payload has been appended.
+    pub fn finish_payload(mut self) -> CaliptraResult<Self> {
+        if self.offset > self.total_size {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+        if self.offset + self.data_size < self.total_size {
+            self.flags |= Self::FLAG_MORE;
+        }
+        Ok(self)
+    }
+
+    pub fn data(&self) -> CaliptraResult<&[u8]> {
+        self.data
+            .get(..self.data_size as usize)
+            .ok_or(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE)
+    }
+
+    fn partial_len(&self) -> CaliptraResult<usize> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        Ok(Self::HEADER_SIZE + self.data_size as usize)
+    }
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        Ok(&self.as_bytes()[..self.partial_len()?])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        let partial_len = self.partial_len()?;
+        Ok(&mut self.as_bytes_mut()[..partial_len])
+    }
+}
+impl Response for ChunkedResp {
+    const MIN_SIZE: usize = ChunkedResp::HEADER_SIZE;
+}
+impl Default for ChunkedResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            total_size: 0,
+            offset: 0,
+            flags: 0,
+            payload_chksum: 0,
+            data_size: 0,
+            data: [0u8; ChunkedResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
 fn populate_checksum(msg: &mut [u8]) {
     let (checksum_bytes, payload_bytes) = msg.split_at_mut(size_of::<u32>());
     let checksum = crate::checksum::calc_checksum(0, payload_bytes);
@@ -132,6 +268,13 @@ pub enum MailboxResp {
     FipsVersion(FipsVersionResp),
     FwInfo(FwInfoResp),
     Capabilities(CapabilitiesResp),
+    GetPcrQuote(GetPcrQuoteResp),
+    SelfTestGetResults(SelfTestGetResultsResp),
+    GetFmcAliasCert(GetFmcAliasCertResp),
+    GetRtAliasCert(GetRtAliasCertResp),
+    Chunked(ChunkedResp),
+    ShaDigest(ShaDigestResp),
+    AuthorizeAndStash(AuthorizeAndStashResp),
 }
 
 impl MailboxResp {
@@ -148,6 +291,13 @@ impl MailboxResp {
             MailboxResp::FipsVersion(resp) => Ok(resp.as_bytes()),
             MailboxResp::FwInfo(resp) => Ok(resp.as_bytes()),
             MailboxResp::Capabilities(resp) => Ok(resp.as_bytes()),
+            MailboxResp::GetPcrQuote(resp) => Ok(resp.as_bytes()),
+            MailboxResp::SelfTestGetResults(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetFmcAliasCert(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetRtAliasCert(resp) => resp.as_bytes_partial(),
+            MailboxResp::Chunked(resp) => resp.as_bytes_partial(),
+            MailboxResp::ShaDigest(resp) => resp.as_bytes_partial(),
+            MailboxResp::AuthorizeAndStash(resp) => Ok(resp.as_bytes()),
         }
     }
 
@@ -164,6 +314,13 @@ impl MailboxResp {
             MailboxResp::FipsVersion(resp) => Ok(resp.as_bytes_mut()),
             MailboxResp::FwInfo(resp) => Ok(resp.as_bytes_mut()),
             MailboxResp::Capabilities(resp) => Ok(resp.as_bytes_mut()),
+            MailboxResp::GetPcrQuote(resp) => Ok(resp.as_bytes_mut()),
+            MailboxResp::SelfTestGetResults(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetFmcAliasCert(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetRtAliasCert(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::Chunked(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::ShaDigest(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::AuthorizeAndStash(resp) => Ok(resp.as_bytes_mut()),
         }
     }
 
@@ -205,14 +362,34 @@ impl Default for MailboxResp {
 #[allow(clippy::large_enum_variant)]
 pub enum MailboxReq {
     EcdsaVerify(EcdsaVerifyReq),
+    LmsVerify(LmsVerifyReq),
     GetIdevCsr(MailboxReqHeader),
     GetLdevCert(MailboxReqHeader),
     StashMeasurement(StashMeasurementReq),
     InvokeDpeCommand(InvokeDpeReq),
     FipsVersion(MailboxReqHeader),
+    SelfTestStart(SelfTestStartReq),
+    SelfTestGetResults(SelfTestGetResultsReq),
     FwInfo(MailboxReqHeader),
     PopulateIdevCert(PopulateIdevCertReq),
     GetIdevCert(GetIdevCertReq),
+    GetPcrQuote(GetPcrQuoteReq),
+    ExtendPcr(ExtendPcrReq),
+    GetPcrLog(GetPcrLogReq),
+    GetFuseLog(GetFuseLogReq),
+    GetMeasurementLog(GetMeasurementLogReq),
+    GetEventLog(GetEventLogReq),
+    GetBootTimeline(GetBootTimelineReq),
+    GetFmcAliasCert(GetFmcAliasCertReq),
+    GetRtAliasCert(GetRtAliasCertReq),
+    GetCertChain(GetCertChainReq),
+    ShaDigest(ShaDigestReq),
+    ShaInit(ShaInitReq),
+    ShaUpdate(ShaUpdateReq),
+    ShaFinal(ShaFinalReq),
+    Capabilities(CapabilitiesReq),
+    SetAuthManifest(SetAuthManifestReq),
+    AuthorizeAndStash(AuthorizeAndStashReq),
 
     #[cfg(feature = "test_only_commands")]
     TestHmacVerify(HmacVerifyReq),
@@ -224,14 +401,34 @@ impl MailboxReq {
     pub fn as_bytes(&self) -> CaliptraResult<&[u8]> {
         match self {
             MailboxReq::EcdsaVerify(req) => Ok(req.as_bytes()),
+            MailboxReq::LmsVerify(req) => Ok(req.as_bytes()),
             MailboxReq::StashMeasurement(req) => Ok(req.as_bytes()),
             MailboxReq::InvokeDpeCommand(req) => req.as_bytes_partial(),
             MailboxReq::FipsVersion(req) => Ok(req.as_bytes()),
+            MailboxReq::SelfTestStart(req) => Ok(req.as_bytes()),
+            MailboxReq::SelfTestGetResults(req) => Ok(req.as_bytes()),
             MailboxReq::FwInfo(req) => Ok(req.as_bytes()),
             MailboxReq::GetIdevCsr(req) => Ok(req.as_bytes()),
             MailboxReq::GetLdevCert(req) => Ok(req.as_bytes()),
             MailboxReq::PopulateIdevCert(req) => req.as_bytes_partial(),
             MailboxReq::GetIdevCert(req) => req.as_bytes_partial(),
+            MailboxReq::GetPcrQuote(req) => Ok(req.as_bytes()),
+            MailboxReq::ExtendPcr(req) => Ok(req.as_bytes()),
+            MailboxReq::GetPcrLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetFuseLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetMeasurementLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetEventLog(req) => Ok(req.as_bytes()),
+            MailboxReq::GetBootTimeline(req) => Ok(req.as_bytes()),
+            MailboxReq::GetFmcAliasCert(req) => Ok(req.as_bytes()),
+            MailboxReq::GetRtAliasCert(req) => Ok(req.as_bytes()),
+            MailboxReq::GetCertChain(req) => Ok(req.as_bytes()),
+            MailboxReq::ShaDigest(req) => req.as_bytes_partial(),
+            MailboxReq::ShaInit(req) => Ok(req.as_bytes()),
+            MailboxReq::ShaUpdate(req) => req.as_bytes_partial(),
+            MailboxReq::ShaFinal(req) => Ok(req.as_bytes()),
+            MailboxReq::Capabilities(req) => Ok(req.as_bytes()),
+            MailboxReq::SetAuthManifest(req) => Ok(req.as_bytes()),
+            MailboxReq::AuthorizeAndStash(req) => Ok(req.as_bytes()),
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestGetFmcAliasCert(req) => Ok(req.as_bytes()),
@@ -243,14 +440,34 @@ impl MailboxReq {
     pub fn as_bytes_mut(&mut self) -> CaliptraResult<&mut [u8]> {
         match self {
             MailboxReq::EcdsaVerify(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::LmsVerify(req) => Ok(req.as_bytes_mut()),
             MailboxReq::GetIdevCsr(req) => Ok(req.as_bytes_mut()),
             MailboxReq::GetLdevCert(req) => Ok(req.as_bytes_mut()),
             MailboxReq::StashMeasurement(req) => Ok(req.as_bytes_mut()),
             MailboxReq::InvokeDpeCommand(req) => req.as_bytes_partial_mut(),
             MailboxReq::FipsVersion(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::SelfTestStart(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::SelfTestGetResults(req) => Ok(req.as_bytes_mut()),
             MailboxReq::FwInfo(req) => Ok(req.as_bytes_mut()),
             MailboxReq::PopulateIdevCert(req) => req.as_bytes_partial_mut(),
             MailboxReq::GetIdevCert(req) => req.as_bytes_partial_mut(),
+            MailboxReq::GetPcrQuote(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::ExtendPcr(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetPcrLog(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetFuseLog(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetMeasurementLog(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetEventLog(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetBootTimeline(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetFmcAliasCert(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetRtAliasCert(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetCertChain(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::ShaDigest(req) => req.as_bytes_partial_mut(),
+            MailboxReq::ShaInit(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::ShaUpdate(req) => req.as_bytes_partial_mut(),
+            MailboxReq::ShaFinal(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::Capabilities(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::SetAuthManifest(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::AuthorizeAndStash(req) => Ok(req.as_bytes_mut()),
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestHmacVerify(req) => Ok(req.as_bytes_mut()),
@@ -262,14 +479,34 @@ impl MailboxReq {
     pub fn cmd_code(&self) -> CommandId {
         match self {
             MailboxReq::EcdsaVerify(_) => CommandId::ECDSA384_VERIFY,
+            MailboxReq::LmsVerify(_) => CommandId::LMS_VERIFY,
             MailboxReq::GetIdevCsr(_) => CommandId::GET_IDEV_CSR,
             MailboxReq::GetLdevCert(_) => CommandId::GET_LDEV_CERT,
             MailboxReq::StashMeasurement(_) => CommandId::STASH_MEASUREMENT,
             MailboxReq::InvokeDpeCommand(_) => CommandId::INVOKE_DPE,
             MailboxReq::FipsVersion(_) => CommandId::VERSION,
+            MailboxReq::SelfTestStart(_) => CommandId::SELF_TEST_START,
+            MailboxReq::SelfTestGetResults(_) => CommandId::SELF_TEST_GET_RESULTS,
             MailboxReq::FwInfo(_) => CommandId::FW_INFO,
             MailboxReq::PopulateIdevCert(_) => CommandId::POPULATE_IDEV_CERT,
             MailboxReq::GetIdevCert(_) => CommandId::GET_IDEV_CERT,
+            MailboxReq::GetPcrQuote(_) => CommandId::GET_PCR_QUOTE,
+            MailboxReq::ExtendPcr(_) => CommandId::EXTEND_PCR,
+            MailboxReq::GetPcrLog(_) => CommandId::GET_PCR_LOG,
+            MailboxReq::GetFuseLog(_) => CommandId::GET_FUSE_LOG,
+            MailboxReq::GetMeasurementLog(_) => CommandId::GET_MEASUREMENT_LOG,
+            MailboxReq::GetEventLog(_) => CommandId::GET_EVENT_LOG,
+            MailboxReq::GetBootTimeline(_) => CommandId::GET_BOOT_TIMELINE,
+            MailboxReq::GetFmcAliasCert(_) => CommandId::GET_FMC_ALIAS_CERT,
+            MailboxReq::GetRtAliasCert(_) => CommandId::GET_RT_ALIAS_CERT,
+            MailboxReq::GetCertChain(_) => CommandId::GET_CERT_CHAIN,
+            MailboxReq::ShaDigest(_) => CommandId::SHA_DIGEST,
+            MailboxReq::ShaInit(_) => CommandId::SHA_INIT,
+            MailboxReq::ShaUpdate(_) => CommandId::SHA_UPDATE,
+            MailboxReq::ShaFinal(_) => CommandId::SHA_FINAL,
+            MailboxReq::Capabilities(_) => CommandId::CAPABILITIES,
+            MailboxReq::SetAuthManifest(_) => CommandId::SET_AUTH_MANIFEST,
+            MailboxReq::AuthorizeAndStash(_) => CommandId::AUTHORIZE_AND_STASH,
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestHmacVerify(_) => CommandId::TEST_ONLY_HMAC384_VERIFY,
@@ -329,7 +566,16 @@ impl Default for MailboxRespHeader {
 }
 
 // GET_IDEV_CSR
-// No command-specific input args
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetIdevCsrReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetIdevCsrReq {
+    const ID: CommandId = CommandId::GET_IDEV_CSR;
+    type Resp = GetIdevCsrResp;
+}
+
 #[repr(C)]
 #[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
 pub struct GetIdevCsrResp {
@@ -425,18 +671,17 @@ pub struct GetIdevInfoResp {
     pub idev_pub_y: [u8; 48],
 }
 
+// GET_LDEV_CERT
 #[repr(C)]
 #[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
-pub struct TestOnlyGetLdevCertReq {
-    header: MailboxReqHeader,
+pub struct GetLdevCertReq {
+    pub hdr: MailboxReqHeader,
 }
-impl Request for TestOnlyGetLdevCertReq {
-    const ID: CommandId = CommandId::TEST_ONLY_GET_LDEV_CERT;
+impl Request for GetLdevCertReq {
+    const ID: CommandId = CommandId::GET_LDEV_CERT;
     type Resp = GetLdevCertResp;
 }
 
-// GET_LDEV_CERT
-// No command-specific input args
 #[repr(C)]
 #[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
 pub struct GetLdevCertResp {
@@ -475,6 +720,44 @@ impl Request for EcdsaVerifyReq {
 }
 // No command-specific output args
 
+// LMS_VERIFY
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct LmsVerifyReq {
+    pub hdr: MailboxReqHeader,
+    pub pub_key: [u8; LmsVerifyReq::MAX_PUB_KEY_SIZE],
+    pub signature_size: u32,
+    pub signature: [u8; LmsVerifyReq::MAX_SIGNATURE_SIZE],
+    pub message_size: u32,
+    pub message: [u8; LmsVerifyReq::MAX_MESSAGE_SIZE],
+}
+impl LmsVerifyReq {
+    /// Size of an RFC 8554 public key with N=32. N=24 keys occupy the first
+    /// 48 bytes and are zero padded.
+    pub const MAX_PUB_KEY_SIZE: usize = 56;
+    /// Size of the largest supported signature (LMS_SHA256_M24_H15 with
+    /// LMOTS_SHA256_N24_W4).
+    pub const MAX_SIGNATURE_SIZE: usize = 1620;
+    pub const MAX_MESSAGE_SIZE: usize = 256;
+}
+impl Default for LmsVerifyReq {
+    fn default() -> Self {
+        Self {
+            hdr: Default::default(),
+            pub_key: [0u8; Self::MAX_PUB_KEY_SIZE],
+            signature_size: 0,
+            signature: [0u8; Self::MAX_SIGNATURE_SIZE],
+            message_size: 0,
+            message: [0u8; Self::MAX_MESSAGE_SIZE],
+        }
+    }
+}
+impl Request for LmsVerifyReq {
+    const ID: CommandId = CommandId::LMS_VERIFY;
+    type Resp = MailboxRespHeader;
+}
+// No command-specific output args
+
 // TEST_ONLY_HMAC384_SIGNATURE_VERIFY
 #[repr(C)]
 #[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
@@ -627,9 +910,197 @@ impl Default for TestGetFmcAliasCertResp {
     }
 }
 
-// FIPS_SELF_TEST
-// No command-specific input args
-// No command-specific output args
+// GET_FMC_ALIAS_CERT
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetFmcAliasCertReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetFmcAliasCertReq {
+    const ID: CommandId = CommandId::GET_FMC_ALIAS_CERT;
+    type Resp = GetFmcAliasCertResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetFmcAliasCertResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetFmcAliasCertResp::DATA_MAX_SIZE], // variable length
+}
+impl GetFmcAliasCertResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+}
+impl ResponseVarSize for GetFmcAliasCertResp {}
+
+impl Default for GetFmcAliasCertResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetFmcAliasCertResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// GET_RT_ALIAS_CERT
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetRtAliasCertReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetRtAliasCertReq {
+    const ID: CommandId = CommandId::GET_RT_ALIAS_CERT;
+    type Resp = GetRtAliasCertResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetRtAliasCertResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetRtAliasCertResp::DATA_MAX_SIZE], // variable length
+}
+impl GetRtAliasCertResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+}
+impl ResponseVarSize for GetRtAliasCertResp {}
+
+impl Default for GetRtAliasCertResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetRtAliasCertResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// GET_CERT_CHAIN
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCertChainReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the cert chain of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetCertChainReq {
+    const ID: CommandId = CommandId::GET_CERT_CHAIN;
+    type Resp = GetCertChainResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The cert chain reassembled from the chunks returned by GET_CERT_CHAIN.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCertChainResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetCertChainResp::DATA_MAX_SIZE], // variable length
+}
+impl GetCertChainResp {
+    pub const DATA_MAX_SIZE: usize = 4096;
+}
+impl ResponseVarSize for GetCertChainResp {}
+
+impl Default for GetCertChainResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetCertChainResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// SELF_TEST_START
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SelfTestStartReq {
+    pub hdr: MailboxReqHeader,
+
+    /// Bitmask of the KATs to run, bit N selecting the KAT with ID N.
+    /// Zero, or a request without this field, runs all of them.
+    pub kats: u32,
+}
+impl SelfTestStartReq {
+    pub const KAT_SHA1: u32 = 0;
+    pub const KAT_SHA256: u32 = 1;
+    pub const KAT_SHA384: u32 = 2;
+    pub const KAT_SHA384_ACC: u32 = 3;
+    pub const KAT_ECC384: u32 = 4;
+    pub const KAT_ECC384_PCT: u32 = 5;
+    pub const KAT_HMAC384: u32 = 6;
+    pub const KAT_HMAC384_KDF: u32 = 7;
+    pub const KAT_LMS: u32 = 8;
+    pub const KAT_DRBG: u32 = 9;
+    pub const KAT_TRNG_HEALTH: u32 = 10;
+}
+impl Request for SelfTestStartReq {
+    const ID: CommandId = CommandId::SELF_TEST_START;
+    type Resp = MailboxRespHeader;
+}
+
+// SELF_TEST_GET_RESULTS
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SelfTestGetResultsReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for SelfTestGetResultsReq {
+    const ID: CommandId = CommandId::SELF_TEST_GET_RESULTS;
+    type Resp = SelfTestGetResultsResp;
+}
+
+/// Outcome of one KAT as returned by SELF_TEST_GET_RESULTS.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SelfTestResult {
+    /// One of the `SelfTestStartReq::KAT_*` IDs
+    pub kat_id: u32,
+    pub status: u32,
+    /// Caliptra error code if the KAT failed
+    pub error: u32,
+    /// Duration of the KAT in core clock cycles
+    pub cycles: u32,
+}
+impl SelfTestResult {
+    pub const STATUS_NOT_RUN: u32 = 0;
+    pub const STATUS_PASSED: u32 = 1;
+    pub const STATUS_FAILED: u32 = 2;
+    pub const STATUS_SKIPPED: u32 = 3;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SelfTestGetResultsResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub results: [SelfTestResult; SelfTestGetResultsResp::MAX_RESULT_COUNT], // variable length
+}
+impl SelfTestGetResultsResp {
+    pub const MAX_RESULT_COUNT: usize = 16;
+
+    /// The reported results, ordered by KAT ID.
+    pub fn results(&self) -> &[SelfTestResult] {
+        let count = self.data_size as usize / size_of::<SelfTestResult>();
+        &self.results[..count.min(Self::MAX_RESULT_COUNT)]
+    }
+}
+impl ResponseVarSize for SelfTestGetResultsResp {}
+
+impl Default for SelfTestGetResultsResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            results: [SelfTestResult::default(); SelfTestGetResultsResp::MAX_RESULT_COUNT],
+        }
+    }
+}
 
 // FIPS_GET_VERSION
 // No command-specific input args
@@ -660,6 +1131,16 @@ pub struct FwInfoResp {
 
 // CAPABILITIES
 // No command-specific input args
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct CapabilitiesReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for CapabilitiesReq {
+    const ID: CommandId = CommandId::CAPABILITIES;
+    type Resp = CapabilitiesResp;
+}
+
 #[repr(C)]
 #[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
 pub struct CapabilitiesResp {
@@ -706,47 +1187,664 @@ impl Default for PopulateIdevCertReq {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// GET_PCR_QUOTE
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetPcrQuoteReq {
+    pub hdr: MailboxReqHeader,
+    pub nonce: [u8; 32],
+}
+impl Request for GetPcrQuoteReq {
+    const ID: CommandId = CommandId::GET_PCR_QUOTE;
+    type Resp = GetPcrQuoteResp;
+}
 
-    #[test]
-    fn test_populate_checksum_resp_header() {
-        let mut hdr = MailboxRespHeader {
-            fips_status: 0x4242,
-            ..Default::default()
-        };
-        hdr.populate_chksum();
-        assert_eq!(
-            hdr,
-            MailboxRespHeader {
-                chksum: 0u32.wrapping_sub(0x84),
-                fips_status: 0x4242,
-            }
-        )
+pub type PcrValue = [u8; 48];
+
+/// The signature covers SHA384(nonce || pcrs[0] || ... || pcrs[31]) and is
+/// produced with the RT alias key.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetPcrQuoteResp {
+    pub hdr: MailboxRespHeader,
+    pub nonce: [u8; 32],
+    pub pcrs: [PcrValue; GetPcrQuoteResp::PCR_COUNT],
+    pub signature_r: [u8; 48],
+    pub signature_s: [u8; 48],
+}
+impl GetPcrQuoteResp {
+    pub const PCR_COUNT: usize = 32;
+}
+impl Response for GetPcrQuoteResp {}
+
+impl Default for GetPcrQuoteResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            nonce: [0u8; 32],
+            pcrs: [[0u8; 48]; GetPcrQuoteResp::PCR_COUNT],
+            signature_r: [0u8; 48],
+            signature_s: [0u8; 48],
+        }
     }
-    #[test]
-    fn test_populate_checksum_capabilities() {
-        let mut msg = CapabilitiesResp {
-            hdr: Default::default(),
-            capabilities: [
-                0x42, 0x23, 0x43, 0x81, 0x45, 0x6c, 0x55, 0x75, 0x3d, 0x81, 0xd4, 0xcc, 0x3c, 0x28,
-                0x29, 0xc9,
-            ],
-        };
-        msg.populate_chksum();
-        assert_eq!(
-            msg,
-            CapabilitiesResp {
-                hdr: MailboxRespHeader {
-                    chksum: 0xfffff9a8,
-                    fips_status: 0
-                },
-                capabilities: [
-                    0x42, 0x23, 0x43, 0x81, 0x45, 0x6c, 0x55, 0x75, 0x3d, 0x81, 0xd4, 0xcc, 0x3c,
-                    0x28, 0x29, 0xc9
-                ],
-            }
-        );
+}
+
+// EXTEND_PCR
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ExtendPcrReq {
+    pub hdr: MailboxReqHeader,
+    pub pcr_idx: u32,
+    pub data: [u8; 48],
+}
+impl Default for ExtendPcrReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            pcr_idx: 0,
+            data: [0u8; 48],
+        }
+    }
+}
+impl Request for ExtendPcrReq {
+    const ID: CommandId = CommandId::EXTEND_PCR;
+    type Resp = MailboxRespHeader;
+}
+// No command-specific output args
+
+// SHA_DIGEST
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ShaDigestReq {
+    pub hdr: MailboxReqHeader,
+    pub hash_algorithm: u32,
+    pub flags: u32,
+    /// SoC PCR to extend the digest into if `FLAG_EXTEND_PCR` is set.
+    pub pcr_idx: u32,
+    pub data_size: u32,
+    pub data: [u8; ShaDigestReq::DATA_MAX_SIZE], // variable length
+}
+
+impl ShaDigestReq {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub const HASH_ALGORITHM_SHA384: u32 = 1;
+    pub const HASH_ALGORITHM_SHA512: u32 = 2;
+
+    pub const FLAG_EXTEND_PCR: u32 = 1 << 0;
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Default for ShaDigestReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            hash_algorithm: Self::HASH_ALGORITHM_SHA384,
+            flags: 0,
+            pcr_idx: 0,
+            data_size: 0,
+            data: [0u8; ShaDigestReq::DATA_MAX_SIZE],
+        }
+    }
+}
+impl Request for ShaDigestReq {
+    const ID: CommandId = CommandId::SHA_DIGEST;
+    type Resp = ShaDigestResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ShaDigestResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub digest: [u8; ShaDigestResp::DATA_MAX_SIZE], // variable length
+}
+impl ShaDigestResp {
+    pub const DATA_MAX_SIZE: usize = 64;
+}
+impl ResponseVarSize for ShaDigestResp {}
+
+impl Default for ShaDigestResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            digest: [0u8; ShaDigestResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// SHA_INIT
+// No command-specific output args
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ShaInitReq {
+    pub hdr: MailboxReqHeader,
+    /// One of `ShaDigestReq::HASH_ALGORITHM_*`
+    pub hash_algorithm: u32,
+    /// `ShaDigestReq::FLAG_*`
+    pub flags: u32,
+    /// SoC PCR to extend the digest into if `FLAG_EXTEND_PCR` is set.
+    pub pcr_idx: u32,
+    /// Total length of the data sent in the following SHA_UPDATE commands.
+    pub data_len: u32,
+}
+impl Request for ShaInitReq {
+    const ID: CommandId = CommandId::SHA_INIT;
+    type Resp = MailboxRespHeader;
+}
+
+// SHA_UPDATE
+// No command-specific output args
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ShaUpdateReq {
+    pub hdr: MailboxReqHeader,
+    pub data_size: u32,
+    pub data: [u8; ShaUpdateReq::DATA_MAX_SIZE], // variable length
+}
+
+impl ShaUpdateReq {
+    pub const DATA_MAX_SIZE: usize = ShaDigestReq::DATA_MAX_SIZE;
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Default for ShaUpdateReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            data_size: 0,
+            data: [0u8; ShaUpdateReq::DATA_MAX_SIZE],
+        }
+    }
+}
+impl Request for ShaUpdateReq {
+    const ID: CommandId = CommandId::SHA_UPDATE;
+    type Resp = MailboxRespHeader;
+}
+
+// SHA_FINAL
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ShaFinalReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for ShaFinalReq {
+    const ID: CommandId = CommandId::SHA_FINAL;
+    type Resp = ShaDigestResp;
+}
+// Response is ShaDigestResp
+
+// SET_AUTH_MANIFEST
+// No command-specific output args
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SetAuthManifestReq {
+    pub hdr: MailboxReqHeader,
+    pub manifest: [u8; SetAuthManifestReq::MANIFEST_SIZE],
+}
+impl SetAuthManifestReq {
+    /// Size of the authorization manifest (`caliptra_image_types::AuthManifest`)
+    pub const MANIFEST_SIZE: usize = 4340;
+}
+impl Default for SetAuthManifestReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            manifest: [0u8; SetAuthManifestReq::MANIFEST_SIZE],
+        }
+    }
+}
+impl Request for SetAuthManifestReq {
+    const ID: CommandId = CommandId::SET_AUTH_MANIFEST;
+    type Resp = MailboxRespHeader;
+}
+
+// AUTHORIZE_AND_STASH
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct AuthorizeAndStashReq {
+    pub hdr: MailboxReqHeader,
+    pub fw_id: [u8; 4],
+    pub measurement: [u8; 48],
+    pub context: [u8; 48],
+    pub flags: u32,
+}
+impl AuthorizeAndStashReq {
+    /// Authorize the image without stashing its measurement in DPE
+    pub const FLAG_SKIP_STASH: u32 = 1 << 0;
+}
+impl Default for AuthorizeAndStashReq {
+    fn default() -> Self {
+        Self {
+            hdr: Default::default(),
+            fw_id: Default::default(),
+            measurement: [0u8; 48],
+            context: [0u8; 48],
+            flags: Default::default(),
+        }
+    }
+}
+impl Request for AuthorizeAndStashReq {
+    const ID: CommandId = CommandId::AUTHORIZE_AND_STASH;
+    type Resp = AuthorizeAndStashResp;
+}
+
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct AuthorizeAndStashResp {
+    pub hdr: MailboxRespHeader,
+    pub auth_req_result: u32,
+}
+impl AuthorizeAndStashResp {
+    pub const AUTHORIZED: u32 = 0xDEAD_C0DE;
+    pub const DENIED: u32 = 0x2152_3F21;
+}
+impl Response for AuthorizeAndStashResp {}
+
+/// PCR log entry as returned by GET_PCR_LOG. Same layout as the entries
+/// ROM and FMC record in persistent data.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct PcrLogEntry {
+    pub id: u16,
+    pub reserved0: [u8; 2],
+    /// Bitmask of the PCRs the data was extended into.
+    pub pcr_ids: u32,
+    pub pcr_data: [u32; 12],
+}
+impl PcrLogEntry {
+    /// The bytes that were extended into the PCRs in `pcr_ids`.
+    pub fn measured_data(&self) -> &[u8] {
+        let data_len = PcrLogEntryId::from(self.id).data_len();
+        &self.pcr_data.as_bytes()[..data_len]
+    }
+}
+
+/// Fuse log entry as returned by GET_FUSE_LOG.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct FuseLogEntry {
+    pub entry_id: u32,
+    pub log_data: [u32; 1],
+    pub reserved: [u32; 2],
+}
+
+/// Measurement log entry as returned by GET_MEASUREMENT_LOG.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct MeasurementLogEntry {
+    pub pcr_entry: PcrLogEntry,
+    pub metadata: [u8; 4],
+    pub context: [u32; 12],
+    pub svn: u32,
+    pub reserved0: [u8; 4],
+}
+
+// GET_PCR_LOG
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetPcrLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the PCR log of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetPcrLogReq {
+    const ID: CommandId = CommandId::GET_PCR_LOG;
+    type Resp = GetPcrLogResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The PCR log reassembled from the chunks returned by GET_PCR_LOG.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetPcrLogResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub entries: [PcrLogEntry; GetPcrLogResp::MAX_ENTRY_COUNT], // variable length
+}
+impl GetPcrLogResp {
+    pub const MAX_ENTRY_COUNT: usize = 17;
+
+    /// The valid entries, in the order they were logged.
+    pub fn entries(&self) -> &[PcrLogEntry] {
+        let count = self.data_size as usize / size_of::<PcrLogEntry>();
+        &self.entries[..count.min(Self::MAX_ENTRY_COUNT)]
+    }
+}
+impl ResponseVarSize for GetPcrLogResp {}
+
+impl Default for GetPcrLogResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            entries: [PcrLogEntry::default(); GetPcrLogResp::MAX_ENTRY_COUNT],
+        }
+    }
+}
+
+// GET_FUSE_LOG
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetFuseLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the fuse log of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetFuseLogReq {
+    const ID: CommandId = CommandId::GET_FUSE_LOG;
+    type Resp = GetFuseLogResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The fuse log reassembled from the chunks returned by GET_FUSE_LOG.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetFuseLogResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub entries: [FuseLogEntry; GetFuseLogResp::MAX_ENTRY_COUNT], // variable length
+}
+impl GetFuseLogResp {
+    pub const MAX_ENTRY_COUNT: usize = 62;
+
+    /// The valid entries, in ascending `entry_id` order.
+    pub fn entries(&self) -> &[FuseLogEntry] {
+        let count = self.data_size as usize / size_of::<FuseLogEntry>();
+        &self.entries[..count.min(Self::MAX_ENTRY_COUNT)]
+    }
+}
+impl ResponseVarSize for GetFuseLogResp {}
+
+impl Default for GetFuseLogResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            entries: [FuseLogEntry::default(); GetFuseLogResp::MAX_ENTRY_COUNT],
+        }
+    }
+}
+
+// GET_MEASUREMENT_LOG
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetMeasurementLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the measurement log of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetMeasurementLogReq {
+    const ID: CommandId = CommandId::GET_MEASUREMENT_LOG;
+    type Resp = GetMeasurementLogResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The measurement log reassembled from the chunks returned by GET_MEASUREMENT_LOG.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetMeasurementLogResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub entries: [MeasurementLogEntry; GetMeasurementLogResp::MAX_ENTRY_COUNT], // variable length
+}
+impl GetMeasurementLogResp {
+    pub const MAX_ENTRY_COUNT: usize = 8;
+
+    /// The valid entries, in the order they were stashed.
+    pub fn entries(&self) -> &[MeasurementLogEntry] {
+        let count = self.data_size as usize / size_of::<MeasurementLogEntry>();
+        &self.entries[..count.min(Self::MAX_ENTRY_COUNT)]
+    }
+}
+impl ResponseVarSize for GetMeasurementLogResp {}
+
+impl Default for GetMeasurementLogResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            entries: [MeasurementLogEntry::default(); GetMeasurementLogResp::MAX_ENTRY_COUNT],
+        }
+    }
+}
+
+// GET_EVENT_LOG
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetEventLogReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the event log of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetEventLogReq {
+    const ID: CommandId = CommandId::GET_EVENT_LOG;
+    type Resp = GetEventLogResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The event log reassembled from the chunks returned by GET_EVENT_LOG.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetEventLogResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetEventLogResp::DATA_MAX_SIZE], // variable length
+}
+impl GetEventLogResp {
+    // Every log entry is extended into at most two PCRs, and a full
+    // measurement log adds a record marking PCR31 as incomplete.
+    pub const DATA_MAX_SIZE: usize =
+        ((GetPcrLogResp::MAX_ENTRY_COUNT + GetMeasurementLogResp::MAX_ENTRY_COUNT) * 2 + 1)
+            * crate::event_log::EventLogRecord::MAX_SIZE;
+}
+impl ResponseVarSize for GetEventLogResp {}
+
+impl Default for GetEventLogResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetEventLogResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+/// Boot timeline entry as returned by GET_BOOT_TIMELINE. Same layout as the
+/// entries ROM, FMC and runtime record in DCCM.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct BootTimelineEntry {
+    pub boot_status: u32,
+    pub cycle_count_low: u32,
+    pub cycle_count_high: u32,
+}
+impl BootTimelineEntry {
+    /// Value of the `mcycle` CSR when `boot_status` was reported.
+    pub fn cycle_count(&self) -> u64 {
+        (u64::from(self.cycle_count_high) << 32) | u64::from(self.cycle_count_low)
+    }
+}
+
+// GET_BOOT_TIMELINE
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetBootTimelineReq {
+    pub hdr: MailboxReqHeader,
+    /// Byte offset into the boot timeline of the first byte to return.
+    pub offset: u32,
+}
+impl Request for GetBootTimelineReq {
+    const ID: CommandId = CommandId::GET_BOOT_TIMELINE;
+    type Resp = GetBootTimelineResp;
+
+    fn chunk_offset_mut(&mut self) -> Option<&mut u32> {
+        Some(&mut self.offset)
+    }
+}
+
+/// The boot timeline reassembled from the chunks returned by GET_BOOT_TIMELINE.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetBootTimelineResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub entries: [BootTimelineEntry; GetBootTimelineResp::MAX_ENTRY_COUNT], // variable length
+}
+impl GetBootTimelineResp {
+    pub const MAX_ENTRY_COUNT: usize = 84;
+
+    /// The valid entries, oldest first.
+    pub fn entries(&self) -> &[BootTimelineEntry] {
+        let count = self.data_size as usize / size_of::<BootTimelineEntry>();
+        &self.entries[..count.min(Self::MAX_ENTRY_COUNT)]
+    }
+}
+impl ResponseVarSize for GetBootTimelineResp {}
+
+impl Default for GetBootTimelineResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            entries: [BootTimelineEntry::default(); GetBootTimelineResp::MAX_ENTRY_COUNT],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_populate_checksum_resp_header() {
+        let mut hdr = MailboxRespHeader {
+            fips_status: 0x4242,
+            ..Default::default()
+        };
+        hdr.populate_chksum();
+        assert_eq!(
+            hdr,
+            MailboxRespHeader {
+                chksum: 0u32.wrapping_sub(0x84),
+                fips_status: 0x4242,
+            }
+        )
+    }
+    #[test]
+    fn test_populate_checksum_capabilities() {
+        let mut msg = CapabilitiesResp {
+            hdr: Default::default(),
+            capabilities: [
+                0x42, 0x23, 0x43, 0x81, 0x45, 0x6c, 0x55, 0x75, 0x3d, 0x81, 0xd4, 0xcc, 0x3c, 0x28,
+                0x29, 0xc9,
+            ],
+        };
+        msg.populate_chksum();
+        assert_eq!(
+            msg,
+            CapabilitiesResp {
+                hdr: MailboxRespHeader {
+                    chksum: 0xfffff9a8,
+                    fips_status: 0
+                },
+                capabilities: [
+                    0x42, 0x23, 0x43, 0x81, 0x45, 0x6c, 0x55, 0x75, 0x3d, 0x81, 0xd4, 0xcc, 0x3c,
+                    0x28, 0x29, 0xc9
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_chunked_resp_from_payload() {
+        let payload: Vec<u8> = (0..ChunkedResp::DATA_MAX_SIZE + 10)
+            .map(|i| i as u8)
+            .collect();
+        let payload_chksum = crate::checksum::calc_checksum(0, &payload);
+
+        let first = ChunkedResp::from_payload(&payload, 0).unwrap();
+        assert_eq!(first.total_size as usize, payload.len());
+        assert_eq!(first.offset, 0);
+        assert_eq!(first.flags, ChunkedResp::FLAG_MORE);
+        assert_eq!(first.payload_chksum, payload_chksum);
+        assert_eq!(
+            first.data().unwrap(),
+            &payload[..ChunkedResp::DATA_MAX_SIZE]
+        );
+
+        let last = ChunkedResp::from_payload(&payload, first.data_size).unwrap();
+        assert_eq!(last.offset, first.data_size);
+        assert_eq!(last.flags, 0);
+        assert_eq!(last.payload_chksum, payload_chksum);
+        assert_eq!(last.data().unwrap(), &payload[ChunkedResp::DATA_MAX_SIZE..]);
+        assert_eq!(
+            last.as_bytes_partial().unwrap().len(),
+            ChunkedResp::MIN_SIZE + 10
+        );
+
+        assert_eq!(
+            ChunkedResp::from_payload(&payload, payload.len() as u32 + 1),
+            Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)
+        );
+    }
+
+    #[test]
+    fn test_chunked_resp_streamed() {
+        let payload: Vec<u8> = (0..ChunkedResp::DATA_MAX_SIZE * 2 + 10)
+            .map(|i| (i * 7) as u8)
+            .collect();
+
+        for offset in [0, 5, ChunkedResp::DATA_MAX_SIZE, payload.len()] {
+            let mut streamed = ChunkedResp::new_streamed(offset as u32);
+            for piece in payload.chunks(100) {
+                streamed.append_payload(piece);
+            }
+            assert_eq!(
+                streamed.finish_payload().unwrap(),
+                ChunkedResp::from_payload(&payload, offset as u32).unwrap()
+            );
+        }
     }
 }
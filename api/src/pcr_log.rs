@@ -0,0 +1,69 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    pcr_log.rs
+
+Abstract:
+
+    Identifiers of the PCR log entries recorded by ROM, FMC and runtime.
+
+--*/
+
+// PcrLogEntryId is used to identify the PCR entry and
+// the size of the data in PcrLogEntry::pcr_data.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PcrLogEntryId {
+    Invalid = 0,
+    DeviceStatus = 1,     // data size = 9 bytes
+    VendorPubKeyHash = 2, // data size = 48 bytes
+    OwnerPubKeyHash = 3,  // data size = 48 bytes
+    FmcTci = 4,           // data size = 48 bytes
+    StashMeasurement = 5, // data size = 48 bytes
+    RtTci = 6,            // data size = 48 bytes
+    FwImageManifest = 7,  // data size = 48 bytes
+    SocPcrExtend = 8,     // data size = 48 bytes
+    FwLoadFailure = 9,    // data size = 8 bytes
+    AuxImage = 10,        // data size = 48 bytes
+}
+
+impl PcrLogEntryId {
+    /// Size of the data extended into the PCRs for this entry
+    pub const fn data_len(self) -> usize {
+        match self {
+            PcrLogEntryId::Invalid => 0,
+            PcrLogEntryId::DeviceStatus => 9,
+            PcrLogEntryId::VendorPubKeyHash => 48,
+            PcrLogEntryId::OwnerPubKeyHash => 48,
+            PcrLogEntryId::FmcTci => 48,
+            PcrLogEntryId::StashMeasurement => 48,
+            PcrLogEntryId::RtTci => 48,
+            PcrLogEntryId::FwImageManifest => 48,
+            PcrLogEntryId::SocPcrExtend => 48,
+            PcrLogEntryId::FwLoadFailure => 8,
+            PcrLogEntryId::AuxImage => 48,
+        }
+    }
+}
+
+impl From<u16> for PcrLogEntryId {
+    /// Converts to this type from the input type.
+    fn from(id: u16) -> PcrLogEntryId {
+        match id {
+            1 => PcrLogEntryId::DeviceStatus,
+            2 => PcrLogEntryId::VendorPubKeyHash,
+            3 => PcrLogEntryId::OwnerPubKeyHash,
+            4 => PcrLogEntryId::FmcTci,
+            5 => PcrLogEntryId::StashMeasurement,
+            6 => PcrLogEntryId::RtTci,
+            7 => PcrLogEntryId::FwImageManifest,
+            8 => PcrLogEntryId::SocPcrExtend,
+            9 => PcrLogEntryId::FwLoadFailure,
+            10 => PcrLogEntryId::AuxImage,
+            _ => PcrLogEntryId::Invalid,
+        }
+    }
+}
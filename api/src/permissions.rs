@@ -0,0 +1,140 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    permissions.rs
+
+Abstract:
+
+    Runtime mailbox command permission bits used by the PAUSER policy
+
+--*/
+
+use crate::mailbox::CommandId;
+
+bitflags::bitflags! {
+    #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct CommandPermissions : u64 {
+        const FIRMWARE_LOAD = 1 << 0;
+        const GET_IDEV_CERT = 1 << 1;
+        const GET_IDEV_CSR = 1 << 2;
+        const GET_IDEV_INFO = 1 << 3;
+        const GET_LDEV_CERT = 1 << 4;
+        const INVOKE_DPE = 1 << 5;
+        const ECDSA384_VERIFY = 1 << 6;
+        const LMS_VERIFY = 1 << 7;
+        const STASH_MEASUREMENT = 1 << 8;
+        const DISABLE_ATTESTATION = 1 << 9;
+        const FW_INFO = 1 << 10;
+        const POPULATE_IDEV_CERT = 1 << 11;
+        const GET_PCR_QUOTE = 1 << 12;
+        const EXTEND_PCR = 1 << 13;
+        const GET_PCR_LOG = 1 << 14;
+        const GET_FUSE_LOG = 1 << 15;
+        const GET_MEASUREMENT_LOG = 1 << 16;
+        const GET_FMC_ALIAS_CERT = 1 << 17;
+        const GET_RT_ALIAS_CERT = 1 << 18;
+        const GET_CERT_CHAIN = 1 << 19;
+        const SHA_DIGEST = 1 << 20;
+        const VERSION = 1 << 21;
+        const SELF_TEST_START = 1 << 22;
+        const SELF_TEST_GET_RESULTS = 1 << 23;
+        const SHUTDOWN = 1 << 24;
+        const CAPABILITIES = 1 << 25;
+        const SET_AUTH_MANIFEST = 1 << 26;
+        const AUTHORIZE_AND_STASH = 1 << 27;
+        const GET_EVENT_LOG = 1 << 28;
+        const GET_BOOT_TIMELINE = 1 << 29;
+    }
+}
+
+impl CommandPermissions {
+    /// Returns the permission bit for `cmd`, or `None` if the command cannot
+    /// be granted through the PAUSER policy.
+    pub fn from_command(cmd: CommandId) -> Option<Self> {
+        let perm = match cmd {
+            CommandId::FIRMWARE_LOAD => Self::FIRMWARE_LOAD,
+            CommandId::GET_IDEV_CERT => Self::GET_IDEV_CERT,
+            CommandId::GET_IDEV_CSR => Self::GET_IDEV_CSR,
+            CommandId::GET_IDEV_INFO => Self::GET_IDEV_INFO,
+            CommandId::GET_LDEV_CERT => Self::GET_LDEV_CERT,
+            CommandId::INVOKE_DPE => Self::INVOKE_DPE,
+            CommandId::ECDSA384_VERIFY => Self::ECDSA384_VERIFY,
+            CommandId::LMS_VERIFY => Self::LMS_VERIFY,
+            CommandId::STASH_MEASUREMENT => Self::STASH_MEASUREMENT,
+            CommandId::DISABLE_ATTESTATION => Self::DISABLE_ATTESTATION,
+            CommandId::FW_INFO => Self::FW_INFO,
+            CommandId::POPULATE_IDEV_CERT => Self::POPULATE_IDEV_CERT,
+            CommandId::GET_PCR_QUOTE => Self::GET_PCR_QUOTE,
+            CommandId::EXTEND_PCR => Self::EXTEND_PCR,
+            CommandId::GET_PCR_LOG => Self::GET_PCR_LOG,
+            CommandId::GET_FUSE_LOG => Self::GET_FUSE_LOG,
+            CommandId::GET_MEASUREMENT_LOG => Self::GET_MEASUREMENT_LOG,
+            CommandId::GET_FMC_ALIAS_CERT => Self::GET_FMC_ALIAS_CERT,
+            CommandId::GET_RT_ALIAS_CERT => Self::GET_RT_ALIAS_CERT,
+            CommandId::GET_CERT_CHAIN => Self::GET_CERT_CHAIN,
+            // The streaming SHA commands share the SHA_DIGEST permission.
+            // Requests that extend a PCR also need EXTEND_PCR, which the
+            // runtime checks when it sees the flag.
+            CommandId::SHA_DIGEST
+            | CommandId::SHA_INIT
+            | CommandId::SHA_UPDATE
+            | CommandId::SHA_FINAL => Self::SHA_DIGEST,
+            CommandId::VERSION => Self::VERSION,
+            CommandId::SELF_TEST_START => Self::SELF_TEST_START,
+            CommandId::SELF_TEST_GET_RESULTS => Self::SELF_TEST_GET_RESULTS,
+            CommandId::SHUTDOWN => Self::SHUTDOWN,
+            CommandId::CAPABILITIES => Self::CAPABILITIES,
+            CommandId::SET_AUTH_MANIFEST => Self::SET_AUTH_MANIFEST,
+            CommandId::AUTHORIZE_AND_STASH => Self::AUTHORIZE_AND_STASH,
+            CommandId::GET_EVENT_LOG => Self::GET_EVENT_LOG,
+            CommandId::GET_BOOT_TIMELINE => Self::GET_BOOT_TIMELINE,
+            _ => return None,
+        };
+        Some(perm)
+    }
+
+    /// Converts from the word layout used by the image manifest
+    /// (least significant word first). Unknown bits are dropped.
+    pub fn from_words(words: [u32; 2]) -> Self {
+        Self::from_bits_truncate(u64::from(words[0]) | (u64::from(words[1]) << 32))
+    }
+
+    /// Converts to the word layout used by the image manifest
+    pub fn to_words(&self) -> [u32; 2] {
+        [self.bits() as u32, (self.bits() >> 32) as u32]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_round_trip() {
+        let perms = CommandPermissions::INVOKE_DPE | CommandPermissions::SHUTDOWN;
+        assert_eq!(CommandPermissions::from_words(perms.to_words()), perms);
+        assert_eq!(
+            CommandPermissions::from_words([0, 0]),
+            CommandPermissions::empty()
+        );
+    }
+
+    #[test]
+    fn test_from_command() {
+        assert_eq!(
+            CommandPermissions::from_command(CommandId::DISABLE_ATTESTATION),
+            Some(CommandPermissions::DISABLE_ATTESTATION)
+        );
+        assert_eq!(
+            CommandPermissions::from_command(CommandId::TEST_ONLY_HMAC384_VERIFY),
+            None
+        );
+        assert_eq!(
+            CommandPermissions::from_command(CommandId::SHA_UPDATE),
+            Some(CommandPermissions::SHA_DIGEST)
+        );
+    }
+}
@@ -13,7 +13,8 @@ use std::sync::{Arc, Mutex};
 
 use caliptra_image_elf::ElfExecutable;
 use caliptra_image_gen::{
-    ImageGenerator, ImageGeneratorConfig, ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig,
+    ImageGenerator, ImageGeneratorAuxImage, ImageGeneratorConfig, ImageGeneratorOwnerConfig,
+    ImageGeneratorVendorConfig,
 };
 use caliptra_image_openssl::OsslCrypto;
 use caliptra_image_types::{ImageBundle, ImageRevision, RomInfo};
@@ -415,6 +416,7 @@ pub struct ImageOptions {
     pub app_svn: u32,
     pub vendor_config: ImageGeneratorVendorConfig,
     pub owner_config: Option<ImageGeneratorOwnerConfig>,
+    pub aux_images: Vec<ImageGeneratorAuxImage>,
 }
 impl Default for ImageOptions {
     fn default() -> Self {
@@ -427,6 +429,7 @@ impl Default for ImageOptions {
             app_svn: Default::default(),
             vendor_config: caliptra_image_fake_keys::VENDOR_CONFIG_KEY_0,
             owner_config: Some(caliptra_image_fake_keys::OWNER_CONFIG),
+            aux_images: Vec::new(),
         }
     }
 }
@@ -456,6 +459,7 @@ pub fn build_and_sign_image(
         )?,
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        aux: opts.aux_images,
     })?;
     Ok(image)
 }
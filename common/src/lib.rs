@@ -2,7 +2,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-pub mod boot_status;
+pub mod boot_status {
+    pub use caliptra_api::boot_status::{FmcBootStatus, RomBootStatus, RtBootStatus};
+}
 pub mod capabilities {
     pub use caliptra_api::Capabilities;
 }
@@ -14,6 +16,9 @@ pub mod dice;
 pub mod error_handler;
 pub mod fips;
 pub mod keyids;
+pub mod permissions {
+    pub use caliptra_api::CommandPermissions;
+}
 pub mod verifier;
 pub mod wdt;
 
@@ -24,6 +29,7 @@ pub use hand_off::{
 };
 
 pub use boot_status::RomBootStatus;
+pub use caliptra_api::event_log;
 pub use caliptra_api::mailbox as mailbox_api;
 pub use caliptra_drivers::cprint;
 pub use caliptra_drivers::cprintln;
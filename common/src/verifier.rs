@@ -17,7 +17,7 @@ use caliptra_image_types::*;
 use caliptra_image_verify::ImageVerificationEnv;
 use core::ops::Range;
 
-use caliptra_drivers::memory_layout::ICCM_RANGE;
+use caliptra_drivers::memory_layout::{AUX_IMAGE_RANGE, ICCM_RANGE};
 
 /// ROM Verification Environemnt
 pub struct FirmwareImageVerificationEnv<'a, 'b> {
@@ -142,6 +142,10 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         ICCM_RANGE
     }
 
+    fn aux_image_range(&self) -> Range<u32> {
+        AUX_IMAGE_RANGE
+    }
+
     fn lms_verify_enabled(&self) -> bool {
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
@@ -0,0 +1,58 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    aux_image.rs
+
+Abstract:
+
+    Auxiliary images loaded by ROM into the auxiliary image region of DCCM.
+
+--*/
+
+use crate::memory_layout;
+use caliptra_error::{CaliptraError, CaliptraResult};
+use caliptra_image_types::{ImageManifest, ImageTocEntry, ImageTocEntryId};
+
+/// Byte range of `entry` at its load address, if it lies entirely within
+/// the auxiliary image region.
+fn load_range(entry: &ImageTocEntry) -> Option<(usize, usize)> {
+    let end = entry.load_addr.checked_add(entry.size)?;
+    if entry.load_addr < memory_layout::AUX_IMAGE_RANGE.start
+        || end > memory_layout::AUX_IMAGE_RANGE.end
+    {
+        return None;
+    }
+    Some((entry.load_addr as usize, entry.size as usize))
+}
+
+/// Copy the image described by `entry` from `bundle` to its load address.
+///
+/// ROM calls this after the image verifier has authenticated `entry`.
+pub fn load(entry: &ImageTocEntry, bundle: &[u8]) -> CaliptraResult<()> {
+    let err = CaliptraError::DRIVER_AUX_IMAGE_INVALID_RANGE;
+    let (addr, size) = load_range(entry).ok_or(err)?;
+    let range = entry.image_range()?;
+    let image = bundle
+        .get(range.start as usize..range.end as usize)
+        .ok_or(err)?;
+
+    // SAFETY: The destination lies within the auxiliary image region, which
+    // is reserved for auxiliary images and not otherwise referenced.
+    let dest = unsafe { core::slice::from_raw_parts_mut(addr as *mut u8, size) };
+    dest.copy_from_slice(image);
+    Ok(())
+}
+
+/// Contents of the auxiliary image `id` of `manifest`, as loaded by ROM.
+///
+/// Returns `None` if the manifest does not carry the image.
+pub fn aux_image(manifest: &ImageManifest, id: ImageTocEntryId) -> Option<&'static [u8]> {
+    let (addr, size) = load_range(manifest.aux_entry(id)?)?;
+
+    // SAFETY: The image lies within the auxiliary image region, which is only
+    // written by ROM before firmware runs.
+    Some(unsafe { core::slice::from_raw_parts(addr as *const u8, size) })
+}
@@ -0,0 +1,143 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    boot_timeline.rs
+
+Abstract:
+
+    Boot timeline recorded by ROM, FMC and runtime.
+
+--*/
+
+use crate::memory_layout;
+use zerocopy::{AsBytes, FromBytes};
+use zeroize::Zeroize;
+
+pub const BOOT_TIMELINE_MAX_COUNT: usize = 84;
+
+/// Cycle count at which a boot status was reported
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, Debug, Default, FromBytes, Zeroize)]
+pub struct BootTimelineEntry {
+    pub boot_status: u32,
+    pub cycle_count_low: u32,
+    pub cycle_count_high: u32,
+}
+
+/// Ring of the most recently reported boot statuses.
+///
+/// ROM clears the ring along with the rest of DCCM on cold reset, so after
+/// a warm or update reset the new boot is appended to the previous one.
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Zeroize)]
+pub struct BootTimeline {
+    /// Number of entries recorded since cold reset
+    pub count: u32,
+    pub entries: [BootTimelineEntry; BOOT_TIMELINE_MAX_COUNT],
+}
+
+impl BootTimeline {
+    /// Copy of the boot timeline in DCCM.
+    pub fn read() -> Self {
+        // SAFETY: BOOT_TIMELINE_ORG is reserved for the boot timeline, and
+        // every bit pattern is a valid BootTimeline.
+        unsafe { core::ptr::read_volatile(memory_layout::BOOT_TIMELINE_ORG as *const Self) }
+    }
+
+    /// Append `boot_status` to the boot timeline in DCCM, overwriting the
+    /// oldest entry once the ring is full.
+    pub(crate) fn record(boot_status: u32) {
+        let cycle_count = cycle_count();
+        let timeline = memory_layout::BOOT_TIMELINE_ORG as *mut Self;
+
+        // SAFETY: BOOT_TIMELINE_ORG is reserved for the boot timeline, and no
+        // references to it outlive a call to this module.
+        unsafe {
+            let count = (*timeline).count;
+            let index = count as usize % BOOT_TIMELINE_MAX_COUNT;
+            if let Some(entry) = (*timeline).entries.get_mut(index) {
+                *entry = BootTimelineEntry {
+                    boot_status,
+                    cycle_count_low: cycle_count as u32,
+                    cycle_count_high: (cycle_count >> 32) as u32,
+                };
+            }
+            (*timeline).count = count.wrapping_add(1);
+        }
+    }
+
+    /// The recorded entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &BootTimelineEntry> {
+        let count = self.count as usize;
+        let (newer, older) = self.entries.split_at(count % BOOT_TIMELINE_MAX_COUNT);
+        let older = if count < BOOT_TIMELINE_MAX_COUNT {
+            &older[..0]
+        } else {
+            older
+        };
+        older.iter().chain(newer)
+    }
+}
+
+/// Number of cycles since reset, read from the `mcycle` CSR
+#[cfg(target_arch = "riscv32")]
+pub fn cycle_count() -> u64 {
+    loop {
+        let (high, low, high_again): (u32, u32, u32);
+        unsafe {
+            core::arch::asm!(
+                "csrr {high}, mcycleh",
+                "csrr {low}, mcycle",
+                "csrr {high_again}, mcycleh",
+                high = out(reg) high,
+                low = out(reg) low,
+                high_again = out(reg) high_again,
+            );
+        }
+        // Read again if mcycle overflowed into mcycleh between the reads
+        if high == high_again {
+            return (u64::from(high) << 32) | u64::from(low);
+        }
+    }
+}
+
+/// Number of cycles since reset, read from the `mcycle` CSR
+#[cfg(not(target_arch = "riscv32"))]
+pub fn cycle_count() -> u64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline_with(count: u32) -> BootTimeline {
+        let mut timeline = BootTimeline::new_zeroed();
+        for i in 0..count {
+            timeline.entries[i as usize % BOOT_TIMELINE_MAX_COUNT].boot_status = i;
+        }
+        timeline.count = count;
+        timeline
+    }
+
+    fn statuses(timeline: &BootTimeline) -> impl Iterator<Item = u32> + '_ {
+        timeline.entries().map(|entry| entry.boot_status)
+    }
+
+    #[test]
+    fn test_entries_before_wrap() {
+        assert!(statuses(&timeline_with(0)).eq(0..0));
+        assert!(statuses(&timeline_with(5)).eq(0..5));
+        let full = BOOT_TIMELINE_MAX_COUNT as u32;
+        assert!(statuses(&timeline_with(full)).eq(0..full));
+    }
+
+    #[test]
+    fn test_entries_after_wrap() {
+        let count = BOOT_TIMELINE_MAX_COUNT as u32 + 10;
+        assert!(statuses(&timeline_with(count)).eq(10..count));
+    }
+}
@@ -108,12 +108,22 @@ impl Csrng {
                 .write(|w| w.enable(TRUE).sw_app_enable(TRUE).read_int_state(TRUE));
         }
 
-        send_command(&mut result.csrng, Command::Uninstantiate)?;
-        send_command(&mut result.csrng, Command::Instantiate(seed))?;
+        result.reinstantiate(seed)?;
 
         Ok(result)
     }
 
+    /// Replaces the DRBG state with a new instance seeded from `seed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal uninstantiate or instantiate command
+    /// fails.
+    pub fn reinstantiate(&mut self, seed: Seed) -> CaliptraResult<()> {
+        send_command(&mut self.csrng, Command::Uninstantiate)?;
+        send_command(&mut self.csrng, Command::Instantiate(seed))
+    }
+
     /// Return 12 randomly generated [`u32`]s.
     ///
     /// # Errors
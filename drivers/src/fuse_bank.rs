@@ -306,6 +306,20 @@ impl FuseBank<'_> {
             RomVerifyConfig::EcdsaAndLms
         }
     }
+
+    /// Get the number of additional firmware images ROM accepts after a
+    /// cold boot image fails verification.
+    ///
+    /// # Arguments
+    /// * None
+    ///
+    /// # Returns
+    ///     firmware recovery retry count (bits 0-1 of the fuse)
+    ///
+    pub fn fw_recovery_retry_count(&self) -> u32 {
+        let soc_ifc_regs = self.soc_ifc.regs();
+        soc_ifc_regs.fuse_fw_recovery_retry_count().read() & 0x3
+    }
 }
 
 #[cfg(test)]
@@ -299,7 +299,8 @@ pub struct FirmwareHandoffTable {
     /// Measurement log Address
     pub meas_log_addr: u32,
 
-    // Last empty measurement log entry slot index
+    // Number of measurements stashed. Only the first MEASUREMENT_MAX_COUNT
+    // are kept in the measurement log.
     pub meas_log_index: u32,
 
     /// Fuse log Address
@@ -13,7 +13,7 @@ Abstract:
 --*/
 
 #[cfg(test)]
-use crate::FirmwareHandoffTable;
+use crate::{boot_timeline::BootTimeline, FirmwareHandoffTable};
 
 #[cfg(test)]
 use caliptra_image_types::ImageManifest;
@@ -29,16 +29,20 @@ pub const ROM_DATA_ORG: u32 = 0x50000000;
 pub const CFI_STATE_ORG: u32 = 0x500003E4; // size = 6 words
 pub const BOOT_STATUS_ORG: u32 = 0x500003FC;
 pub const MAN1_ORG: u32 = 0x50000400;
-pub const MAN2_ORG: u32 = 0x50001C00;
-pub const FHT_ORG: u32 = 0x50003400;
-pub const LDEVID_TBS_ORG: u32 = 0x50003C00;
-pub const FMCALIAS_TBS_ORG: u32 = 0x50004000;
-pub const RTALIAS_TBS_ORG: u32 = 0x50004400;
-pub const PCR_LOG_ORG: u32 = 0x50004800;
-pub const MEASUREMENT_LOG_ORG: u32 = 0x50004C00;
-pub const FUSE_LOG_ORG: u32 = 0x50005000;
-pub const DPE_ORG: u32 = 0x50005400;
-pub const DATA_ORG: u32 = 0x50006400;
+pub const MAN2_ORG: u32 = 0x50002000;
+pub const FHT_ORG: u32 = 0x50003C00;
+pub const LDEVID_TBS_ORG: u32 = 0x50004400;
+pub const FMCALIAS_TBS_ORG: u32 = 0x50004800;
+pub const RTALIAS_TBS_ORG: u32 = 0x50004C00;
+pub const PCR_LOG_ORG: u32 = 0x50005000;
+pub const MEASUREMENT_LOG_ORG: u32 = 0x50005400;
+pub const FUSE_LOG_ORG: u32 = 0x50005800;
+pub const DPE_ORG: u32 = 0x50005C00;
+pub const IDEVID_CSR_ORG: u32 = 0x50006C00;
+pub const AUTH_MAN_IMAGE_METADATA_ORG: u32 = 0x50007000;
+pub const BOOT_TIMELINE_ORG: u32 = 0x50007400;
+pub const AUX_IMAGE_ORG: u32 = 0x50007800;
+pub const DATA_ORG: u32 = 0x50009400;
 pub const STACK_ORG: u32 = 0x5001A000;
 pub const ROM_STACK_ORG: u32 = 0x5001C000;
 pub const ESTACK_ORG: u32 = 0x5001F800;
@@ -53,8 +57,8 @@ pub const MBOX_SIZE: u32 = 128 * 1024;
 pub const ICCM_SIZE: u32 = 128 * 1024;
 pub const DCCM_SIZE: u32 = 128 * 1024;
 pub const ROM_DATA_SIZE: u32 = 996;
-pub const MAN1_SIZE: u32 = 6 * 1024;
-pub const MAN2_SIZE: u32 = 6 * 1024;
+pub const MAN1_SIZE: u32 = 7 * 1024;
+pub const MAN2_SIZE: u32 = 7 * 1024;
 pub const FHT_SIZE: u32 = 2 * 1024;
 pub const LDEVID_TBS_SIZE: u32 = 1024;
 pub const FMCALIAS_TBS_SIZE: u32 = 1024;
@@ -63,7 +67,11 @@ pub const PCR_LOG_SIZE: u32 = 1024;
 pub const MEASUREMENT_LOG_SIZE: u32 = 1024;
 pub const FUSE_LOG_SIZE: u32 = 1024;
 pub const DPE_SIZE: u32 = 4 * 1024;
-pub const DATA_SIZE: u32 = 79 * 1024;
+pub const IDEVID_CSR_SIZE: u32 = 1024;
+pub const AUTH_MAN_IMAGE_METADATA_SIZE: u32 = 1024;
+pub const BOOT_TIMELINE_SIZE: u32 = 1024;
+pub const AUX_IMAGE_SIZE: u32 = 7 * 1024;
+pub const DATA_SIZE: u32 = 67 * 1024;
 pub const STACK_SIZE: u32 = 22 * 1024;
 pub const ROM_STACK_SIZE: u32 = 14 * 1024;
 pub const ESTACK_SIZE: u32 = 1024;
@@ -74,6 +82,11 @@ pub const ICCM_RANGE: core::ops::Range<u32> = core::ops::Range {
     end: ICCM_ORG + ICCM_SIZE,
 };
 
+pub const AUX_IMAGE_RANGE: core::ops::Range<u32> = core::ops::Range {
+    start: AUX_IMAGE_ORG,
+    end: AUX_IMAGE_ORG + AUX_IMAGE_SIZE,
+};
+
 #[test]
 #[allow(clippy::assertions_on_constants)]
 fn mem_layout_test_manifest() {
@@ -130,7 +143,38 @@ fn mem_layout_test_fuselog() {
 #[test]
 #[allow(clippy::assertions_on_constants)]
 fn mem_layout_test_dpe() {
-    assert_eq!((DATA_ORG - DPE_ORG), DPE_SIZE);
+    assert_eq!((IDEVID_CSR_ORG - DPE_ORG), DPE_SIZE);
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_idevid_csr() {
+    assert_eq!(
+        (AUTH_MAN_IMAGE_METADATA_ORG - IDEVID_CSR_ORG),
+        IDEVID_CSR_SIZE
+    );
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_auth_man_image_metadata() {
+    assert_eq!(
+        (BOOT_TIMELINE_ORG - AUTH_MAN_IMAGE_METADATA_ORG),
+        AUTH_MAN_IMAGE_METADATA_SIZE
+    );
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_boot_timeline() {
+    assert!(BOOT_TIMELINE_SIZE as usize >= core::mem::size_of::<BootTimeline>());
+    assert_eq!((AUX_IMAGE_ORG - BOOT_TIMELINE_ORG), BOOT_TIMELINE_SIZE);
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_aux_image() {
+    assert_eq!((DATA_ORG - AUX_IMAGE_ORG), AUX_IMAGE_SIZE);
 }
 
 #[test]
@@ -2,7 +2,7 @@
 
 use core::{marker::PhantomData, mem::size_of, ptr::addr_of};
 
-use caliptra_image_types::ImageManifest;
+use caliptra_image_types::{AuthManifestImageMetadataCollection, ImageManifest};
 #[cfg(feature = "runtime")]
 use dpe::DpeInstance;
 use zerocopy::{AsBytes, FromBytes};
@@ -23,6 +23,25 @@ pub type PcrLogArray = [PcrLogEntry; PCR_LOG_MAX_COUNT];
 pub type FuseLogArray = [FuseLogEntry; FUSE_LOG_MAX_COUNT];
 pub type StashMeasurementArray = [MeasurementLogEntry; MEASUREMENT_MAX_COUNT];
 
+/// IDevID CSR generated by ROM on cold reset.
+#[derive(Clone, FromBytes, AsBytes, Zeroize)]
+#[repr(C)]
+pub struct IdevIdCsr {
+    pub csr_len: u32,
+    pub csr: [u8; IdevIdCsr::MAX_CSR_SIZE],
+}
+impl IdevIdCsr {
+    pub const MAX_CSR_SIZE: usize = 512;
+
+    /// The CSR bytes, or `None` if ROM did not record a CSR.
+    pub fn get(&self) -> Option<&[u8]> {
+        match self.csr_len {
+            0 => None,
+            len => self.csr.get(..len as usize),
+        }
+    }
+}
+
 #[derive(FromBytes, AsBytes, Zeroize)]
 #[repr(C)]
 pub struct PersistentData {
@@ -57,6 +76,14 @@ pub struct PersistentData {
     reserved6: [u8; memory_layout::DPE_SIZE as usize - size_of::<DpeInstance>()],
     #[cfg(not(feature = "runtime"))]
     dpe: [u8; memory_layout::DPE_SIZE as usize],
+
+    pub idevid_csr: IdevIdCsr,
+    reserved7: [u8; memory_layout::IDEVID_CSR_SIZE as usize - size_of::<IdevIdCsr>()],
+
+    /// Image metadata from the last authorization manifest set by the SoC
+    pub auth_manifest_image_metadata: AuthManifestImageMetadataCollection,
+    reserved8: [u8; memory_layout::AUTH_MAN_IMAGE_METADATA_SIZE as usize
+        - size_of::<AuthManifestImageMetadataCollection>()],
 }
 impl PersistentData {
     pub fn assert_matches_layout() {
@@ -76,9 +103,18 @@ impl PersistentData {
             );
             assert_eq!(addr_of!((*P).fuse_log) as u32, memory_layout::FUSE_LOG_ORG);
             assert_eq!(addr_of!((*P).dpe) as u32, memory_layout::DPE_ORG);
+            assert_eq!(
+                addr_of!((*P).idevid_csr) as u32,
+                memory_layout::IDEVID_CSR_ORG
+            );
+            assert_eq!(
+                addr_of!((*P).auth_manifest_image_metadata) as u32,
+                memory_layout::AUTH_MAN_IMAGE_METADATA_ORG
+            );
             assert_eq!(
                 P.add(1) as u32,
-                memory_layout::DPE_ORG + memory_layout::DPE_SIZE
+                memory_layout::AUTH_MAN_IMAGE_METADATA_ORG
+                    + memory_layout::AUTH_MAN_IMAGE_METADATA_SIZE
             );
         }
     }
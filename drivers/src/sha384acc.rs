@@ -13,6 +13,7 @@ Abstract:
 --*/
 use crate::wait;
 use crate::Array4x12;
+use crate::Array4x16;
 use crate::CaliptraResult;
 
 use caliptra_error::CaliptraError;
@@ -157,6 +158,139 @@ impl Sha384AccOp<'_> {
         start_address: u32,
         maintain_data_endianess: bool,
         digest: Sha384Digest,
+    ) -> CaliptraResult<()> {
+        self.execute(dlen, start_address, maintain_data_endianess, false)?;
+
+        self.copy_digest_to_buf(digest)?;
+
+        // Zeroize the hardware registers.
+        self.zeroize_regs();
+
+        Ok(())
+    }
+
+    /// Compute the SHA512 digest of `dlen` bytes of mailbox data starting at
+    /// `start_address`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dlen` - Length of the data in bytes
+    /// * `start_address` - Offset of the data in the mailbox
+    /// * `maintain_data_endianess` - Preserve the DWORD endianess of the data
+    /// * `digest` - Digest buffer
+    pub fn digest_512(
+        &mut self,
+        dlen: u32,
+        start_address: u32,
+        maintain_data_endianess: bool,
+        digest: &mut Array4x16,
+    ) -> CaliptraResult<()> {
+        self.execute(dlen, start_address, maintain_data_endianess, true)?;
+
+        *digest = Array4x16::read_from_reg(self.sha512_acc.regs().digest());
+
+        // Zeroize the hardware registers.
+        self.zeroize_regs();
+
+        Ok(())
+    }
+
+    /// Start a streaming digest of `dlen` bytes written with `stream_update`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dlen` - Total length of the streamed data in bytes
+    /// * `sha512` - Compute a SHA512 instead of a SHA384 digest
+    pub fn stream_init(&mut self, dlen: u32, sha512: bool) {
+        let sha_acc = self.sha512_acc.regs_mut();
+
+        // Setting the mode restarts the streamed digest, so it goes first.
+        sha_acc.mode().write(|w| {
+            w.mode(|w| {
+                if sha512 {
+                    w.sha_stream_512()
+                } else {
+                    w.sha_stream_384()
+                }
+            })
+        });
+
+        // Set the total length of the streamed data.
+        sha_acc.dlen().write(|_| dlen);
+    }
+
+    /// Write `data` to the streamed digest. The accelerator takes a DWORD at a
+    /// time, so only the last write of a stream may have a length that is not a
+    /// multiple of 4.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data to add to the digest
+    pub fn stream_update(&mut self, data: &[u8]) {
+        let sha_acc = self.sha512_acc.regs_mut();
+        for chunk in data.chunks(4) {
+            let mut word = [0u8; 4];
+            for (dst, src) in word.iter_mut().zip(chunk) {
+                *dst = *src;
+            }
+            sha_acc.datain().write(|_| u32::from_be_bytes(word));
+        }
+    }
+
+    /// Finish a streamed SHA384 digest started with `stream_init`.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Digest buffer
+    pub fn stream_finalize(&mut self, digest: Sha384Digest) -> CaliptraResult<()> {
+        self.stream_execute();
+
+        self.copy_digest_to_buf(digest)?;
+
+        // Zeroize the hardware registers.
+        self.zeroize_regs();
+
+        Ok(())
+    }
+
+    /// Finish a streamed SHA512 digest started with `stream_init`.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Digest buffer
+    pub fn stream_finalize_512(&mut self, digest: &mut Array4x16) -> CaliptraResult<()> {
+        self.stream_execute();
+
+        *digest = Array4x16::read_from_reg(self.sha512_acc.regs().digest());
+
+        // Zeroize the hardware registers.
+        self.zeroize_regs();
+
+        Ok(())
+    }
+
+    /// Keep the accelerator locked when the operation goes out of scope, so
+    /// it can be resumed later with `ShaAccLockState::AssumedLocked`.
+    pub fn retain_lock(self) {
+        core::mem::forget(self);
+    }
+
+    fn stream_execute(&mut self) {
+        let sha_acc = self.sha512_acc.regs_mut();
+
+        // Signal the end of the stream.
+        sha_acc.execute().write(|_| ExecuteWriteVal::from(1));
+
+        // Wait for the digest operation to finish
+        wait::until(|| sha_acc.status().read().valid());
+    }
+
+    fn execute(
+        &mut self,
+        dlen: u32,
+        start_address: u32,
+        maintain_data_endianess: bool,
+        sha512: bool,
     ) -> CaliptraResult<()> {
         let sha_acc = self.sha512_acc.regs_mut();
 
@@ -172,29 +306,34 @@ impl Sha384AccOp<'_> {
         // Set the start offset of the data in the mailbox.
         sha_acc.start_address().write(|_| start_address);
 
-        // Set the SHA accelerator mode (only SHA384 supported) and
-        // set the option to maintain the DWORD endianess of the data in the
-        // mailbox provided to the SHA384 engine.
+        // Set the SHA accelerator mode and set the option to maintain the
+        // DWORD endianess of the data in the mailbox provided to the SHA
+        // engine.
         sha_acc.mode().write(|w| {
-            w.mode(|w| w.sha_mbox_384())
-                .endian_toggle(maintain_data_endianess)
+            w.mode(|w| {
+                if sha512 {
+                    w.sha_mbox_512()
+                } else {
+                    w.sha_mbox_384()
+                }
+            })
+            .endian_toggle(maintain_data_endianess)
         });
 
-        // Trigger the SHA384 operation.
+        // Trigger the SHA operation.
         sha_acc.execute().write(|_| ExecuteWriteVal::from(1));
 
         // Wait for the digest operation to finish
         wait::until(|| sha_acc.status().read().valid());
 
-        self.copy_digest_to_buf(digest)?;
+        Ok(())
+    }
 
-        // Zeroize the hardware registers.
+    fn zeroize_regs(&mut self) {
         self.sha512_acc
             .regs_mut()
             .control()
             .write(|w| w.zeroize(true));
-
-        Ok(())
     }
 
     /// Copy digest to buffer
@@ -16,7 +16,7 @@ use caliptra_error::{CaliptraError, CaliptraResult};
 use caliptra_registers::soc_ifc::enums::DeviceLifecycleE;
 use caliptra_registers::soc_ifc::{self, SocIfcReg};
 
-use crate::{memory_layout, FuseBank};
+use crate::{boot_timeline::BootTimeline, memory_layout, FuseBank};
 
 pub type Lifecycle = DeviceLifecycleE;
 
@@ -29,6 +29,8 @@ pub fn report_boot_status(val: u32) {
         *ptr = val;
     };
 
+    BootTimeline::record(val);
+
     // For testability, save the boot status in the boot status register only if debugging is enabled.
     if !soc_ifc.regs().cptra_security_state().read().debug_locked() {
         soc_ifc.regs_mut().cptra_boot_status().write(|_| val);
@@ -169,6 +171,22 @@ impl SocIfc {
         ((val >> 31) & 1) != 0
     }
 
+    /// Check if the fake-rom should derive the device identity from a test seed
+    pub fn derive_identity_in_fake_mode(&self) -> bool {
+        let soc_ifc_regs = self.soc_ifc.regs();
+        let val = soc_ifc_regs.cptra_dbg_manuf_service_reg().read();
+        // Bit 30 indicates to derive the LDEVID and FMC Alias identities in fake ROM
+        ((val >> 30) & 1) != 0
+    }
+
+    /// Returns the test seed the fake-rom derives the device identity from
+    pub fn fake_rom_identity_seed(&self) -> u32 {
+        let soc_ifc_regs = self.soc_ifc.regs();
+        let val = soc_ifc_regs.cptra_dbg_manuf_service_reg().read();
+        // Bits 18-29 hold the seed
+        (val >> 18) & 0xfff
+    }
+
     #[inline(always)]
     pub fn hw_config_internal_trng(&mut self) -> bool {
         self.soc_ifc.regs().cptra_hw_config().read().i_trng_en()
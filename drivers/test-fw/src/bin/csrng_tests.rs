@@ -15,6 +15,7 @@ Abstract:
 #![no_main]
 
 use caliptra_drivers::{Csrng, CsrngSeed};
+use caliptra_kat::DrbgKat;
 
 use caliptra_registers::{csrng::CsrngReg, entropy_src::EntropySrcReg, soc_ifc::SocIfcReg};
 use caliptra_test_harness::test_suite;
@@ -84,10 +85,29 @@ fn test_zero_health_fails() {
     );
 }
 
+// Runs last as it draws seed material from the entropy source.
+fn test_drbg_kat() {
+    let csrng_reg = unsafe { CsrngReg::new() };
+    let entropy_src_reg = unsafe { EntropySrcReg::new() };
+    let soc_ifc_reg = unsafe { SocIfcReg::new() };
+
+    let mut csrng = Csrng::new(csrng_reg, entropy_src_reg, &soc_ifc_reg).expect("construct CSRNG");
+
+    DrbgKat::default()
+        .execute(&mut csrng)
+        .expect("DRBG KAT should pass");
+
+    // The KAT leaves the CSRNG seeded from the entropy source.
+    csrng
+        .generate12()
+        .expect("generate after the KAT should work");
+}
+
 test_suite! {
     test_ctr_drbg_ctr0_smoke,
     test_entropy_src_seed,
     test_zero_health_fails,
+    test_drbg_kat,
 
     // TODO(rkr35): Induce failing health checks and assert we can observe them.
     // TODO(rkr35): Test Reseed and Update commands.
@@ -21,7 +21,7 @@ use caliptra_drivers::{
     Ecc384Seed, KeyId, KeyReadArgs, KeyUsage, KeyWriteArgs, Trng,
 };
 use caliptra_error::CaliptraError;
-use caliptra_kat::Ecc384Kat;
+use caliptra_kat::{Ecc384Kat, Ecc384PctKat};
 use caliptra_registers::csrng::CsrngReg;
 use caliptra_registers::ecc::EccReg;
 use caliptra_registers::entropy_src::EntropySrcReg;
@@ -499,6 +499,10 @@ fn test_kat() {
         Ecc384Kat::default().execute(&mut ecc, &mut trng).is_ok(),
         true
     );
+    assert_eq!(
+        Ecc384PctKat::default().execute(&mut ecc, &mut trng).is_ok(),
+        true
+    );
 }
 
 test_suite! {
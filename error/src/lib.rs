@@ -262,6 +262,26 @@ impl CaliptraError {
         CaliptraError::new_const(0x000b0040);
     pub const IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS: CaliptraError =
         CaliptraError::new_const(0x000b0041);
+    pub const IMAGE_VERIFIER_ERR_AUX_ID_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0042);
+    pub const IMAGE_VERIFIER_ERR_AUX_ID_DUPLICATE: CaliptraError =
+        CaliptraError::new_const(0x000b0043);
+    pub const IMAGE_VERIFIER_ERR_AUX_TYPE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0044);
+    pub const IMAGE_VERIFIER_ERR_AUX_SIZE_ZERO: CaliptraError =
+        CaliptraError::new_const(0x000b0045);
+    pub const IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0046);
+    pub const IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_UNALIGNED: CaliptraError =
+        CaliptraError::new_const(0x000b0047);
+    pub const IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_OVERLAP: CaliptraError =
+        CaliptraError::new_const(0x000b0048);
+    pub const IMAGE_VERIFIER_ERR_AUX_DIGEST_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b0049);
+    pub const IMAGE_VERIFIER_ERR_AUX_DIGEST_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000b004a);
+    pub const IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP: CaliptraError =
+        CaliptraError::new_const(0x000b004b);
 
     /// Driver Error: LMS
     pub const DRIVER_LMS_INVALID_LMS_ALGO_TYPE: CaliptraError =
@@ -364,6 +384,44 @@ impl CaliptraError {
         CaliptraError::new_const(0x000E0024);
     pub const RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE: CaliptraError =
         CaliptraError::new_const(0x000E0025);
+    pub const RUNTIME_GET_PCR_QUOTE_FAILED: CaliptraError = CaliptraError::new_const(0x000E0026);
+    pub const RUNTIME_EXTEND_PCR_INVALID_INDEX: CaliptraError =
+        CaliptraError::new_const(0x000E0027);
+    pub const RUNTIME_EXTEND_PCR_FMC_PCR_RESERVED: CaliptraError =
+        CaliptraError::new_const(0x000E0028);
+    pub const RUNTIME_EXTEND_PCR_RT_PCR_RESERVED: CaliptraError =
+        CaliptraError::new_const(0x000E0029);
+    pub const RUNTIME_EXTEND_PCR_STASH_MEASUREMENT_PCR_RESERVED: CaliptraError =
+        CaliptraError::new_const(0x000E002A);
+    pub const RUNTIME_PCR_LOG_EXHAUSTED: CaliptraError = CaliptraError::new_const(0x000E002B);
+    pub const RUNTIME_IDEV_CSR_NOT_AVAILABLE: CaliptraError = CaliptraError::new_const(0x000E002C);
+    pub const RUNTIME_LMS_VERIFY_FAILED: CaliptraError = CaliptraError::new_const(0x000E002D);
+    pub const RUNTIME_LMS_VERIFY_UNSUPPORTED_ALGORITHM: CaliptraError =
+        CaliptraError::new_const(0x000E002E);
+    pub const RUNTIME_SHA_DIGEST_INVALID_ALGORITHM: CaliptraError =
+        CaliptraError::new_const(0x000E002F);
+    pub const RUNTIME_SHA_DIGEST_EXTEND_REQUIRES_SHA384: CaliptraError =
+        CaliptraError::new_const(0x000E0030);
+    pub const RUNTIME_SHA_ACC_LOCK_UNAVAILABLE: CaliptraError =
+        CaliptraError::new_const(0x000E0031);
+    pub const RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER: CaliptraError =
+        CaliptraError::new_const(0x000E0032);
+    pub const RUNTIME_AUTH_MANIFEST_INVALID: CaliptraError = CaliptraError::new_const(0x000E0033);
+    pub const RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000E0034);
+    pub const RUNTIME_AUTH_MANIFEST_OWNER_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000E0035);
+    pub const RUNTIME_AUTH_AND_STASH_MEASUREMENT_DPE_ERROR: CaliptraError =
+        CaliptraError::new_const(0x000E0036);
+    pub const RUNTIME_SHA_STREAM_NOT_STARTED: CaliptraError = CaliptraError::new_const(0x000E0037);
+    pub const RUNTIME_SHA_STREAM_LENGTH_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000E0038);
+    pub const RUNTIME_SHA_STREAM_UNALIGNED_UPDATE: CaliptraError =
+        CaliptraError::new_const(0x000E0039);
+    pub const RUNTIME_AUTH_MANIFEST_DUPLICATE_FW_ID: CaliptraError =
+        CaliptraError::new_const(0x000E003A);
+    pub const RUNTIME_SHA_STREAM_PAUSER_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000E003B);
 
     /// FMC Errors
     pub const FMC_GLOBAL_NMI: CaliptraError = CaliptraError::new_const(0x000F0001);
@@ -391,6 +449,9 @@ impl CaliptraError {
     pub const ADDRESS_MISALIGNED: CaliptraError = CaliptraError::new_const(0x00110000);
     pub const ADDRESS_NOT_IN_ROM: CaliptraError = CaliptraError::new_const(0x00110001);
 
+    /// Auxiliary image driver Errors
+    pub const DRIVER_AUX_IMAGE_INVALID_RANGE: CaliptraError = CaliptraError::new_const(0x00120001);
+
     /// Initial Device ID Errors
     pub const ROM_IDEVID_CSR_BUILDER_INIT_FAILURE: CaliptraError =
         CaliptraError::new_const(0x01000001);
@@ -517,6 +578,25 @@ impl CaliptraError {
     pub const ROM_KAT_LMS_DIGEST_MISMATCH: CaliptraError = CaliptraError::new_const(0x90070002);
 
     pub const ROM_INTEGRITY_FAILURE: CaliptraError = CaliptraError::new_const(0x90080001);
+
+    pub const ROM_KAT_HMAC384_KDF_FAILURE: CaliptraError = CaliptraError::new_const(0x90090001);
+    pub const ROM_KAT_HMAC384_KDF_MISMATCH: CaliptraError = CaliptraError::new_const(0x90090002);
+
+    pub const ROM_KAT_ECC384_PCT_KEY_PAIR_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x900A0001);
+    pub const ROM_KAT_ECC384_PCT_SIGN_FAILURE: CaliptraError = CaliptraError::new_const(0x900A0002);
+    pub const ROM_KAT_ECC384_PCT_VERIFY_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x900A0003);
+
+    pub const ROM_KAT_DRBG_INSTANTIATE_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x900B0001);
+    pub const ROM_KAT_DRBG_GENERATE_FAILURE: CaliptraError = CaliptraError::new_const(0x900B0002);
+    pub const ROM_KAT_DRBG_OUTPUT_MISMATCH: CaliptraError = CaliptraError::new_const(0x900B0003);
+
+    pub const ROM_KAT_TRNG_HEALTH_TEST_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x900C0001);
+    pub const ROM_KAT_TRNG_GENERATE_FAILURE: CaliptraError = CaliptraError::new_const(0x900C0002);
+    pub const ROM_KAT_TRNG_REPEATED_OUTPUT: CaliptraError = CaliptraError::new_const(0x900C0003);
 }
 
 impl From<core::num::NonZeroU32> for crate::CaliptraError {
@@ -19,12 +19,11 @@ use caliptra_common::{cprintln, handle_fatal_error};
 use caliptra_cpu::{log_trap_record, TrapRecord};
 
 use caliptra_drivers::{report_fw_error_non_fatal, Mailbox};
-mod boot_status;
 mod flow;
 pub mod fmc_env;
 mod hand_off;
 
-pub use boot_status::FmcBootStatus;
+pub use caliptra_common::boot_status::FmcBootStatus;
 use caliptra_error::CaliptraError;
 use caliptra_registers::soc_ifc::SocIfcReg;
 use hand_off::HandOff;
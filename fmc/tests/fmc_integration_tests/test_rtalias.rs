@@ -7,6 +7,7 @@ use caliptra_common::RomBootStatus::*;
 
 use caliptra_common::mailbox_api::CommandId;
 use caliptra_drivers::{
+    memory_layout,
     pcr_log::{PcrLogEntry, PcrLogEntryId},
     FirmwareHandoffTable, PcrId,
 };
@@ -88,11 +89,11 @@ fn test_fht_info() {
     let fht = FirmwareHandoffTable::read_from_prefix(data.as_bytes()).unwrap();
     assert_eq!(fht.ldevid_tbs_size, 552);
     assert_eq!(fht.fmcalias_tbs_size, 786);
-    assert_eq!(fht.ldevid_tbs_addr, 0x50003C00);
-    assert_eq!(fht.fmcalias_tbs_addr, 0x50004000);
-    assert_eq!(fht.pcr_log_addr, 0x50004800);
-    assert_eq!(fht.meas_log_addr, 0x50004C00);
-    assert_eq!(fht.fuse_log_addr, 0x50005000);
+    assert_eq!(fht.ldevid_tbs_addr, memory_layout::LDEVID_TBS_ORG);
+    assert_eq!(fht.fmcalias_tbs_addr, memory_layout::FMCALIAS_TBS_ORG);
+    assert_eq!(fht.pcr_log_addr, memory_layout::PCR_LOG_ORG);
+    assert_eq!(fht.meas_log_addr, memory_layout::MEASUREMENT_LOG_ORG);
+    assert_eq!(fht.fuse_log_addr, memory_layout::FUSE_LOG_ORG);
 }
 
 #[test]
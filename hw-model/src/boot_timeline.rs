@@ -0,0 +1,186 @@
+// Licensed under the Apache-2.0 license
+
+use std::fmt::Display;
+
+use caliptra_api::boot_status::{FmcBootStatus, RomBootStatus::*, RtBootStatus};
+use caliptra_api::mailbox::BootTimelineEntry;
+
+use crate::output::PrettyU64;
+
+/// A boot phase, delimited by the boot statuses reported at its start and
+/// end.
+struct PhaseDef {
+    name: &'static str,
+    start_status: u32,
+    end_status: u32,
+}
+
+const fn phase(name: &'static str, start_status: u32, end_status: u32) -> PhaseDef {
+    PhaseDef {
+        name,
+        start_status,
+        end_status,
+    }
+}
+
+const PHASES: &[PhaseDef] = &[
+    phase("KATs", KatStarted as u32, KatComplete as u32),
+    phase(
+        "DOE",
+        ColdResetStarted as u32,
+        IDevIdClearDoeSecretsComplete as u32,
+    ),
+    phase(
+        "IDevID",
+        IDevIdClearDoeSecretsComplete as u32,
+        IDevIdDerivationComplete as u32,
+    ),
+    phase(
+        "LDevID",
+        IDevIdDerivationComplete as u32,
+        LDevIdDerivationComplete as u32,
+    ),
+    phase(
+        "Image download",
+        LDevIdDerivationComplete as u32,
+        FwProcessorDownloadImageComplete as u32,
+    ),
+    phase(
+        "Image verify",
+        FwProcessorDownloadImageComplete as u32,
+        FwProcessorImageVerificationComplete as u32,
+    ),
+    phase(
+        "Image load",
+        FwProcessorImageVerificationComplete as u32,
+        FwProcessorComplete as u32,
+    ),
+    phase(
+        "FMC alias",
+        FwProcessorComplete as u32,
+        FmcAliasDerivationComplete as u32,
+    ),
+    phase(
+        "RT alias",
+        ColdResetComplete as u32,
+        FmcBootStatus::RtAliasDerivationComplete as u32,
+    ),
+    phase(
+        "Runtime init",
+        FmcBootStatus::RtAliasDerivationComplete as u32,
+        RtBootStatus::RtReadyForCommands as u32,
+    ),
+];
+
+/// Number of cycles spent in a boot phase
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BootPhase {
+    pub name: &'static str,
+    pub cycles: u64,
+}
+
+/// Per-phase breakdown of the most recent cold boot in a boot timeline, as
+/// returned by `HwModel::get_boot_timeline()`. Phases that do not appear in
+/// the timeline are omitted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BootPhases {
+    pub phases: Vec<BootPhase>,
+}
+
+impl BootPhases {
+    pub fn new(entries: &[BootTimelineEntry]) -> Self {
+        let phases = PHASES
+            .iter()
+            .filter_map(|def| {
+                let end = entries
+                    .iter()
+                    .rposition(|e| e.boot_status == def.end_status)?;
+                let start = entries[..end]
+                    .iter()
+                    .rposition(|e| e.boot_status == def.start_status)?;
+                Some(BootPhase {
+                    name: def.name,
+                    cycles: entries[end]
+                        .cycle_count()
+                        .saturating_sub(entries[start].cycle_count()),
+                })
+            })
+            .collect();
+        Self { phases }
+    }
+
+    /// Cycles spent in the phase called `name`, if it was recorded.
+    pub fn cycles(&self, name: &str) -> Option<u64> {
+        self.phases
+            .iter()
+            .find(|phase| phase.name == name)
+            .map(|phase| phase.cycles)
+    }
+}
+
+impl Display for BootPhases {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for phase in &self.phases {
+            writeln!(f, "{:<16}{} cycles", phase.name, PrettyU64(phase.cycles))?;
+        }
+        let total = self.phases.iter().map(|phase| phase.cycles).sum();
+        writeln!(f, "{:<16}{} cycles", "Total", PrettyU64(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(boot_status: u32, cycle_count: u64) -> BootTimelineEntry {
+        BootTimelineEntry {
+            boot_status,
+            cycle_count_low: cycle_count as u32,
+            cycle_count_high: (cycle_count >> 32) as u32,
+        }
+    }
+
+    #[test]
+    fn test_boot_phases() {
+        let entries = [
+            entry(CfiInitialized as u32, 10),
+            entry(KatStarted as u32, 20),
+            entry(KatComplete as u32, 1_020),
+            entry(ColdResetStarted as u32, 1_100),
+            entry(IDevIdDecryptUdsComplete as u32, 1_200),
+            entry(IDevIdClearDoeSecretsComplete as u32, 1_500),
+            entry(IDevIdDerivationComplete as u32, 0x1_0000_0000),
+        ];
+        let phases = BootPhases::new(&entries);
+        assert_eq!(
+            phases.phases,
+            [
+                BootPhase {
+                    name: "KATs",
+                    cycles: 1_000,
+                },
+                BootPhase {
+                    name: "DOE",
+                    cycles: 400,
+                },
+                BootPhase {
+                    name: "IDevID",
+                    cycles: 0x1_0000_0000 - 1_500,
+                },
+            ]
+        );
+        assert_eq!(phases.cycles("DOE"), Some(400));
+        assert_eq!(phases.cycles("LDevID"), None);
+    }
+
+    #[test]
+    fn test_boot_phases_uses_last_boot() {
+        let entries = [
+            entry(KatStarted as u32, 0),
+            entry(KatComplete as u32, 100),
+            entry(KatStarted as u32, 1_000),
+            entry(KatComplete as u32, 1_050),
+        ];
+        assert_eq!(BootPhases::new(&entries).cycles("KATs"), Some(50));
+    }
+}
@@ -26,6 +26,7 @@ use caliptra_registers::soc_ifc::regs::{
 
 use rand::{rngs::StdRng, SeedableRng};
 
+mod boot_timeline;
 pub mod mmio;
 mod model_emulated;
 
@@ -39,6 +40,7 @@ mod model_fpga_realtime;
 mod output;
 mod rv32_builder;
 
+pub use boot_timeline::{BootPhase, BootPhases};
 pub use caliptra_emu_bus::BusMmio;
 pub use caliptra_hw_model_types::{DeviceLifecycle, Fuses, SecurityState, U4};
 use output::ExitStatus;
@@ -258,6 +260,11 @@ pub enum ModelError {
         actual: u32,
     },
     MailboxRespInvalidFipsStatus(u32),
+    MailboxUnexpectedChunk {
+        offset: u32,
+        size: u32,
+        total_size: u32,
+    },
 }
 impl Error for ModelError {}
 impl Display for ModelError {
@@ -320,6 +327,16 @@ impl Display for ModelError {
                     "Mailbox response had non-success FIPS status: 0x{status:x}"
                 )
             }
+            ModelError::MailboxUnexpectedChunk {
+                offset,
+                size,
+                total_size,
+            } => {
+                write!(
+                    f,
+                    "Unexpected mailbox response chunk offset={offset} size={size} total_size={total_size}"
+                )
+            }
         }
     }
 }
@@ -587,6 +604,9 @@ pub trait HwModel {
         self.soc_ifc()
             .fuse_lms_revocation()
             .write(|_| fuses.fuse_lms_revocation);
+        self.soc_ifc()
+            .fuse_fw_recovery_retry_count()
+            .write(|_| fuses.fw_recovery_retry_count);
 
         self.soc_ifc().cptra_fuse_wr_done().write(|w| w.done(true));
         assert!(self.soc_ifc().cptra_fuse_wr_done().read().done());
@@ -786,12 +806,25 @@ pub trait HwModel {
 
     /// Executes a typed request and (if success), returns the typed response.
     /// The checksum field of the request is calculated, and the checksum of the
-    /// response is validated.
+    /// response is validated. Responses sent in chunks are read in full and
+    /// returned reassembled.
     fn mailbox_execute_req<R: api::mailbox::Request>(
+        &mut self,
+        req: R,
+    ) -> std::result::Result<R::Resp, ModelError> {
+        self.mailbox_execute_req_partial(req, mem::size_of::<R>())
+    }
+
+    /// Like `mailbox_execute_req`, but only sends the first `req_len` bytes of
+    /// `req`. Used for requests that end in a variable-length payload.
+    fn mailbox_execute_req_partial<R: api::mailbox::Request>(
         &mut self,
         mut req: R,
+        req_len: usize,
     ) -> std::result::Result<R::Resp, ModelError> {
-        if mem::size_of::<R>() < mem::size_of::<MailboxReqHeader>() {
+        if mem::size_of::<R>() < mem::size_of::<MailboxReqHeader>()
+            || req_len < mem::size_of::<MailboxReqHeader>()
+        {
             return Err(ModelError::MailboxReqTypeTooSmall);
         }
         if mem::size_of::<R::Resp>() < mem::size_of::<MailboxRespHeader>() {
@@ -800,16 +833,16 @@ pub trait HwModel {
         if R::Resp::MIN_SIZE < mem::size_of::<MailboxRespHeader>() {
             return Err(ModelError::MailboxRespTypeTooSmall);
         }
-        let (header_bytes, payload_bytes) = req
-            .as_bytes_mut()
-            .split_at_mut(mem::size_of::<MailboxReqHeader>());
-
-        let mut header = MailboxReqHeader::read_from(header_bytes as &[u8]).unwrap();
-        header.chksum = api::calc_checksum(R::ID.into(), payload_bytes);
-        header_bytes.copy_from_slice(header.as_bytes());
 
-        let Some(response_bytes) = self.mailbox_execute(R::ID.into(), req.as_bytes())? else {
-            return Err(ModelError::MailboxNoResponseData);
+        let response_bytes = if req.chunk_offset_mut().is_some() {
+            self.mailbox_execute_chunks(&mut req, req_len)?
+        } else {
+            self.mailbox_execute_req_bytes(
+                &mut req,
+                req_len,
+                R::Resp::MIN_SIZE,
+                mem::size_of::<R::Resp>(),
+            )?
         };
         if response_bytes.len() < R::Resp::MIN_SIZE
             || response_bytes.len() > mem::size_of::<R::Resp>()
@@ -823,6 +856,40 @@ pub trait HwModel {
 
         let mut response = R::Resp::new_zeroed();
         response.as_bytes_mut()[..response_bytes.len()].copy_from_slice(&response_bytes);
+        Ok(response)
+    }
+
+    /// Sends the first `req_len` bytes of `req` and returns the raw response
+    /// after checking its length is within `resp_min..=resp_max` and
+    /// validating its header.
+    fn mailbox_execute_req_bytes<R: api::mailbox::Request>(
+        &mut self,
+        req: &mut R,
+        req_len: usize,
+        resp_min: usize,
+        resp_max: usize,
+    ) -> std::result::Result<Vec<u8>, ModelError> {
+        let req_bytes = req
+            .as_bytes_mut()
+            .get_mut(..req_len)
+            .ok_or(ModelError::BufferTooLargeForMailbox)?;
+        let (header_bytes, payload_bytes) =
+            req_bytes.split_at_mut(mem::size_of::<MailboxReqHeader>());
+
+        let mut header = MailboxReqHeader::read_from(header_bytes as &[u8]).unwrap();
+        header.chksum = api::calc_checksum(R::ID.into(), payload_bytes);
+        header_bytes.copy_from_slice(header.as_bytes());
+
+        let Some(response_bytes) = self.mailbox_execute(R::ID.into(), req_bytes)? else {
+            return Err(ModelError::MailboxNoResponseData);
+        };
+        if response_bytes.len() < resp_min || response_bytes.len() > resp_max {
+            return Err(ModelError::MailboxUnexpectedResponseLen {
+                expected_min: resp_min as u32,
+                expected_max: resp_max as u32,
+                actual: response_bytes.len() as u32,
+            });
+        }
 
         let response_header =
             MailboxRespHeader::read_from_prefix(response_bytes.as_slice()).unwrap();
@@ -838,7 +905,92 @@ pub trait HwModel {
                 response_header.fips_status,
             ));
         }
-        Ok(response)
+        Ok(response_bytes)
+    }
+
+    /// Executes a request whose response is sent as `ChunkedResp` chunks,
+    /// re-issuing it until the whole payload has been read. Returns the
+    /// response reassembled from the chunks (see
+    /// `Request::chunk_offset_mut`) after checking the payload against the
+    /// payload checksum.
+    fn mailbox_execute_chunks<R: api::mailbox::Request>(
+        &mut self,
+        req: &mut R,
+        req_len: usize,
+    ) -> std::result::Result<Vec<u8>, ModelError> {
+        use api::mailbox::{ChunkedResp, MailboxRespHeaderVarSize};
+
+        let mut payload = vec![];
+        loop {
+            if let Some(offset) = req.chunk_offset_mut() {
+                *offset = payload.len() as u32;
+            }
+            let resp_bytes = self.mailbox_execute_req_bytes(
+                req,
+                req_len,
+                ChunkedResp::MIN_SIZE,
+                mem::size_of::<ChunkedResp>(),
+            )?;
+            let mut resp = ChunkedResp::new_zeroed();
+            resp.as_bytes_mut()[..resp_bytes.len()].copy_from_slice(&resp_bytes);
+
+            let chunk = resp
+                .data()
+                .map_err(|_| ModelError::MailboxUnexpectedResponseLen {
+                    expected_min: 0,
+                    expected_max: ChunkedResp::DATA_MAX_SIZE as u32,
+                    actual: resp.data_size,
+                })?;
+            if resp.offset as usize != payload.len()
+                || payload.len() + chunk.len() > resp.total_size as usize
+            {
+                return Err(ModelError::MailboxUnexpectedChunk {
+                    offset: resp.offset,
+                    size: resp.data_size,
+                    total_size: resp.total_size,
+                });
+            }
+            payload.extend_from_slice(chunk);
+
+            if resp.flags & ChunkedResp::FLAG_MORE == 0 {
+                if payload.len() != resp.total_size as usize {
+                    return Err(ModelError::MailboxUnexpectedChunk {
+                        offset: resp.offset,
+                        size: resp.data_size,
+                        total_size: resp.total_size,
+                    });
+                }
+                let actual_checksum = calc_checksum(0, &payload);
+                if actual_checksum != resp.payload_chksum {
+                    return Err(ModelError::MailboxRespInvalidChecksum {
+                        expected: resp.payload_chksum,
+                        actual: actual_checksum,
+                    });
+                }
+
+                let mut response = MailboxRespHeaderVarSize {
+                    hdr: MailboxRespHeader {
+                        chksum: 0,
+                        fips_status: resp.hdr.fips_status,
+                    },
+                    data_len: resp.total_size,
+                }
+                .as_bytes()
+                .to_vec();
+                response.extend_from_slice(&payload);
+                let chksum = calc_checksum(0, &response[4..]);
+                response[..4].copy_from_slice(&chksum.to_le_bytes());
+                return Ok(response);
+            }
+            if chunk.is_empty() {
+                // No progress would be made with the next request.
+                return Err(ModelError::MailboxUnexpectedChunk {
+                    offset: resp.offset,
+                    size: resp.data_size,
+                    total_size: resp.total_size,
+                });
+            }
+        }
     }
 
     /// Executes `cmd` with request data `buf`. Returns `Ok(Some(_))` if
@@ -1067,6 +1219,250 @@ pub trait HwModel {
 
         Ok(())
     }
+
+    /// Request a quote over all PCRs, bound to `nonce` and signed by the RT
+    /// alias key.
+    fn get_pcr_quote(
+        &mut self,
+        nonce: [u8; 32],
+    ) -> Result<api::mailbox::GetPcrQuoteResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetPcrQuoteReq {
+            hdr: MailboxReqHeader::default(),
+            nonce,
+        })
+    }
+
+    /// Executes `LMS_VERIFY`, asking the runtime to verify an RFC 8554
+    /// `signature` over `message` with `pub_key`.
+    fn lms_verify(
+        &mut self,
+        pub_key: &[u8],
+        signature: &[u8],
+        message: &[u8],
+    ) -> Result<(), ModelError> {
+        use api::mailbox::LmsVerifyReq;
+
+        let mut req = LmsVerifyReq {
+            signature_size: signature.len() as u32,
+            message_size: message.len() as u32,
+            ..Default::default()
+        };
+        req.pub_key
+            .get_mut(..pub_key.len())
+            .ok_or(ModelError::BufferTooLargeForMailbox)?
+            .copy_from_slice(pub_key);
+        req.signature
+            .get_mut(..signature.len())
+            .ok_or(ModelError::BufferTooLargeForMailbox)?
+            .copy_from_slice(signature);
+        req.message
+            .get_mut(..message.len())
+            .ok_or(ModelError::BufferTooLargeForMailbox)?
+            .copy_from_slice(message);
+        self.mailbox_execute_req(req)?;
+        Ok(())
+    }
+
+    /// Executes `SHA_DIGEST`, asking the runtime to hash `data` with
+    /// `hash_algorithm` (one of `ShaDigestReq::HASH_ALGORITHM_*`). If `pcr_idx`
+    /// is set, the digest is also extended into that SoC PCR.
+    fn sha_digest(
+        &mut self,
+        hash_algorithm: u32,
+        data: &[u8],
+        pcr_idx: Option<u32>,
+    ) -> Result<Vec<u8>, ModelError> {
+        use api::mailbox::{ResponseVarSize, ShaDigestReq, ShaDigestResp};
+
+        let mut req = ShaDigestReq {
+            hash_algorithm,
+            flags: if pcr_idx.is_some() {
+                ShaDigestReq::FLAG_EXTEND_PCR
+            } else {
+                0
+            },
+            pcr_idx: pcr_idx.unwrap_or_default(),
+            data_size: data.len() as u32,
+            ..Default::default()
+        };
+        req.data
+            .get_mut(..data.len())
+            .ok_or(ModelError::BufferTooLargeForMailbox)?
+            .copy_from_slice(data);
+        let req_len = mem::size_of::<ShaDigestReq>() - (ShaDigestReq::DATA_MAX_SIZE - data.len());
+        let resp = self.mailbox_execute_req_partial(req, req_len)?;
+        let digest = resp
+            .data()
+            .map_err(|_| ModelError::MailboxUnexpectedResponseLen {
+                expected_min: 0,
+                expected_max: ShaDigestResp::DATA_MAX_SIZE as u32,
+                actual: resp.data_size,
+            })?;
+        Ok(digest.to_vec())
+    }
+
+    /// Executes `SHA_INIT`, starting a streaming digest of `data_len` bytes
+    /// sent with `sha_update`. If `pcr_idx` is set, the digest is also
+    /// extended into that SoC PCR by `sha_final`.
+    fn sha_init(
+        &mut self,
+        hash_algorithm: u32,
+        data_len: u32,
+        pcr_idx: Option<u32>,
+    ) -> Result<(), ModelError> {
+        use api::mailbox::{ShaDigestReq, ShaInitReq};
+
+        self.mailbox_execute_req(ShaInitReq {
+            hash_algorithm,
+            flags: if pcr_idx.is_some() {
+                ShaDigestReq::FLAG_EXTEND_PCR
+            } else {
+                0
+            },
+            pcr_idx: pcr_idx.unwrap_or_default(),
+            data_len,
+            ..Default::default()
+        })?;
+        Ok(())
+    }
+
+    /// Executes `SHA_UPDATE`, adding `data` to the streaming digest.
+    fn sha_update(&mut self, data: &[u8]) -> Result<(), ModelError> {
+        use api::mailbox::ShaUpdateReq;
+
+        let mut req = ShaUpdateReq {
+            data_size: data.len() as u32,
+            ..Default::default()
+        };
+        req.data
+            .get_mut(..data.len())
+            .ok_or(ModelError::BufferTooLargeForMailbox)?
+            .copy_from_slice(data);
+        let req_len = mem::size_of::<ShaUpdateReq>() - (ShaUpdateReq::DATA_MAX_SIZE - data.len());
+        self.mailbox_execute_req_partial(req, req_len)?;
+        Ok(())
+    }
+
+    /// Executes `SHA_FINAL` and returns the digest of the streamed data.
+    fn sha_final(&mut self) -> Result<Vec<u8>, ModelError> {
+        use api::mailbox::{ResponseVarSize, ShaDigestResp, ShaFinalReq};
+
+        let resp = self.mailbox_execute_req(ShaFinalReq::default())?;
+        let digest = resp
+            .data()
+            .map_err(|_| ModelError::MailboxUnexpectedResponseLen {
+                expected_min: 0,
+                expected_max: ShaDigestResp::DATA_MAX_SIZE as u32,
+                actual: resp.data_size,
+            })?;
+        Ok(digest.to_vec())
+    }
+
+    /// Executes `GET_PCR_LOG` and returns the PCR log recorded so far.
+    fn get_pcr_log(&mut self) -> Result<api::mailbox::GetPcrLogResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetPcrLogReq::default())
+    }
+
+    /// Executes `GET_FUSE_LOG` and returns the populated fuse log entries.
+    fn get_fuse_log(&mut self) -> Result<api::mailbox::GetFuseLogResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetFuseLogReq::default())
+    }
+
+    /// Executes `GET_MEASUREMENT_LOG` and returns the stashed measurements.
+    fn get_measurement_log(&mut self) -> Result<api::mailbox::GetMeasurementLogResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetMeasurementLogReq::default())
+    }
+
+    /// Executes `GET_BOOT_TIMELINE` and returns the boot statuses reported
+    /// by ROM, FMC and runtime along with the cycle count at which each was
+    /// reported. Use [`BootPhases`] for a per-phase breakdown.
+    fn get_boot_timeline(&mut self) -> Result<api::mailbox::GetBootTimelineResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetBootTimelineReq::default())
+    }
+
+    /// Executes `GET_EVENT_LOG` and returns the whole CEL-encoded event log.
+    /// Use [`api::event_log::ReplayedPcrs`] to check it against a PCR quote.
+    fn get_event_log(&mut self) -> Result<Vec<u8>, ModelError> {
+        use api::mailbox::{GetEventLogReq, ResponseVarSize};
+
+        // The length of the reassembled response has already been checked.
+        let resp = self.mailbox_execute_req(GetEventLogReq::default())?;
+        Ok(resp.data().unwrap().to_vec())
+    }
+
+    /// Executes `CAPABILITIES` and decodes the result. Works against both ROM
+    /// and runtime. Bits unknown to this crate are dropped, so hosts built
+    /// against older definitions can still negotiate with newer firmware.
+    fn capabilities(&mut self) -> Result<api::Capabilities, ModelError> {
+        let resp = self.mailbox_execute_req(api::mailbox::CapabilitiesReq::default())?;
+        Ok(api::Capabilities::from_bits_truncate(u128::from_be_bytes(
+            resp.capabilities,
+        )))
+    }
+
+    /// Executes `GET_IDEV_CSR` and returns the IDevID CSR retained by ROM.
+    fn get_idev_csr(&mut self) -> Result<api::mailbox::GetIdevCsrResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetIdevCsrReq::default())
+    }
+
+    /// Executes `GET_LDEV_CERT` and returns the DER-encoded LDevID cert.
+    fn get_ldev_cert(&mut self) -> Result<api::mailbox::GetLdevCertResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetLdevCertReq::default())
+    }
+
+    /// Executes `GET_FMC_ALIAS_CERT` and returns the DER-encoded FMC alias cert.
+    fn get_fmc_alias_cert(&mut self) -> Result<api::mailbox::GetFmcAliasCertResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetFmcAliasCertReq::default())
+    }
+
+    /// Executes `GET_RT_ALIAS_CERT` and returns the DER-encoded RT alias cert.
+    fn get_rt_alias_cert(&mut self) -> Result<api::mailbox::GetRtAliasCertResp, ModelError> {
+        self.mailbox_execute_req(api::mailbox::GetRtAliasCertReq::default())
+    }
+
+    /// Reads the whole cert chain with as many `GET_CERT_CHAIN` requests as
+    /// needed.
+    fn get_cert_chain(&mut self) -> Result<Vec<u8>, ModelError> {
+        use api::mailbox::{GetCertChainReq, ResponseVarSize};
+
+        // The length of the reassembled response has already been checked.
+        let resp = self.mailbox_execute_req(GetCertChainReq::default())?;
+        Ok(resp.data().unwrap().to_vec())
+    }
+
+    /// Executes `SET_AUTH_MANIFEST` with a serialized authorization manifest.
+    fn set_auth_manifest(&mut self, manifest: &[u8]) -> Result<(), ModelError> {
+        use api::mailbox::SetAuthManifestReq;
+
+        let mut req = SetAuthManifestReq::default();
+        if manifest.len() != req.manifest.len() {
+            return Err(ModelError::BufferTooLargeForMailbox);
+        }
+        req.manifest.copy_from_slice(manifest);
+        self.mailbox_execute_req(req)?;
+        Ok(())
+    }
+
+    /// Executes `AUTHORIZE_AND_STASH` and returns whether the image with
+    /// digest `measurement` was authorized by the current manifest.
+    fn authorize_and_stash(
+        &mut self,
+        fw_id: [u8; 4],
+        measurement: [u8; 48],
+        context: [u8; 48],
+        flags: u32,
+    ) -> Result<bool, ModelError> {
+        use api::mailbox::{AuthorizeAndStashReq, AuthorizeAndStashResp};
+
+        let resp = self.mailbox_execute_req(AuthorizeAndStashReq {
+            fw_id,
+            measurement,
+            context,
+            flags,
+            ..Default::default()
+        })?;
+        Ok(resp.auth_req_result == AuthorizeAndStashResp::AUTHORIZED)
+    }
 }
 
 #[cfg(test)]
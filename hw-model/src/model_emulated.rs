@@ -141,7 +141,8 @@ impl crate::HwModel for ModelEmulated {
             dccm_dest.copy_from_slice(params.dccm);
         }
         let soc_to_caliptra_bus = root_bus.soc_to_caliptra_bus();
-        let cpu = Cpu::new(BusLogger::new(root_bus), clock);
+        let pic = root_bus.pic.clone();
+        let cpu = Cpu::new(BusLogger::new(root_bus), clock, pic);
 
         let mut hasher = DefaultHasher::new();
         std::hash::Hash::hash_slice(params.rom, &mut hasher);
@@ -23,7 +23,7 @@ struct OutputSinkImpl {
     next_write_needs_time_prefix: Cell<bool>,
 }
 
-struct PrettyU64(u64);
+pub(crate) struct PrettyU64(pub(crate) u64);
 impl Display for PrettyU64 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         const RANKS: [u64; 7] = [
@@ -27,6 +27,11 @@ pub const DEFAULT_CPTRA_OBF_KEY: [u32; 8] = [
 
 // Based on device_lifecycle_e from RTL
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum DeviceLifecycle {
     #[default]
     Unprovisioned = 0b00,
@@ -158,7 +163,32 @@ impl TryFrom<u32> for U4 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for U4 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32((*self).into())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for U4 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u32::deserialize(deserializer)?;
+        Self::try_from(value)
+            .map_err(|_| serde::de::Error::custom(format!("{value} does not fit in 4 bits")))
+    }
+}
+
+/// Fuse values
+///
+/// With the `serde` feature, fuse files can be loaded directly into this
+/// structure; fields missing from the file keep their default value.
 #[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize),
+    serde(default)
+)]
 pub struct Fuses {
     pub uds_seed: [u32; 12],
     pub field_entropy: [u32; 8],
@@ -173,6 +203,7 @@ pub struct Fuses {
     pub life_cycle: DeviceLifecycle,
     pub lms_verify: bool,
     pub fuse_lms_revocation: u32,
+    pub fw_recovery_retry_count: u32,
 }
 impl Default for Fuses {
     fn default() -> Self {
@@ -190,6 +221,7 @@ impl Default for Fuses {
             life_cycle: Default::default(),
             lms_verify: Default::default(),
             fuse_lms_revocation: Default::default(),
+            fw_recovery_retry_count: Default::default(),
         }
     }
 }
@@ -0,0 +1,44 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    build.rs
+
+Abstract:
+
+    Build script for the Caliptra Image Tool. Collects the image verifier
+    error names from the error crate.
+
+--*/
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let errors_path =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../../error/src/lib.rs");
+    let errors = fs::read_to_string(&errors_path).expect("Unable to read the error definitions");
+
+    let mut names = String::from("error_names! {\n");
+    for line in errors.lines() {
+        let Some(decl) = line.trim().strip_prefix("pub const ") else {
+            continue;
+        };
+        let Some((name, _)) = decl.split_once(':') else {
+            continue;
+        };
+        if name.starts_with("IMAGE_VERIFIER_ERR_") {
+            names += &format!("    {name},\n");
+        }
+    }
+    names += "}\n";
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("error_names.rs"), names).expect("Unable to generate error_names.rs");
+
+    println!("cargo:rerun-if-changed={}", errors_path.display());
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,194 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image signature assembly command.
+
+--*/
+
+use anyhow::Context;
+use caliptra_image_openssl::{ecc_sig_from_file, lms_sig_from_file};
+use caliptra_image_serde::{ImageBundleReader, ImageBundleWriter};
+use caliptra_image_types::{ImageEccSignature, ImageLmsSignature, ImageManifest};
+use clap::ArgMatches;
+use std::fs::File;
+use std::path::PathBuf;
+
+use crate::verify::{verify_owner_sigs, verify_vendor_sigs};
+
+/// Signatures produced outside of the image tool
+#[derive(Clone, Copy, Default)]
+struct ImportedSigs {
+    vendor_ecc_sig: ImageEccSignature,
+    vendor_lms_sig: Option<ImageLmsSignature>,
+    owner_ecc_sig: Option<ImageEccSignature>,
+    owner_lms_sig: Option<ImageLmsSignature>,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let vendor_ecc_sig_path: &PathBuf = args
+        .get_one::<PathBuf>("vendor-ecc-sig")
+        .with_context(|| "vendor-ecc-sig arg not specified")?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    let file = File::open(image_path)
+        .with_context(|| format!("Failed to open the image {}", image_path.display()))?;
+    let mut image = ImageBundleReader::new(file).read()?;
+
+    let sigs = ImportedSigs {
+        vendor_ecc_sig: ecc_sig_from_file(vendor_ecc_sig_path)?,
+        vendor_lms_sig: args
+            .get_one::<PathBuf>("vendor-lms-sig")
+            .map(lms_sig_from_file)
+            .transpose()?,
+        owner_ecc_sig: args
+            .get_one::<PathBuf>("owner-ecc-sig")
+            .map(ecc_sig_from_file)
+            .transpose()?,
+        owner_lms_sig: args
+            .get_one::<PathBuf>("owner-lms-sig")
+            .map(lms_sig_from_file)
+            .transpose()?,
+    };
+    assemble(&mut image.manifest, &sigs)?;
+
+    let out_file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(out_path)
+        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+
+    let mut writer = ImageBundleWriter::new(out_file);
+    writer.write(&image)?;
+
+    Ok(())
+}
+
+/// Write the imported signatures into `manifest`
+///
+/// Each signature is verified against the header digest and the public keys
+/// in the preamble before it is written.
+fn assemble(manifest: &mut ImageManifest, sigs: &ImportedSigs) -> anyhow::Result<()> {
+    let mut signed = *manifest;
+    let preamble = &mut signed.preamble;
+    preamble.vendor_sigs.ecc_sig = sigs.vendor_ecc_sig;
+    if let Some(sig) = sigs.vendor_lms_sig {
+        preamble.vendor_sigs.lms_sig = sig;
+    }
+    if let Some(sig) = sigs.owner_ecc_sig {
+        preamble.owner_sigs.ecc_sig = sig;
+    }
+    if let Some(sig) = sigs.owner_lms_sig {
+        preamble.owner_sigs.lms_sig = sig;
+    }
+
+    verify_vendor_sigs(&signed, sigs.vendor_lms_sig.is_some())?;
+    verify_owner_sigs(
+        &signed,
+        sigs.owner_ecc_sig.is_some(),
+        sigs.owner_lms_sig.is_some(),
+    )?;
+
+    *manifest = signed;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_image;
+    use zerocopy::AsBytes;
+
+    /// Corruption applied to the imported signatures
+    type Corruption = fn(&mut ImportedSigs);
+
+    /// `manifest` with its signatures split off
+    fn unsigned(manifest: &ImageManifest) -> (ImageManifest, ImportedSigs) {
+        let preamble = &manifest.preamble;
+        let sigs = ImportedSigs {
+            vendor_ecc_sig: preamble.vendor_sigs.ecc_sig,
+            vendor_lms_sig: Some(preamble.vendor_sigs.lms_sig),
+            owner_ecc_sig: Some(preamble.owner_sigs.ecc_sig),
+            owner_lms_sig: Some(preamble.owner_sigs.lms_sig),
+        };
+        let mut unsigned = *manifest;
+        unsigned.preamble.vendor_sigs = Default::default();
+        unsigned.preamble.owner_sigs = Default::default();
+        (unsigned, sigs)
+    }
+
+    #[test]
+    fn test_assemble() {
+        let signed = test_image::image_bundle().manifest;
+        let (mut manifest, sigs) = unsigned(&signed);
+        assemble(&mut manifest, &sigs).unwrap();
+        assert_eq!(manifest.as_bytes(), signed.as_bytes());
+    }
+
+    #[test]
+    fn test_assemble_vendor_ecc_only() {
+        let signed = test_image::image_bundle().manifest;
+        let (mut manifest, sigs) = unsigned(&signed);
+        let sigs = ImportedSigs {
+            vendor_ecc_sig: sigs.vendor_ecc_sig,
+            ..Default::default()
+        };
+        assemble(&mut manifest, &sigs).unwrap();
+        assert_eq!(manifest.preamble.vendor_sigs.ecc_sig, sigs.vendor_ecc_sig);
+    }
+
+    #[test]
+    fn test_assemble_invalid_sigs() {
+        let signed = test_image::image_bundle().manifest;
+        let (manifest, sigs) = unsigned(&signed);
+
+        let corrupt: [(Corruption, &str); 4] = [
+            (
+                |sigs| sigs.vendor_ecc_sig.r[0] ^= 1,
+                "Vendor ECC signature does not verify",
+            ),
+            (
+                |sigs| {
+                    let sig = sigs.vendor_lms_sig.as_mut().unwrap();
+                    sig.q = (sig.q.get() + 1).into();
+                },
+                "Vendor LMS signature does not verify",
+            ),
+            (
+                |sigs| sigs.owner_ecc_sig.as_mut().unwrap().s[0] ^= 1,
+                "Owner ECC signature does not verify",
+            ),
+            (
+                |sigs| {
+                    let sig = sigs.owner_lms_sig.as_mut().unwrap();
+                    sig.q = (sig.q.get() + 1).into();
+                },
+                "Owner LMS signature does not verify",
+            ),
+        ];
+        for (corrupt, msg) in corrupt {
+            let mut sigs = sigs;
+            corrupt(&mut sigs);
+            let mut out = manifest;
+            let err = assemble(&mut out, &sigs).unwrap_err();
+            assert_eq!(err.to_string(), msg);
+            // Nothing is written into the manifest.
+            assert_eq!(out.as_bytes(), manifest.as_bytes());
+        }
+    }
+}
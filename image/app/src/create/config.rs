@@ -13,7 +13,7 @@ Abstract:
 --*/
 
 use anyhow::Context;
-use caliptra_image_types::{VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT};
+use caliptra_image_types::{ImageTocEntryId, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT};
 use serde_derive::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -59,3 +59,75 @@ pub(crate) fn load_key_config(path: &PathBuf) -> anyhow::Result<KeyConfig> {
 
     Ok(config)
 }
+
+/// Owner Key Configuration file
+///
+/// Any vendor keys present in the file are ignored.
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct OwnerKeyConfigFile {
+    pub owner: OwnerKeyConfig,
+}
+
+/// Load the owner keys of a Key Configuration file
+pub(crate) fn load_owner_key_config(path: &PathBuf) -> anyhow::Result<OwnerKeyConfig> {
+    let config_str = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+    let config: OwnerKeyConfigFile = toml::from_str(&config_str)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+
+    Ok(config.owner)
+}
+
+/// Auxiliary Image Identifier
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum AuxImageId {
+    DpeConfig,
+    SocPolicy,
+}
+
+impl From<AuxImageId> for ImageTocEntryId {
+    fn from(id: AuxImageId) -> Self {
+        match id {
+            AuxImageId::DpeConfig => ImageTocEntryId::DpeConfig,
+            AuxImageId::SocPolicy => ImageTocEntryId::SocPolicy,
+        }
+    }
+}
+
+/// Auxiliary Image Configuration
+#[derive(Serialize, Deserialize)]
+pub(crate) struct AuxImageConfig {
+    pub id: AuxImageId,
+
+    pub path: String,
+
+    pub version: u32,
+
+    pub svn: u32,
+
+    pub min_svn: u32,
+
+    pub rev: Option<String>,
+
+    pub load_addr: u32,
+}
+
+/// Auxiliary Images Configuration
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct AuxConfig {
+    #[serde(default)]
+    pub aux: Vec<AuxImageConfig>,
+}
+
+/// Load Auxiliary Images Configuration from file
+pub(crate) fn load_aux_config(path: &PathBuf) -> anyhow::Result<AuxConfig> {
+    let config_str = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+    let config: AuxConfig = toml::from_str(&config_str)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+
+    Ok(config)
+}
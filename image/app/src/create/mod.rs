@@ -12,7 +12,7 @@ Abstract:
 
 --*/
 
-mod config;
+pub(crate) mod config;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -21,6 +21,9 @@ use caliptra_image_openssl::ecc_priv_key_from_pem;
 use caliptra_image_openssl::ecc_pub_key_from_pem;
 use caliptra_image_openssl::lms_priv_key_from_pem;
 use caliptra_image_openssl::lms_pub_key_from_pem;
+use caliptra_image_openssl::lms_state_path;
+use caliptra_image_openssl::ExternalSignerCrypto;
+use caliptra_image_openssl::StatefulLmsCrypto;
 use caliptra_image_serde::ImageBundleWriter;
 use caliptra_image_types::*;
 use clap::ArgMatches;
@@ -28,7 +31,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use caliptra_image_elf::ElfExecutable;
-use config::{OwnerKeyConfig, VendorKeyConfig};
+use config::{AuxConfig, OwnerKeyConfig, VendorKeyConfig};
 
 use chrono::NaiveDate;
 
@@ -70,6 +73,29 @@ fn check_date(from_date: &str, to_date: &str) -> anyhow::Result<bool> {
     Ok(true)
 }
 
+/// Parse the validity dates passed in `from_arg` and `to_arg`
+///
+/// The dates are left unset unless both are specified.
+pub(crate) fn validity_dates(
+    args: &ArgMatches,
+    from_arg: &str,
+    to_arg: &str,
+) -> anyhow::Result<([u8; 15], [u8; 15])> {
+    //YYYYMMDDHHMMSS - Zulu Time
+    let mut from_date: [u8; 15] = [0u8; 15];
+    let mut to_date: [u8; 15] = [0u8; 15];
+    if let Some(from) = args.get_one::<String>(from_arg) {
+        if let Some(to) = args.get_one::<String>(to_arg) {
+            check_date(from, to)?;
+            from_date[0..14].copy_from_slice(&from.as_bytes()[0..14]);
+            from_date[14] = b'Z';
+            to_date[0..14].copy_from_slice(&to.as_bytes()[0..14]);
+            to_date[14] = b'Z';
+        }
+    }
+    Ok((from_date, to_date))
+}
+
 /// Run the command
 pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
     let config_path: &PathBuf = args
@@ -128,31 +154,8 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         .get_one::<PathBuf>("out")
         .with_context(|| "out arg not specified")?;
 
-    //YYYYMMDDHHMMSS - Zulu Time
-    let mut own_from_date: [u8; 15] = [0u8; 15];
-    let mut own_to_date: [u8; 15] = [0u8; 15];
-    if let Some(from_date) = args.get_one::<String>("own-from-date") {
-        if let Some(to_date) = args.get_one::<String>("own-to-date") {
-            check_date(from_date, to_date)?;
-            own_from_date[0..14].copy_from_slice(&from_date.as_bytes()[0..14]);
-            own_from_date[14] = b'Z';
-            own_to_date[0..14].copy_from_slice(&to_date.as_bytes()[0..14]);
-            own_to_date[14] = b'Z';
-        }
-    }
-
-    //YYYYMMDDHHMMSS - Zulu Time
-    let mut mfg_from_date: [u8; 15] = [0u8; 15];
-    let mut mfg_to_date: [u8; 15] = [0u8; 15];
-    if let Some(from_date) = args.get_one::<String>("mfg-from-date") {
-        if let Some(to_date) = args.get_one::<String>("mfg-to-date") {
-            check_date(from_date, to_date)?;
-            mfg_from_date[0..14].copy_from_slice(&from_date.as_bytes()[0..14]);
-            mfg_from_date[14] = b'Z';
-            mfg_to_date[0..14].clone_from_slice(&to_date.as_bytes()[0..14]);
-            mfg_to_date[14] = b'Z';
-        }
-    }
+    let (own_from_date, own_to_date) = validity_dates(args, "own-from-date", "own-to-date")?;
+    let (mfg_from_date, mfg_to_date) = validity_dates(args, "mfg-from-date", "mfg-to-date")?;
 
     let config = config::load_key_config(config_path)?;
 
@@ -178,8 +181,21 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         .parent()
         .with_context(|| "Invalid parent path")?;
 
+    let aux = match args.get_one::<PathBuf>("aux-config") {
+        Some(aux_config_path) => {
+            let aux_config = config::load_aux_config(aux_config_path)?;
+            let aux_config_dir = aux_config_path
+                .parent()
+                .with_context(|| "Invalid parent path")?;
+            aux_images(aux_config_dir, &aux_config)?
+        }
+        None => Vec::new(),
+    };
+
+    let mut crypto = StatefulLmsCrypto::default();
     let gen_config = ImageGeneratorConfig::<ElfExecutable> {
         vendor_config: vendor_config(
+            &mut crypto,
             config_dir,
             &config.vendor,
             *ecc_key_idx,
@@ -187,13 +203,34 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
             mfg_from_date,
             mfg_to_date,
         )?,
-        owner_config: owner_config(config_dir, &config.owner, own_from_date, own_to_date)?,
+        owner_config: owner_config(
+            &mut crypto,
+            config_dir,
+            &config.owner,
+            own_from_date,
+            own_to_date,
+        )?,
         fmc,
         runtime,
+        aux,
     };
 
-    let gen = ImageGenerator::new(caliptra_image_openssl::OsslCrypto::default());
-    let image = gen.generate(&gen_config).unwrap();
+    let image = match args.get_one::<PathBuf>("signer") {
+        Some(signer) => {
+            let signer_args = args
+                .get_many::<String>("signer-arg")
+                .map(|args| args.cloned().collect())
+                .unwrap_or_default();
+            let mut gen_config = gen_config;
+            signer_key_handles(&mut gen_config);
+            let gen = ImageGenerator::new(ExternalSignerCrypto::new(signer, signer_args));
+            gen.generate(&gen_config)?
+        }
+        None => {
+            let gen = ImageGenerator::new(crypto);
+            gen.generate(&gen_config)?
+        }
+    };
 
     let out_file = std::fs::OpenOptions::new()
         .create(true)
@@ -208,8 +245,60 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Replace the private keys with the key handles resolved by the external signer
+fn signer_key_handles(config: &mut ImageGeneratorConfig<ElfExecutable>) {
+    let vendor_config = &mut config.vendor_config;
+    let mut priv_keys = ImageVendorPrivKeys::default();
+    for (handle, pub_key) in priv_keys
+        .lms_priv_keys
+        .iter_mut()
+        .zip(vendor_config.pub_keys.lms_pub_keys.iter())
+    {
+        *handle = ExternalSignerCrypto::lms_key_handle(pub_key);
+    }
+    vendor_config.priv_keys = Some(priv_keys);
+
+    if let Some(owner_config) = &mut config.owner_config {
+        owner_config.priv_keys = Some(ImageOwnerPrivKeys {
+            lms_priv_key: ExternalSignerCrypto::lms_key_handle(&owner_config.pub_keys.lms_pub_key),
+            ..Default::default()
+        });
+    }
+}
+
+/// Generate Auxiliary Images
+fn aux_images(path: &Path, config: &AuxConfig) -> anyhow::Result<Vec<ImageGeneratorAuxImage>> {
+    let mut aux_images = Vec::with_capacity(config.aux.len());
+
+    for aux in &config.aux {
+        let aux_path = path.join(&aux.path);
+        let content = std::fs::read(&aux_path)
+            .with_context(|| format!("Failed to read the aux image {}", aux_path.display()))?;
+
+        let mut rev = ImageRevision::default();
+        if let Some(aux_rev) = &aux.rev {
+            let aux_rev = hex::decode(aux_rev)?;
+            rev = aux_rev[..IMAGE_REVISION_BYTE_SIZE].try_into()?;
+        }
+
+        aux_images.push(ImageGeneratorAuxImage {
+            id: aux.id.into(),
+            r#type: ImageTocEntryType::Data,
+            version: aux.version,
+            svn: aux.svn,
+            min_svn: aux.min_svn,
+            rev,
+            load_addr: aux.load_addr,
+            content,
+        });
+    }
+
+    Ok(aux_images)
+}
+
 /// Generate Vendor Config
 fn vendor_config(
+    crypto: &mut StatefulLmsCrypto,
     path: &Path,
     config: &VendorKeyConfig,
     ecc_key_idx: u32,
@@ -261,6 +350,7 @@ fn vendor_config(
         {
             let priv_key_path = path.join(pem_file);
             priv_keys.lms_priv_keys[i] = lms_priv_key_from_pem(&priv_key_path)?;
+            crypto.add_lms_state(&priv_keys.lms_priv_keys[i], lms_state_path(&priv_key_path));
         }
         gen_config.priv_keys = Some(priv_keys);
     }
@@ -274,7 +364,8 @@ fn vendor_config(
 }
 
 /// Generate owner config
-fn owner_config(
+pub(crate) fn owner_config(
+    crypto: &mut StatefulLmsCrypto,
     path: &Path,
     config: &Option<OwnerKeyConfig>,
     from_date: [u8; 15],
@@ -302,6 +393,7 @@ fn owner_config(
         if let Some(pem_file) = &config.lms_priv_key {
             let priv_key_path = path.join(pem_file);
             priv_keys.lms_priv_key = lms_priv_key_from_pem(&priv_key_path)?;
+            crypto.add_lms_state(&priv_keys.lms_priv_key, lms_state_path(&priv_key_path));
             gen_config.priv_keys = Some(priv_keys);
         }
         gen_config.not_before = from_date;
@@ -0,0 +1,60 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image header digest command.
+
+--*/
+
+use anyhow::Context;
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_serde::ImageBundleReader;
+use caliptra_image_types::ImageDigest;
+use clap::ArgMatches;
+use std::fs::File;
+use std::path::PathBuf;
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let file = File::open(image_path)
+        .with_context(|| format!("Failed to open the image {}", image_path.display()))?;
+    let image = ImageBundleReader::new(file).read()?;
+
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let vendor_digest = digest_bytes(&gen.header_digest_vendor(&image.manifest.header)?);
+    let owner_digest = digest_bytes(&gen.header_digest_owner(&image.manifest.header)?);
+
+    println!("Vendor header digest: {}", hex::encode(&vendor_digest));
+    println!("Owner header digest: {}", hex::encode(&owner_digest));
+
+    if let Some(path) = args.get_one::<PathBuf>("vendor-digest") {
+        write_digest(path, &vendor_digest)?;
+    }
+
+    if let Some(path) = args.get_one::<PathBuf>("owner-digest") {
+        write_digest(path, &owner_digest)?;
+    }
+
+    Ok(())
+}
+
+/// Convert the digest to the big-endian byte string that is signed
+fn digest_bytes(digest: &ImageDigest) -> Vec<u8> {
+    digest.iter().flat_map(|word| word.to_be_bytes()).collect()
+}
+
+fn write_digest(path: &PathBuf, digest: &[u8]) -> anyhow::Result<()> {
+    std::fs::write(path, digest)
+        .with_context(|| format!("Failed to write digest file {}", path.display()))
+}
@@ -0,0 +1,218 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image fuse values command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_hw_model_types::U4;
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::{ecc_pub_key_from_pem, lms_pub_key_from_pem, OsslCrypto};
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use serde_derive::Serialize;
+use std::path::{Path, PathBuf};
+use zerocopy::{AsBytes, FromBytes};
+
+use crate::create::config::{self, KeyConfig};
+
+/// Fuse values derived from the public keys
+///
+/// Field names and encodings match `caliptra_hw_model::Fuses`, so the output
+/// can be loaded as a fuse file by the hardware model, the emulator and the
+/// `verify` command.
+#[derive(Serialize)]
+struct FuseValues {
+    key_manifest_pk_hash: ImageDigest,
+    key_manifest_pk_hash_mask: U4,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner_pk_hash: Option<ImageDigest>,
+    lms_verify: bool,
+    fuse_lms_revocation: u32,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let format: &String = args
+        .get_one::<String>("format")
+        .with_context(|| "format arg not specified")?;
+
+    let preamble = match (
+        args.get_one::<PathBuf>("key-config"),
+        args.get_one::<PathBuf>("image"),
+    ) {
+        (Some(config_path), None) => {
+            let config = config::load_key_config(config_path)?;
+            let config_dir = config_path
+                .parent()
+                .with_context(|| "Invalid parent path")?;
+            key_config_preamble(config_dir, &config)?
+        }
+        (None, Some(image_path)) => {
+            let image = std::fs::read(image_path)
+                .with_context(|| format!("Failed to read the image {}", image_path.display()))?;
+            ImageManifest::read_from_prefix(image.as_slice())
+                .with_context(|| "Image is smaller than the manifest")?
+                .preamble
+        }
+        _ => return Err(anyhow!("Specify exactly one of key-config and image")),
+    };
+
+    let fuses = fuse_values(
+        &preamble,
+        revocation_mask(args, "revoke-ecc-key", VENDOR_ECC_KEY_COUNT)?,
+        revocation_mask(args, "revoke-lms-key", VENDOR_LMS_KEY_COUNT)?,
+        args.get_flag("lms-verify"),
+    )?;
+    let out = format_fuse_values(&fuses, format)?;
+
+    match args.get_one::<PathBuf>("out") {
+        Some(out_path) => std::fs::write(out_path, out)
+            .with_context(|| format!("Failed to write {}", out_path.display()))?,
+        None => println!("{out}"),
+    }
+
+    Ok(())
+}
+
+/// Derive the fuse values from the public keys of `preamble`
+fn fuse_values(
+    preamble: &ImagePreamble,
+    ecc_revocation: u32,
+    lms_revocation: u32,
+    lms_verify: bool,
+) -> anyhow::Result<FuseValues> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let owner_keys_present = preamble.owner_pub_keys.as_bytes().iter().any(|b| *b != 0);
+    Ok(FuseValues {
+        key_manifest_pk_hash: gen.vendor_pubkey_digest(preamble)?,
+        key_manifest_pk_hash_mask: U4::try_from(ecc_revocation)
+            .map_err(|_| anyhow!("Invalid ECC revocation mask"))?,
+        owner_pk_hash: if owner_keys_present {
+            Some(gen.owner_pubkey_digest(preamble)?)
+        } else {
+            None
+        },
+        lms_verify,
+        fuse_lms_revocation: lms_revocation,
+    })
+}
+
+/// Serialize the fuse values in `format`
+fn format_fuse_values(fuses: &FuseValues, format: &str) -> anyhow::Result<String> {
+    match format {
+        "json" => Ok(serde_json::to_string_pretty(fuses)?),
+        "toml" => Ok(toml::to_string(fuses)?),
+        _ => Err(anyhow!("Unsupported output format {format}")),
+    }
+}
+
+/// Build a preamble holding the public keys of the key configuration
+fn key_config_preamble(path: &Path, config: &KeyConfig) -> anyhow::Result<ImagePreamble> {
+    let mut preamble = ImagePreamble::default();
+
+    for (pub_key, pem_file) in preamble
+        .vendor_pub_keys
+        .ecc_pub_keys
+        .iter_mut()
+        .zip(&config.vendor.ecc_pub_keys)
+    {
+        *pub_key = ecc_pub_key_from_pem(&path.join(pem_file))?;
+    }
+
+    for (pub_key, pem_file) in preamble
+        .vendor_pub_keys
+        .lms_pub_keys
+        .iter_mut()
+        .zip(&config.vendor.lms_pub_keys)
+    {
+        *pub_key = lms_pub_key_from_pem(&path.join(pem_file))?;
+    }
+
+    if let Some(owner) = &config.owner {
+        preamble.owner_pub_keys.ecc_pub_key = ecc_pub_key_from_pem(&path.join(&owner.ecc_pub_key))?;
+        preamble.owner_pub_keys.lms_pub_key = lms_pub_key_from_pem(&path.join(&owner.lms_pub_key))?;
+    }
+
+    Ok(preamble)
+}
+
+/// Build the revocation mask of the key indices passed in `arg`
+///
+/// ROM never honors the revocation of the last key, so revoking it is
+/// rejected rather than silently ignored.
+fn revocation_mask(args: &ArgMatches, arg: &str, key_count: u32) -> anyhow::Result<u32> {
+    let mut mask = 0;
+    for idx in args.get_many::<u32>(arg).into_iter().flatten() {
+        if *idx >= key_count {
+            return Err(anyhow!("{arg}: key index {idx} out of bounds"));
+        }
+        if *idx == key_count - 1 {
+            return Err(anyhow!("{arg}: the last key {idx} cannot be revoked"));
+        }
+        mask |= 1 << idx;
+    }
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_image;
+    use caliptra_hw_model_types::Fuses;
+
+    fn parse_fuses(out: &str, format: &str) -> Fuses {
+        match format {
+            "json" => serde_json::from_str(out).unwrap(),
+            _ => toml::from_str(out).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_fuse_values_fake_keys() {
+        let preamble = test_image::image_bundle().manifest.preamble;
+        let gen = ImageGenerator::new(OsslCrypto::default());
+
+        for format in ["toml", "json"] {
+            let fuses = fuse_values(&preamble, 0b0101, 0b1000, true).unwrap();
+            let fuses = parse_fuses(&format_fuse_values(&fuses, format).unwrap(), format);
+            assert_eq!(
+                fuses.key_manifest_pk_hash,
+                gen.vendor_pubkey_digest(&preamble).unwrap()
+            );
+            assert_eq!(
+                fuses.owner_pk_hash,
+                gen.owner_pubkey_digest(&preamble).unwrap()
+            );
+            assert_eq!(u32::from(fuses.key_manifest_pk_hash_mask), 0b0101);
+            assert_eq!(fuses.fuse_lms_revocation, 0b1000);
+            assert!(fuses.lms_verify);
+        }
+    }
+
+    #[test]
+    fn test_fuse_values_no_owner_keys() {
+        let mut preamble = test_image::image_bundle().manifest.preamble;
+        preamble.owner_pub_keys = Default::default();
+
+        let fuses = fuse_values(&preamble, 0, 0, false).unwrap();
+        let out = format_fuse_values(&fuses, "toml").unwrap();
+        assert!(!out.contains("owner_pk_hash"));
+        assert_eq!(parse_fuses(&out, "toml").owner_pk_hash, [0u32; 12]);
+    }
+
+    #[test]
+    fn test_fuse_values_unsupported_format() {
+        let preamble = test_image::image_bundle().manifest.preamble;
+        let fuses = fuse_values(&preamble, 0, 0, false).unwrap();
+        assert!(format_fuse_values(&fuses, "yaml").is_err());
+    }
+}
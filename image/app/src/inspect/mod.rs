@@ -0,0 +1,321 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image inspection command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorCrypto};
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_serde::ImageBundleReader;
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use serde_derive::Serialize;
+use std::fs::File;
+use std::path::PathBuf;
+
+/// Image Manifest summary
+#[derive(Serialize)]
+struct ManifestInfo {
+    marker: String,
+    marker_valid: bool,
+    size: u32,
+}
+
+/// Image Preamble summary
+#[derive(Serialize)]
+struct PreambleInfo {
+    vendor_ecc_pub_key_idx: u32,
+    vendor_lms_pub_key_idx: u32,
+    vendor_pub_key_hash: String,
+    owner_pub_key_hash: String,
+}
+
+/// Image Header summary
+#[derive(Serialize)]
+struct HeaderInfo {
+    revision: String,
+    vendor_ecc_pub_key_idx: u32,
+    vendor_lms_pub_key_idx: u32,
+    flags: String,
+    toc_len: u32,
+    pl0_pauser: String,
+    vendor_not_before: String,
+    vendor_not_after: String,
+    owner_not_before: String,
+    owner_not_after: String,
+    toc_digest: String,
+    computed_toc_digest: String,
+    toc_digest_valid: bool,
+    pauser_policy_entry_count: u32,
+    pauser_policy: Vec<PauserPolicyEntryInfo>,
+}
+
+/// Image PAUSER policy entry summary
+#[derive(Serialize)]
+struct PauserPolicyEntryInfo {
+    pauser: String,
+    allowed_cmds: String,
+}
+
+/// Image Table of Contents entry summary
+#[derive(Serialize)]
+struct TocEntryInfo {
+    id: String,
+    r#type: String,
+    revision: String,
+    version: u32,
+    svn: u32,
+    min_svn: u32,
+    load_addr: String,
+    entry_point: String,
+    offset: u32,
+    size: u32,
+    digest: String,
+    computed_digest: String,
+    digest_valid: bool,
+}
+
+/// Image Bundle summary
+#[derive(Serialize)]
+struct ImageInfo {
+    manifest: ManifestInfo,
+    preamble: PreambleInfo,
+    header: HeaderInfo,
+    fmc: TocEntryInfo,
+    runtime: TocEntryInfo,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aux: Vec<TocEntryInfo>,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let format: &String = args
+        .get_one::<String>("format")
+        .with_context(|| "format arg not specified")?;
+
+    let file = File::open(image_path)
+        .with_context(|| format!("Failed to open the image {}", image_path.display()))?;
+    let image = ImageBundleReader::new(file).read()?;
+
+    let info = image_info(&image)?;
+
+    let out = match format.as_str() {
+        "json" => serde_json::to_string_pretty(&info)?,
+        "toml" => toml::to_string(&info)?,
+        _ => return Err(anyhow!("Unsupported output format {format}")),
+    };
+    println!("{out}");
+
+    check_digests(&info)
+}
+
+/// Fail with the names of the sections whose digests do not match
+fn check_digests(info: &ImageInfo) -> anyhow::Result<()> {
+    let mut mismatches = vec![];
+    if !info.header.toc_digest_valid {
+        mismatches.push("TOC".to_string());
+    }
+    for entry in [&info.fmc, &info.runtime].into_iter().chain(&info.aux) {
+        if !entry.digest_valid {
+            mismatches.push(entry.id.clone());
+        }
+    }
+    if !mismatches.is_empty() {
+        return Err(anyhow!("Digest mismatch: {}", mismatches.join(", ")));
+    }
+
+    Ok(())
+}
+
+/// Build the summary of the image bundle, recomputing all digests
+fn image_info(image: &ImageBundle) -> anyhow::Result<ImageInfo> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let crypto = OsslCrypto::default();
+    let manifest = &image.manifest;
+    let preamble = &manifest.preamble;
+    let header = &manifest.header;
+
+    let computed_toc_digest =
+        gen.toc_digest(&manifest.fmc, &manifest.runtime, manifest.aux_entries())?;
+
+    let mut aux = vec![];
+    for (entry, content) in manifest.aux_entries().iter().zip(&image.aux) {
+        aux.push(toc_entry_info(&crypto, entry, content)?);
+    }
+
+    Ok(ImageInfo {
+        manifest: ManifestInfo {
+            marker: format!("0x{:08x}", manifest.marker),
+            marker_valid: manifest.marker == MANIFEST_MARKER,
+            size: manifest.size,
+        },
+        preamble: PreambleInfo {
+            vendor_ecc_pub_key_idx: preamble.vendor_ecc_pub_key_idx,
+            vendor_lms_pub_key_idx: preamble.vendor_lms_pub_key_idx,
+            vendor_pub_key_hash: digest_hex(&gen.vendor_pubkey_digest(preamble)?),
+            owner_pub_key_hash: digest_hex(&gen.owner_pubkey_digest(preamble)?),
+        },
+        header: HeaderInfo {
+            revision: format!("{:08x}{:08x}", header.revision[1], header.revision[0]),
+            vendor_ecc_pub_key_idx: header.vendor_ecc_pub_key_idx,
+            vendor_lms_pub_key_idx: header.vendor_lms_pub_key_idx,
+            flags: format!("0x{:08x}", header.flags),
+            toc_len: header.toc_len,
+            pl0_pauser: format!("0x{:08x}", header.pl0_pauser),
+            vendor_not_before: validity_date(&header.vendor_data.vendor_not_before),
+            vendor_not_after: validity_date(&header.vendor_data.vendor_not_after),
+            owner_not_before: validity_date(&header.owner_data.owner_not_before),
+            owner_not_after: validity_date(&header.owner_data.owner_not_after),
+            toc_digest: digest_hex(&header.toc_digest),
+            computed_toc_digest: digest_hex(&computed_toc_digest),
+            toc_digest_valid: header.toc_digest == computed_toc_digest,
+            pauser_policy_entry_count: header.pauser_policy.entry_count,
+            pauser_policy: pauser_policy_info(&header.pauser_policy),
+        },
+        fmc: toc_entry_info(&crypto, &manifest.fmc, &image.fmc)?,
+        runtime: toc_entry_info(&crypto, &manifest.runtime, &image.runtime)?,
+        aux,
+    })
+}
+
+/// Build the summary of a TOC entry, recomputing the digest of its image
+fn toc_entry_info(
+    crypto: &OsslCrypto,
+    entry: &ImageTocEntry,
+    content: &[u8],
+) -> anyhow::Result<TocEntryInfo> {
+    let computed_digest = crypto.sha384_digest(content)?;
+    Ok(TocEntryInfo {
+        id: toc_entry_id_name(entry.id),
+        r#type: toc_entry_type_name(entry.r#type),
+        revision: hex::encode(entry.revision),
+        version: entry.version,
+        svn: entry.svn,
+        min_svn: entry.min_svn,
+        load_addr: format!("0x{:08x}", entry.load_addr),
+        entry_point: format!("0x{:08x}", entry.entry_point),
+        offset: entry.offset,
+        size: entry.size,
+        digest: digest_hex(&entry.digest),
+        computed_digest: digest_hex(&computed_digest),
+        digest_valid: entry.digest == computed_digest,
+    })
+}
+
+/// Build the summary of the valid entries of a PAUSER policy
+fn pauser_policy_info(policy: &ImagePauserPolicy) -> Vec<PauserPolicyEntryInfo> {
+    let count = (policy.entry_count as usize).min(PAUSER_POLICY_ENTRY_COUNT);
+    policy.entries[..count]
+        .iter()
+        .map(|entry| PauserPolicyEntryInfo {
+            pauser: format!("0x{:08x}", entry.pauser),
+            allowed_cmds: format!(
+                "0x{:08x}{:08x}",
+                entry.allowed_cmds[1], entry.allowed_cmds[0]
+            ),
+        })
+        .collect()
+}
+
+fn toc_entry_id_name(id: u32) -> String {
+    match ImageTocEntryId::try_from(id) {
+        Ok(ImageTocEntryId::Fmc) => "fmc".into(),
+        Ok(ImageTocEntryId::Runtime) => "runtime".into(),
+        Ok(ImageTocEntryId::DpeConfig) => "dpe-config".into(),
+        Ok(ImageTocEntryId::SocPolicy) => "soc-policy".into(),
+        Err(_) => format!("unknown (0x{id:08x})"),
+    }
+}
+
+fn toc_entry_type_name(ty: u32) -> String {
+    match ImageTocEntryType::try_from(ty) {
+        Ok(ImageTocEntryType::Executable) => "executable".into(),
+        Ok(ImageTocEntryType::Data) => "data".into(),
+        Err(_) => format!("unknown (0x{ty:08x})"),
+    }
+}
+
+/// Format a digest as the hex string of its big-endian bytes
+fn digest_hex(digest: &ImageDigest) -> String {
+    digest.iter().map(|word| format!("{word:08x}")).collect()
+}
+
+/// Format a validity date, dropping the zero padding of unset dates
+fn validity_date(date: &[u8; 15]) -> String {
+    String::from_utf8_lossy(date)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_image;
+
+    #[test]
+    fn test_inspect() {
+        let image = test_image::image_bundle();
+        let info = image_info(&image).unwrap();
+        assert!(info.manifest.marker_valid);
+        assert_eq!(info.preamble.vendor_ecc_pub_key_idx, 0);
+        assert!(info.header.toc_digest_valid);
+        assert_eq!(info.header.pl0_pauser, "0x00000001");
+        assert!(info.header.pauser_policy.is_empty());
+        assert_eq!(info.fmc.id, "fmc");
+        assert_eq!(info.fmc.size, 1024);
+        assert_eq!(info.runtime.id, "runtime");
+        assert_eq!(info.runtime.size, 2048);
+        assert!(info.aux.is_empty());
+        check_digests(&info).unwrap();
+
+        // Both output formats serialize the summary
+        serde_json::to_string_pretty(&info).unwrap();
+        toml::to_string(&info).unwrap();
+    }
+
+    #[test]
+    fn test_inspect_pauser_policy() {
+        let mut image = test_image::image_bundle();
+        let policy = &mut image.manifest.header.pauser_policy;
+        policy.entry_count = 1;
+        policy.entries[0] = ImagePauserPolicyEntry {
+            pauser: 2,
+            allowed_cmds: [0x0000_0005, 0x8000_0000],
+        };
+        let info = image_info(&image).unwrap();
+        assert_eq!(info.header.pauser_policy_entry_count, 1);
+        assert_eq!(info.header.pauser_policy.len(), 1);
+        assert_eq!(info.header.pauser_policy[0].pauser, "0x00000002");
+        assert_eq!(
+            info.header.pauser_policy[0].allowed_cmds,
+            "0x8000000000000005"
+        );
+        toml::to_string(&info).unwrap();
+    }
+
+    #[test]
+    fn test_inspect_digest_mismatch() {
+        let mut image = test_image::image_bundle();
+        image.runtime[0] ^= 1;
+        let info = image_info(&image).unwrap();
+        assert!(info.header.toc_digest_valid);
+        assert!(info.fmc.digest_valid);
+        assert!(!info.runtime.digest_valid);
+        assert_ne!(info.runtime.digest, info.runtime.computed_digest);
+        let err = check_digests(&info).unwrap_err();
+        assert_eq!(err.to_string(), "Digest mismatch: runtime");
+    }
+}
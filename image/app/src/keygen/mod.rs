@@ -0,0 +1,150 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image key generation command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_image_openssl::{ecc_key_pair_pem, lms_key_pair, lms_state_init, lms_state_path};
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zerocopy::AsBytes;
+
+use crate::create::config::{KeyConfig, OwnerKeyConfig, VendorKeyConfig};
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let out_dir: &PathBuf = args
+        .get_one::<PathBuf>("out-dir")
+        .with_context(|| "out-dir arg not specified")?;
+
+    let lms_key_count: &u32 = args
+        .get_one::<u32>("lms-key-count")
+        .with_context(|| "lms-key-count arg not specified")?;
+
+    if *lms_key_count == 0 || *lms_key_count > VENDOR_LMS_KEY_COUNT {
+        return Err(anyhow!(
+            "lms-key-count must be between 1 and {VENDOR_LMS_KEY_COUNT}"
+        ));
+    }
+
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Failed to create directory {}", out_dir.display()))?;
+
+    let mut vendor = VendorKeyConfig::default();
+    let mut ecc_priv_keys: [String; VENDOR_ECC_KEY_COUNT as usize] = Default::default();
+    for (i, (pub_key, priv_key)) in vendor
+        .ecc_pub_keys
+        .iter_mut()
+        .zip(ecc_priv_keys.iter_mut())
+        .enumerate()
+    {
+        *pub_key = format!("vnd-pub-key-{i}.pem");
+        *priv_key = format!("vnd-priv-key-{i}.pem");
+        write_ecc_key(out_dir, priv_key, pub_key)?;
+    }
+    vendor.ecc_priv_keys = Some(ecc_priv_keys);
+
+    // The LMS trees take a while to build, so generate the keys in parallel.
+    // The last key is the owner key.
+    let lms_keys = std::thread::scope(|s| {
+        let threads: Vec<_> = (0..=*lms_key_count)
+            .map(|_| s.spawn(lms_key_pair))
+            .collect();
+        threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+    let (owner_lms_key, vendor_lms_keys) = lms_keys.split_last().unwrap();
+
+    for (i, (priv_key, pub_key)) in vendor_lms_keys.iter().enumerate() {
+        let priv_key_file = format!("vnd-lms-priv-key-{i}.pem");
+        let pub_key_file = format!("vnd-lms-pub-key-{i}.pem");
+        write_lms_key(out_dir, &priv_key_file, priv_key, &pub_key_file, pub_key)?;
+    }
+
+    // Reuse the generated keys for the remaining vendor LMS key slots
+    let mut lms_priv_keys: [String; VENDOR_LMS_KEY_COUNT as usize] = Default::default();
+    for (i, (pub_key, priv_key)) in vendor
+        .lms_pub_keys
+        .iter_mut()
+        .zip(lms_priv_keys.iter_mut())
+        .enumerate()
+    {
+        let key_idx = i % vendor_lms_keys.len();
+        *pub_key = format!("vnd-lms-pub-key-{key_idx}.pem");
+        *priv_key = format!("vnd-lms-priv-key-{key_idx}.pem");
+    }
+    vendor.lms_priv_keys = Some(lms_priv_keys);
+
+    let owner = OwnerKeyConfig {
+        ecc_pub_key: "own-pub-key.pem".into(),
+        ecc_priv_key: Some("own-priv-key.pem".into()),
+        lms_pub_key: "own-lms-pub-key.pem".into(),
+        lms_priv_key: Some("own-lms-priv-key.pem".into()),
+    };
+    write_ecc_key(out_dir, "own-priv-key.pem", "own-pub-key.pem")?;
+    let (priv_key, pub_key) = owner_lms_key;
+    write_lms_key(
+        out_dir,
+        "own-lms-priv-key.pem",
+        priv_key,
+        "own-lms-pub-key.pem",
+        pub_key,
+    )?;
+
+    let config = KeyConfig {
+        vendor,
+        owner: Some(owner),
+    };
+    write_new(
+        &out_dir.join("keys.toml"),
+        toml::to_string(&config)?.as_bytes(),
+    )?;
+
+    Ok(())
+}
+
+/// Generate an ECC key pair and write it to `priv_key_file` and `pub_key_file`
+fn write_ecc_key(dir: &Path, priv_key_file: &str, pub_key_file: &str) -> anyhow::Result<()> {
+    let (priv_key, pub_key) = ecc_key_pair_pem()?;
+    write_new(&dir.join(priv_key_file), &priv_key)?;
+    write_new(&dir.join(pub_key_file), &pub_key)
+}
+
+/// Write an LMS key pair along with the leaf index state of the private key
+fn write_lms_key(
+    dir: &Path,
+    priv_key_file: &str,
+    priv_key: &ImageLmsPrivKey,
+    pub_key_file: &str,
+    pub_key: &ImageLmsPublicKey,
+) -> anyhow::Result<()> {
+    let priv_key_path = dir.join(priv_key_file);
+    lms_state_init(&lms_state_path(&priv_key_path), priv_key)?;
+    write_new(&priv_key_path, priv_key.as_bytes())?;
+    write_new(&dir.join(pub_key_file), pub_key.as_bytes())
+}
+
+/// Write `data` to a new file, never overwriting existing keys
+fn write_new(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create file {}", path.display()))?;
+    file.write_all(data)?;
+    Ok(())
+}
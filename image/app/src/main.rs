@@ -13,9 +13,18 @@ Abstract:
 --*/
 use std::path::PathBuf;
 
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgAction, Command};
 
+mod assemble;
 mod create;
+mod digest;
+mod fuses;
+mod inspect;
+mod keygen;
+mod resign;
+#[cfg(test)]
+mod test_image;
+mod verify;
 
 /// Entry point
 fn main() {
@@ -86,6 +95,11 @@ fn main() {
                 .required(true)
                 .value_parser(value_parser!(u32)),
         )
+        .arg(
+            arg!(--"aux-config" <FILE> "Auxiliary Images Configuration file")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
         .arg(
             arg!(--"out" <FILE> "Output file")
                 .required(true)
@@ -110,6 +124,183 @@ fn main() {
             arg!(--"mfg-to-date" <String> "Certificate Validity End Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
                 .required(false)
                 .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"signer" <FILE> "External signer command used instead of the private keys")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"signer-arg" <ARG> "Argument passed to the external signer command")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(String)),
+        ),
+        Command::new("keygen")
+        .about("Generate vendor and owner key sets with a Key Configuration file")
+        .arg(
+            arg!(--"out-dir" <DIR> "Output directory")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"lms-key-count" <U32> "Number of distinct vendor LMS keys")
+                .required(false)
+                .value_parser(value_parser!(u32))
+                .default_value("32"),
+        ),
+        Command::new("digest")
+        .about("Print the header digests signed by the vendor and the owner")
+        .arg(
+            arg!(--"image" <FILE> "Unsigned firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"vendor-digest" <FILE> "Output file for the vendor header digest")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"owner-digest" <FILE> "Output file for the owner header digest")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("assemble")
+        .about("Assemble a firmware image bundle from externally produced signatures")
+        .arg(
+            arg!(--"image" <FILE> "Unsigned firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"vendor-ecc-sig" <FILE> "Vendor ECC signature [DER or raw r || s]")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"vendor-lms-sig" <FILE> "Vendor LMS signature [raw]")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"owner-ecc-sig" <FILE> "Owner ECC signature [DER or raw r || s]")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"owner-lms-sig" <FILE> "Owner LMS signature [raw]")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output file")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("resign")
+        .about("Replace the owner keys of a vendor signed firmware image bundle")
+        .arg(
+            arg!(--"image" <FILE> "Vendor signed firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"key-config" <FILE> "Key Configuration file of the new owner")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"own-from-date" <String> "Certificate Validity Start Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"own-to-date" <String> "Certificate Validity End Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"signer" <FILE> "External signer command used instead of the private keys")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"signer-arg" <ARG> "Argument passed to the external signer command")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output file")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("verify")
+        .about("Verify a firmware image bundle against fuse values")
+        .arg(
+            arg!(--"image" <FILE> "Firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"fuses" <FILE> "Fuse Configuration file")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("fuses")
+        .about("Print the fuse values matching a Key Configuration or a firmware image bundle")
+        .arg(
+            arg!(--"key-config" <FILE> "Key Configuration file")
+                .required(false)
+                .conflicts_with("image")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"image" <FILE> "Firmware image bundle")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"revoke-ecc-key" <U32> "Index of a revoked vendor ECC public key")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"revoke-lms-key" <U32> "Index of a revoked vendor LMS public key")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"lms-verify" "Enable LMS signature verification")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"format" <FORMAT> "Output format [json, toml]")
+                .required(false)
+                .value_parser(["json", "toml"])
+                .default_value("toml"),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output file")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("inspect")
+        .about("Print the contents of a firmware image bundle")
+        .arg(
+            arg!(--"image" <FILE> "Firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"format" <FORMAT> "Output format [json, toml]")
+                .required(false)
+                .value_parser(["json", "toml"])
+                .default_value("json"),
         )];
 
     let cmd = Command::new("caliptra-image-app")
@@ -120,6 +311,13 @@ fn main() {
 
     let result = match cmd.subcommand().unwrap() {
         ("create", args) => create::run_cmd(args),
+        ("keygen", args) => keygen::run_cmd(args),
+        ("digest", args) => digest::run_cmd(args),
+        ("assemble", args) => assemble::run_cmd(args),
+        ("fuses", args) => fuses::run_cmd(args),
+        ("inspect", args) => inspect::run_cmd(args),
+        ("resign", args) => resign::run_cmd(args),
+        ("verify", args) => verify::run_cmd(args),
         (_, _) => unreachable!(),
     };
 
@@ -0,0 +1,181 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image owner re-signing command.
+
+--*/
+
+use anyhow::Context;
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorCrypto, ImageGeneratorOwnerConfig};
+use caliptra_image_openssl::{ExternalSignerCrypto, StatefulLmsCrypto};
+use caliptra_image_serde::{ImageBundleReader, ImageBundleWriter};
+use caliptra_image_types::{ImageManifest, ImageOwnerPrivKeys};
+use clap::ArgMatches;
+use std::fs::File;
+use std::path::PathBuf;
+use zerocopy::AsBytes;
+
+use crate::create::{config, owner_config, validity_dates};
+use crate::verify::verify_vendor_sigs;
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let config_path: &PathBuf = args
+        .get_one::<PathBuf>("key-config")
+        .with_context(|| "key-config arg not specified")?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    let (own_from_date, own_to_date) = validity_dates(args, "own-from-date", "own-to-date")?;
+
+    let file = File::open(image_path)
+        .with_context(|| format!("Failed to open the image {}", image_path.display()))?;
+    let mut image = ImageBundleReader::new(file).read()?;
+
+    let config = config::load_owner_key_config(config_path)?;
+    let config_dir = config_path
+        .parent()
+        .with_context(|| "Invalid parent path")?;
+
+    let mut crypto = StatefulLmsCrypto::default();
+    let mut owner_config = owner_config(
+        &mut crypto,
+        config_dir,
+        &Some(config),
+        own_from_date,
+        own_to_date,
+    )?
+    .with_context(|| "Owner configuration not specified")?;
+
+    image.manifest = match args.get_one::<PathBuf>("signer") {
+        Some(signer) => {
+            let signer_args = args
+                .get_many::<String>("signer-arg")
+                .map(|args| args.cloned().collect())
+                .unwrap_or_default();
+            owner_config.priv_keys = Some(ImageOwnerPrivKeys {
+                lms_priv_key: ExternalSignerCrypto::lms_key_handle(
+                    &owner_config.pub_keys.lms_pub_key,
+                ),
+                ..Default::default()
+            });
+            let gen = ImageGenerator::new(ExternalSignerCrypto::new(signer, signer_args));
+            resign_manifest(&gen, &image.manifest, &owner_config)?
+        }
+        None => {
+            let gen = ImageGenerator::new(crypto);
+            resign_manifest(&gen, &image.manifest, &owner_config)?
+        }
+    };
+
+    let out_file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(out_path)
+        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+
+    let mut writer = ImageBundleWriter::new(out_file);
+    writer.write(&image)?;
+
+    Ok(())
+}
+
+/// Re-sign `manifest` with the keys of `owner_config`
+///
+/// The vendor signatures are carried over by re-signing, so the vendor ECC
+/// signature, and the vendor LMS signature if the image has one, are checked
+/// first.
+fn resign_manifest<Crypto: ImageGeneratorCrypto>(
+    gen: &ImageGenerator<Crypto>,
+    manifest: &ImageManifest,
+    owner_config: &ImageGeneratorOwnerConfig,
+) -> anyhow::Result<ImageManifest> {
+    let vendor_lms_sig_present = manifest
+        .preamble
+        .vendor_sigs
+        .lms_sig
+        .as_bytes()
+        .iter()
+        .any(|b| *b != 0);
+    verify_vendor_sigs(manifest, vendor_lms_sig_present)?;
+    gen.resign_owner(manifest, owner_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_image;
+    use crate::verify::verify_owner_sigs;
+    use caliptra_image_fake_keys::OWNER_CONFIG;
+    use caliptra_image_openssl::OsslCrypto;
+
+    /// Corruption applied to the signed manifest
+    type Corruption = fn(&mut ImageManifest);
+
+    #[test]
+    fn test_resign() {
+        let manifest = test_image::image_bundle().manifest;
+        let gen = ImageGenerator::new(OsslCrypto::default());
+        let owner_config = ImageGeneratorOwnerConfig {
+            not_before: *b"20240101000000Z",
+            ..OWNER_CONFIG
+        };
+
+        let resigned = resign_manifest(&gen, &manifest, &owner_config).unwrap();
+        assert_eq!(
+            resigned.header.owner_data.owner_not_before,
+            owner_config.not_before
+        );
+        verify_vendor_sigs(&resigned, true).unwrap();
+        verify_owner_sigs(&resigned, true, true).unwrap();
+
+        // Images without a vendor LMS signature can be re-signed as well.
+        let mut manifest = manifest;
+        manifest.preamble.vendor_sigs.lms_sig = Default::default();
+        resign_manifest(&gen, &manifest, &owner_config).unwrap();
+    }
+
+    #[test]
+    fn test_resign_invalid_vendor_sigs() {
+        let manifest = test_image::image_bundle().manifest;
+        let gen = ImageGenerator::new(OsslCrypto::default());
+
+        let corrupt: [(Corruption, &str); 3] = [
+            (
+                |manifest| manifest.preamble.vendor_sigs.ecc_sig.r[0] ^= 1,
+                "Vendor ECC signature does not verify",
+            ),
+            (
+                |manifest| {
+                    let sig = &mut manifest.preamble.vendor_sigs.lms_sig;
+                    sig.q = (sig.q.get() + 1).into();
+                },
+                "Vendor LMS signature does not verify",
+            ),
+            (
+                |manifest| manifest.header.vendor_data.vendor_not_after[0] ^= 1,
+                "Vendor ECC signature does not verify",
+            ),
+        ];
+        for (corrupt, msg) in corrupt {
+            let mut manifest = manifest;
+            corrupt(&mut manifest);
+            let err = resign_manifest(&gen, &manifest, &OWNER_CONFIG).unwrap_err();
+            assert_eq!(err.to_string(), msg);
+        }
+    }
+}
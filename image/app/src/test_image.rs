@@ -0,0 +1,84 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   test_image.rs
+
+Abstract:
+
+    File contains a firmware image bundle signed with the fake keys, for
+    tests.
+
+--*/
+
+use caliptra_drivers::memory_layout::ICCM_ORG;
+use caliptra_image_fake_keys::{OWNER_CONFIG, VENDOR_CONFIG_KEY_0};
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorConfig, ImageGenratorExecutable};
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::{ImageBundle, ImageRevision};
+
+/// Executable filled with a fixed byte
+struct TestExecutable {
+    load_addr: u32,
+    rev: ImageRevision,
+    content: Vec<u8>,
+}
+
+impl TestExecutable {
+    fn new(load_addr: u32, size: usize, fill: u8) -> Self {
+        Self {
+            load_addr,
+            rev: Default::default(),
+            content: vec![fill; size],
+        }
+    }
+}
+
+impl ImageGenratorExecutable for TestExecutable {
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn svn(&self) -> u32 {
+        0
+    }
+
+    fn min_svn(&self) -> u32 {
+        0
+    }
+
+    fn rev(&self) -> &ImageRevision {
+        &self.rev
+    }
+
+    fn load_addr(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn entry_point(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn content(&self) -> &Vec<u8> {
+        &self.content
+    }
+
+    fn size(&self) -> u32 {
+        self.content.len() as u32
+    }
+}
+
+/// Image bundle signed with the fake vendor key 0 and the fake owner key
+pub(crate) fn image_bundle() -> ImageBundle {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    gen.generate(&ImageGeneratorConfig {
+        vendor_config: VENDOR_CONFIG_KEY_0,
+        owner_config: Some(OWNER_CONFIG),
+        fmc: TestExecutable::new(ICCM_ORG, 1024, 0x11),
+        runtime: TestExecutable::new(ICCM_ORG + 1024, 2048, 0x22),
+        aux: Vec::new(),
+    })
+    .unwrap()
+}
@@ -0,0 +1,37 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   config.rs
+
+Abstract:
+
+    File contains utilities for parsing fuse configuration files
+
+--*/
+
+use anyhow::Context;
+use caliptra_hw_model_types::Fuses;
+use std::path::PathBuf;
+
+/// Load Fuse Configuration from file
+///
+/// The file holds the fields of `caliptra_hw_model::Fuses` in TOML, or in
+/// JSON when the file name ends with `.json`. Absent fields keep their
+/// default value.
+pub(crate) fn load_fuse_config(path: &PathBuf) -> anyhow::Result<Fuses> {
+    let config_str = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+    let config: Fuses = if path.extension().map_or(false, |ext| ext == "json") {
+        serde_json::from_str(&config_str)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))?
+    } else {
+        toml::from_str(&config_str)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))?
+    };
+
+    Ok(config)
+}
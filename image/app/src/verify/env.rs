@@ -0,0 +1,252 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   env.rs
+
+Abstract:
+
+    File contains the OpenSSL backed image verification environment.
+
+--*/
+
+use caliptra_drivers::memory_layout::{AUX_IMAGE_RANGE, ICCM_RANGE};
+use caliptra_drivers::*;
+use caliptra_image_types::*;
+use caliptra_image_verify::ImageVerificationEnv;
+use core::marker::PhantomData;
+use core::ops::Range;
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::ec::{EcGroup, EcKey, EcPoint};
+use openssl::ecdsa::EcdsaSig;
+use openssl::nid::Nid;
+use openssl::sha::{sha384, Sha256};
+
+/// Fuse values the image is verified against
+pub(crate) struct HostFuses {
+    pub vendor_pub_key_digest: ImageDigest,
+    pub vendor_ecc_pub_key_revocation: VendorPubKeyRevocation,
+    pub vendor_lms_pub_key_revocation: u32,
+    pub owner_pub_key_digest: ImageDigest,
+    pub anti_rollback_disable: bool,
+    pub lifecycle: Lifecycle,
+    pub fmc_svn: u32,
+    pub runtime_svn: u32,
+    pub lms_verify: bool,
+}
+
+/// Image verification environment running on the host
+pub(crate) struct HostImageVerificationEnv<'a> {
+    image: &'a [u8],
+    fuses: HostFuses,
+    fw_extended_error: u32,
+}
+
+impl<'a> HostImageVerificationEnv<'a> {
+    pub fn new(image: &'a [u8], fuses: HostFuses) -> Self {
+        Self {
+            image,
+            fuses,
+            fw_extended_error: 0,
+        }
+    }
+
+    /// Extended error code reported by the last failing crypto operation
+    pub fn fw_extended_error(&self) -> u32 {
+        self.fw_extended_error
+    }
+}
+
+impl ImageVerificationEnv for &mut HostImageVerificationEnv<'_> {
+    /// Calculate Digest using SHA-384
+    fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest> {
+        let data = (offset as usize)
+            .checked_add(len as usize)
+            .and_then(|end| self.image.get(offset as usize..end))
+            .ok_or(CaliptraError::DRIVER_SHA384_INVALID_SLICE)?;
+        Ok(words_from_be_bytes(&sha384(data)))
+    }
+
+    /// ECC-384 Verification routine
+    fn ecc384_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> CaliptraResult<Array4xN<12, 48>> {
+        // A signature that does not verify yields a value other than `sig.r`.
+        match ecdsa384_verify(digest, pub_key, sig) {
+            Ok(true) => Ok(Array4x12::from(sig.r)),
+            _ => Ok(Array4x12::default()),
+        }
+    }
+
+    /// LMS Verification routine
+    fn lms_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>> {
+        let message: Vec<u8> = digest.iter().flat_map(|w| w.to_be_bytes()).collect();
+        Lms::default().verify_lms_signature_cfi_generic(
+            &mut OsslSha256::default(),
+            &message,
+            pub_key,
+            sig,
+        )
+    }
+
+    fn vendor_pub_key_digest(&self) -> ImageDigest {
+        self.fuses.vendor_pub_key_digest
+    }
+
+    fn vendor_ecc_pub_key_revocation(&self) -> VendorPubKeyRevocation {
+        self.fuses.vendor_ecc_pub_key_revocation
+    }
+
+    fn vendor_lms_pub_key_revocation(&self) -> u32 {
+        self.fuses.vendor_lms_pub_key_revocation
+    }
+
+    fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
+        self.fuses.owner_pub_key_digest
+    }
+
+    fn anti_rollback_disable(&self) -> bool {
+        self.fuses.anti_rollback_disable
+    }
+
+    fn dev_lifecycle(&self) -> Lifecycle {
+        self.fuses.lifecycle
+    }
+
+    // The data vault is only consulted on update reset, which is not
+    // supported on the host.
+    fn vendor_ecc_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn vendor_lms_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn owner_pub_key_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    fn get_fmc_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    fn fmc_fuse_svn(&self) -> u32 {
+        self.fuses.fmc_svn
+    }
+
+    fn runtime_fuse_svn(&self) -> u32 {
+        self.fuses.runtime_svn
+    }
+
+    fn iccm_range(&self) -> Range<u32> {
+        ICCM_RANGE
+    }
+
+    fn aux_image_range(&self) -> Range<u32> {
+        AUX_IMAGE_RANGE
+    }
+
+    fn lms_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn set_fw_extended_error(&mut self, err: u32) {
+        self.fw_extended_error = err;
+    }
+}
+
+/// Check an ECC-384 signature of `digest`
+pub(crate) fn ecdsa384_verify(
+    digest: &ImageDigest,
+    pub_key: &ImageEccPubKey,
+    sig: &ImageEccSignature,
+) -> anyhow::Result<bool> {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+    let mut ctx = BigNumContext::new()?;
+
+    let pub_key_x = BigNum::from_slice(&be_bytes_from_words(&pub_key.x))?;
+    let pub_key_y = BigNum::from_slice(&be_bytes_from_words(&pub_key.y))?;
+    let mut point = EcPoint::new(&group)?;
+    point.set_affine_coordinates_gfp(&group, &pub_key_x, &pub_key_y, &mut ctx)?;
+    let ec_key = EcKey::from_public_key(&group, &point)?;
+
+    let r = BigNum::from_slice(&be_bytes_from_words(&sig.r))?;
+    let s = BigNum::from_slice(&be_bytes_from_words(&sig.s))?;
+    let sig = EcdsaSig::from_private_components(r, s)?;
+
+    Ok(sig.verify(&be_bytes_from_words(digest), &ec_key)?)
+}
+
+/// Check an LMS signature of `digest`
+pub(crate) fn lms_verify(
+    digest: &ImageDigest,
+    pub_key: &ImageLmsPublicKey,
+    sig: &ImageLmsSignature,
+) -> bool {
+    let candidate_key = Lms::default().verify_lms_signature_cfi_generic(
+        &mut OsslSha256::default(),
+        &be_bytes_from_words(digest),
+        pub_key,
+        sig,
+    );
+    matches!(candidate_key, Ok(key) if key == HashValue::from(pub_key.digest))
+}
+
+fn be_bytes_from_words(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_be_bytes()).collect()
+}
+
+fn words_from_be_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// SHA-256 implementation backing the LMS verifier
+#[derive(Default)]
+struct OsslSha256 {}
+
+struct OsslSha256DigestOp<'a> {
+    driver: PhantomData<&'a mut OsslSha256>,
+    digest: Sha256,
+}
+
+impl<'a> Sha256DigestOp<'a> for OsslSha256DigestOp<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        self.digest.update(data);
+        Ok(())
+    }
+
+    fn finalize(self, digest: &mut Array4x8) -> CaliptraResult<()> {
+        *digest = Array4x8::from(self.digest.finish());
+        Ok(())
+    }
+}
+
+impl Sha256Alg for OsslSha256 {
+    type DigestOp<'a> = OsslSha256DigestOp<'a>;
+
+    fn digest_init(&mut self) -> CaliptraResult<Self::DigestOp<'_>> {
+        Ok(OsslSha256DigestOp {
+            driver: PhantomData,
+            digest: Sha256::new(),
+        })
+    }
+
+    fn digest(&mut self, buf: &[u8]) -> CaliptraResult<Array4x8> {
+        Ok(Array4x8::from(openssl::sha::sha256(buf)))
+    }
+}
@@ -0,0 +1,297 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra Image verification command.
+
+--*/
+
+mod config;
+mod env;
+
+use anyhow::{anyhow, bail, Context};
+use caliptra_drivers::{CaliptraError, Lifecycle, ResetReason, VendorPubKeyRevocation};
+use caliptra_hw_model_types::{DeviceLifecycle, Fuses};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::ImageManifest;
+use caliptra_image_verify::{ImageVerificationInfo, ImageVerifier};
+use clap::ArgMatches;
+use std::path::PathBuf;
+use zerocopy::FromBytes;
+
+use env::{HostFuses, HostImageVerificationEnv};
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let fuses = match args.get_one::<PathBuf>("fuses") {
+        Some(fuses_path) => config::load_fuse_config(fuses_path)?,
+        None => Fuses::default(),
+    };
+
+    let image = std::fs::read(image_path)
+        .with_context(|| format!("Failed to read the image {}", image_path.display()))?;
+
+    let info = verify_image(&image, &fuses)?;
+
+    println!("Image verification succeeded");
+    println!(
+        "Vendor ECC public key index: {}",
+        info.vendor_ecc_pub_key_idx
+    );
+    if let Some(idx) = info.vendor_lms_pub_key_idx {
+        println!("Vendor LMS public key index: {idx}");
+    }
+    println!(
+        "Owner public key digest in fuses: {}",
+        info.owner_pub_keys_digest_in_fuses
+    );
+    println!(
+        "FMC SVN: {} (effective fuse SVN {})",
+        info.fmc.svn, info.fmc.effective_fuse_svn
+    );
+    println!(
+        "Runtime SVN: {} (effective fuse SVN {})",
+        info.runtime.svn, info.runtime.effective_fuse_svn
+    );
+    Ok(())
+}
+
+/// Verify `image` the way ROM does on a cold reset with `fuses`
+fn verify_image(image: &[u8], fuses: &Fuses) -> anyhow::Result<ImageVerificationInfo> {
+    let manifest = ImageManifest::read_from_prefix(image)
+        .with_context(|| "Image is smaller than the manifest")?;
+
+    let mut env = HostImageVerificationEnv::new(image, host_fuses(fuses));
+    let mut verifier = ImageVerifier::new(&mut env);
+    let result = verifier.verify(&manifest, image.len() as u32, ResetReason::ColdReset);
+
+    result.map_err(|err| {
+        let mut msg = format!(
+            "Image verification failed: {} (0x{:08x})",
+            error_name(err).unwrap_or("UNKNOWN"),
+            u32::from(err)
+        );
+        if env.fw_extended_error() != 0 {
+            msg += &format!(", extended error 0x{:08x}", env.fw_extended_error());
+        }
+        anyhow!(msg)
+    })
+}
+
+/// Check the vendor signatures of `manifest`
+///
+/// The ECC signature, and the LMS signature if `lms` is set, must verify
+/// against the vendor header digest with the vendor public keys selected by
+/// the header.
+pub(crate) fn verify_vendor_sigs(manifest: &ImageManifest, lms: bool) -> anyhow::Result<()> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let digest = gen.header_digest_vendor(&manifest.header)?;
+    let header = &manifest.header;
+    let preamble = &manifest.preamble;
+
+    let ecc_pub_key = preamble
+        .vendor_pub_keys
+        .ecc_pub_keys
+        .get(header.vendor_ecc_pub_key_idx as usize)
+        .with_context(|| "Vendor ECC public key index out of bounds")?;
+    if !matches!(
+        env::ecdsa384_verify(&digest, ecc_pub_key, &preamble.vendor_sigs.ecc_sig),
+        Ok(true)
+    ) {
+        bail!("Vendor ECC signature does not verify");
+    }
+
+    if lms {
+        let lms_pub_key = preamble
+            .vendor_pub_keys
+            .lms_pub_keys
+            .get(header.vendor_lms_pub_key_idx as usize)
+            .with_context(|| "Vendor LMS public key index out of bounds")?;
+        if !env::lms_verify(&digest, lms_pub_key, &preamble.vendor_sigs.lms_sig) {
+            bail!("Vendor LMS signature does not verify");
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the owner signatures of `manifest`
+///
+/// The ECC signature if `ecc` is set, and the LMS signature if `lms` is set,
+/// must verify against the owner header digest with the owner public keys.
+pub(crate) fn verify_owner_sigs(
+    manifest: &ImageManifest,
+    ecc: bool,
+    lms: bool,
+) -> anyhow::Result<()> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let digest = gen.header_digest_owner(&manifest.header)?;
+    let pub_keys = &manifest.preamble.owner_pub_keys;
+    let sigs = &manifest.preamble.owner_sigs;
+
+    if ecc
+        && !matches!(
+            env::ecdsa384_verify(&digest, &pub_keys.ecc_pub_key, &sigs.ecc_sig),
+            Ok(true)
+        )
+    {
+        bail!("Owner ECC signature does not verify");
+    }
+
+    if lms && !env::lms_verify(&digest, &pub_keys.lms_pub_key, &sigs.lms_sig) {
+        bail!("Owner LMS signature does not verify");
+    }
+
+    Ok(())
+}
+
+/// Convert the fuse values to the values ROM reads from the fuses
+fn host_fuses(fuses: &Fuses) -> HostFuses {
+    HostFuses {
+        vendor_pub_key_digest: fuses.key_manifest_pk_hash,
+        vendor_ecc_pub_key_revocation: VendorPubKeyRevocation::from_bits_truncate(
+            fuses.key_manifest_pk_hash_mask.into(),
+        ),
+        vendor_lms_pub_key_revocation: fuses.fuse_lms_revocation,
+        owner_pub_key_digest: fuses.owner_pk_hash,
+        anti_rollback_disable: fuses.anti_rollback_disable,
+        lifecycle: match fuses.life_cycle {
+            DeviceLifecycle::Unprovisioned => Lifecycle::Unprovisioned,
+            DeviceLifecycle::Manufacturing => Lifecycle::Manufacturing,
+            DeviceLifecycle::Reserved2 => Lifecycle::Reserved2,
+            DeviceLifecycle::Production => Lifecycle::Production,
+        },
+        fmc_svn: fmc_svn(fuses.fmc_key_manifest_svn),
+        runtime_svn: runtime_svn(&fuses.runtime_svn),
+        lms_verify: fuses.lms_verify,
+    }
+}
+
+/// FMC security version number, as read by ROM from the fuses
+fn fmc_svn(fuse: u32) -> u32 {
+    32 - fuse.leading_zeros()
+}
+
+/// Runtime security version number, as read by ROM from the fuses
+fn runtime_svn(fuse: &[u32; 4]) -> u32 {
+    let fuse = fuse
+        .iter()
+        .rev()
+        .fold(0u128, |acc, word| (acc << 32) | u128::from(*word));
+    128 - fuse.leading_zeros()
+}
+
+macro_rules! error_names {
+    ($($name:ident,)*) => {
+        /// Name of the image verifier error `err`
+        fn error_name(err: CaliptraError) -> Option<&'static str> {
+            $(
+                if err == CaliptraError::$name {
+                    return Some(stringify!($name));
+                }
+            )*
+            None
+        }
+    };
+}
+
+// Generated by build.rs from the image verifier errors in caliptra-error
+include!(concat!(env!("OUT_DIR"), "/error_names.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_image;
+
+    fn provisioned_fuses(image: &[u8]) -> Fuses {
+        let manifest = ImageManifest::read_from_prefix(image).unwrap();
+        let gen = ImageGenerator::new(OsslCrypto::default());
+        Fuses {
+            key_manifest_pk_hash: gen.vendor_pubkey_digest(&manifest.preamble).unwrap(),
+            owner_pk_hash: gen.owner_pubkey_digest(&manifest.preamble).unwrap(),
+            life_cycle: DeviceLifecycle::Production,
+            lms_verify: true,
+            ..Default::default()
+        }
+    }
+
+    fn failure_msg(err: CaliptraError) -> String {
+        format!(
+            "Image verification failed: {} (0x{:08x})",
+            error_name(err).unwrap(),
+            u32::from(err)
+        )
+    }
+
+    #[test]
+    fn test_verify_unprovisioned() {
+        let image = test_image::image_bundle().to_bytes().unwrap();
+        let info = verify_image(&image, &Fuses::default()).unwrap();
+        assert_eq!(info.vendor_ecc_pub_key_idx, 0);
+        assert_eq!(info.vendor_lms_pub_key_idx, None);
+        assert!(!info.owner_pub_keys_digest_in_fuses);
+    }
+
+    #[test]
+    fn test_verify_provisioned() {
+        let image = test_image::image_bundle().to_bytes().unwrap();
+        let info = verify_image(&image, &provisioned_fuses(&image)).unwrap();
+        assert_eq!(info.vendor_ecc_pub_key_idx, 0);
+        assert_eq!(info.vendor_lms_pub_key_idx, Some(0));
+        assert!(info.owner_pub_keys_digest_in_fuses);
+    }
+
+    #[test]
+    fn test_verify_vendor_pub_key_digest_mismatch() {
+        let image = test_image::image_bundle().to_bytes().unwrap();
+        let mut fuses = provisioned_fuses(&image);
+        fuses.key_manifest_pk_hash[0] ^= 1;
+        let err = verify_image(&image, &fuses).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            failure_msg(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
+        );
+    }
+
+    #[test]
+    fn test_verify_runtime_digest_mismatch() {
+        let bundle = test_image::image_bundle();
+        let mut image = bundle.to_bytes().unwrap();
+        image[bundle.manifest.runtime.offset as usize] ^= 1;
+        let err = verify_image(&image, &provisioned_fuses(&image)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            failure_msg(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH)
+        );
+    }
+
+    #[test]
+    fn test_verify_truncated() {
+        let image = test_image::image_bundle().to_bytes().unwrap();
+        assert!(verify_image(&image[..100], &Fuses::default()).is_err());
+    }
+
+    #[test]
+    fn test_error_name() {
+        assert_eq!(
+            error_name(CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH),
+            Some("IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH")
+        );
+        assert_eq!(
+            error_name(CaliptraError::IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP),
+            Some("IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP")
+        );
+        assert_eq!(error_name(CaliptraError::DRIVER_SHA384_MAX_DATA_ERR), None);
+    }
+}
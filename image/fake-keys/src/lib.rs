@@ -339,6 +339,7 @@ pub const VENDOR_CONFIG_KEY_0: ImageGeneratorVendorConfig = ImageGeneratorVendor
     not_before: [0u8; 15],
     not_after: [0u8; 15],
     pl0_pauser: Some(0x1),
+    pauser_policy: None,
 };
 
 pub const VENDOR_CONFIG_KEY_1: ImageGeneratorVendorConfig = ImageGeneratorVendorConfig {
@@ -375,18 +376,27 @@ pub const OWNER_CONFIG: ImageGeneratorOwnerConfig = ImageGeneratorOwnerConfig {
 #[test]
 #[ignore]
 fn test_write_lms_keys() {
+    use caliptra_image_openssl::{lms_state_init, lms_state_path};
+    use std::path::Path;
+
     for i in 0..VENDOR_PRIVATE_KEYS.lms_priv_keys.len() {
+        let path = format!(
+            "../../target/riscv32imc-unknown-none-elf/firmware/vnd-lms-priv-key-{}.pem",
+            i
+        );
         let mut file = fs::OpenOptions::new()
             .create(true)
             .write(true)
             .truncate(true)
-            .open(format!(
-                "../../target/riscv32imc-unknown-none-elf/firmware/vnd-lms-priv-key-{}.pem",
-                i
-            ))
+            .open(&path)
             .unwrap();
         file.write_all(VENDOR_PRIVATE_KEYS.lms_priv_keys[i].as_bytes())
             .unwrap();
+        lms_state_init(
+            &lms_state_path(Path::new(&path)),
+            &VENDOR_PRIVATE_KEYS.lms_priv_keys[i],
+        )
+        .unwrap();
     }
     for i in 0..VENDOR_PUBLIC_KEYS.lms_pub_keys.len() {
         let mut file = fs::OpenOptions::new()
@@ -401,14 +411,20 @@ fn test_write_lms_keys() {
         file.write_all(VENDOR_PUBLIC_KEYS.lms_pub_keys[i].as_bytes())
             .unwrap();
     }
+    let path = "../../target/riscv32imc-unknown-none-elf/firmware/own-lms-priv-key.pem";
     let mut file = fs::OpenOptions::new()
         .create(true)
         .write(true)
         .truncate(true)
-        .open("../../target/riscv32imc-unknown-none-elf/firmware/own-lms-priv-key.pem")
+        .open(path)
         .unwrap();
     file.write_all(OWNER_PRIVATE_KEYS.lms_priv_key.as_bytes())
         .unwrap();
+    lms_state_init(
+        &lms_state_path(Path::new(path)),
+        &OWNER_PRIVATE_KEYS.lms_priv_key,
+    )
+    .unwrap();
 
     let mut file = fs::OpenOptions::new()
         .create(true)
@@ -26,6 +26,7 @@ pub struct ImageGenerator<Crypto: ImageGeneratorCrypto> {
 impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
     const DEFAULT_FLAGS: u32 = 0;
     const PL0_PAUSER_FLAG: u32 = (1 << 0);
+    const PAUSER_POLICY_FLAG: u32 = (1 << 1);
 
     /// Create an instance `ImageGenerator`
     pub fn new(crypto: Crypto) -> Self {
@@ -45,7 +46,12 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
     where
         E: ImageGenratorExecutable,
     {
-        if IMAGE_MANIFEST_BYTE_SIZE as u32 + config.fmc.size() + config.runtime.size()
+        if config.aux.len() > AUX_TOC_ENTRY_COUNT {
+            bail!("Image supports at most {AUX_TOC_ENTRY_COUNT} auxiliary images");
+        }
+
+        let aux_size: u32 = config.aux.iter().map(|aux| aux.size()).sum();
+        if IMAGE_MANIFEST_BYTE_SIZE as u32 + config.fmc.size() + config.runtime.size() + aux_size
             > IMAGE_BYTE_SIZE as u32
         {
             bail!("Image larger than {IMAGE_BYTE_SIZE} bytes");
@@ -53,13 +59,14 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
 
         // Create FMC TOC & Content
         let id = ImageTocEntryId::Fmc;
+        let r#type = ImageTocEntryType::Executable;
         let offset = IMAGE_MANIFEST_BYTE_SIZE as u32;
-        let (fmc_toc, fmc) = self.gen_image(&config.fmc, id, offset)?;
+        let (fmc_toc, fmc) = self.gen_image(&config.fmc, id, r#type, offset)?;
 
         // Create Runtime TOC & Content
         let id = ImageTocEntryId::Runtime;
         let offset = offset + fmc_toc.size;
-        let (runtime_toc, runtime) = self.gen_image(&config.runtime, id, offset)?;
+        let (runtime_toc, runtime) = self.gen_image(&config.runtime, id, r#type, offset)?;
 
         // Check if fmc and runtime image load address ranges don't overlap.
         if fmc_toc.overlaps(&runtime_toc) {
@@ -72,11 +79,32 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             );
         }
 
+        // Create auxiliary image TOCs & Contents
+        let mut aux_tocs = [ImageTocEntry::default(); AUX_TOC_ENTRY_COUNT];
+        let mut aux = Vec::with_capacity(config.aux.len());
+        let mut offset = offset + runtime_toc.size;
+        for (i, image) in config.aux.iter().enumerate() {
+            let (toc, content) = self.gen_image(image, image.id, image.r#type, offset)?;
+            if let Some(prev) = aux_tocs[..i].iter().find(|prev| prev.id == toc.id) {
+                bail!("Duplicate auxiliary image id {}", prev.id);
+            }
+            if let Some(prev) = aux_tocs[..i].iter().find(|prev| prev.overlaps(&toc)) {
+                bail!(
+                    "Auxiliary images {0} and {1} load address ranges overlap",
+                    prev.id,
+                    toc.id
+                );
+            }
+            offset += toc.size;
+            aux_tocs[i] = toc;
+            aux.push(content);
+        }
+
         let ecc_key_idx = config.vendor_config.ecc_key_idx;
         let lms_key_idx = config.vendor_config.lms_key_idx;
 
         // Create Header
-        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc)?;
+        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc, &aux_tocs[..aux.len()])?;
         let header = self.gen_header(config, ecc_key_idx, lms_key_idx, toc_digest)?;
 
         // Create Preamable
@@ -98,6 +126,7 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             header,
             fmc: fmc_toc,
             runtime: runtime_toc,
+            aux: aux_tocs,
         };
 
         // Create Image Bundle
@@ -105,11 +134,124 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             manifest,
             fmc,
             runtime,
+            aux,
         };
 
         Ok(image)
     }
 
+    /// Re-sign a vendor signed image manifest with a new owner
+    ///
+    /// The owner public keys and owner validity dates are replaced and the
+    /// owner signatures are recomputed. The vendor header digest does not
+    /// cover the owner data, so the vendor signatures are carried over as is.
+    /// They are not checked here; callers must verify them before re-signing.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Manifest of the vendor signed image
+    /// * `owner_config` - Configuration of the new owner
+    ///
+    /// # Returns
+    ///
+    /// * `ImageManifest` - Manifest signed by the new owner
+    pub fn resign_owner(
+        &self,
+        manifest: &ImageManifest,
+        owner_config: &ImageGeneratorOwnerConfig,
+    ) -> anyhow::Result<ImageManifest> {
+        let Some(priv_keys) = &owner_config.priv_keys else {
+            bail!("Owner private keys are required to re-sign the image");
+        };
+
+        let mut manifest = *manifest;
+        manifest.header.owner_data.owner_not_before = owner_config.not_before;
+        manifest.header.owner_data.owner_not_after = owner_config.not_after;
+
+        let header_digest_owner = self.header_digest_owner(&manifest.header)?;
+        manifest.preamble.owner_pub_keys = owner_config.pub_keys;
+        manifest.preamble.owner_sigs = ImageSignatures {
+            ecc_sig: self.crypto.ecdsa384_sign(
+                &header_digest_owner,
+                &priv_keys.ecc_priv_key,
+                &owner_config.pub_keys.ecc_pub_key,
+            )?,
+            lms_sig: self
+                .crypto
+                .lms_sign(&header_digest_owner, &priv_keys.lms_priv_key)?,
+        };
+
+        Ok(manifest)
+    }
+
+    /// Generate a signed SoC image authorization manifest
+    ///
+    /// The image metadata collection is signed with the vendor keys selected
+    /// by `vendor_config` and with the owner keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `vendor_config` - Vendor configuration of the Caliptra firmware image
+    /// * `owner_config` - Owner configuration of the Caliptra firmware image
+    /// * `image_metadata` - Digests of the authorized SoC images
+    ///
+    /// # Returns
+    ///
+    /// * `AuthManifest` - Signed authorization manifest
+    pub fn gen_auth_manifest(
+        &self,
+        vendor_config: &ImageGeneratorVendorConfig,
+        owner_config: &ImageGeneratorOwnerConfig,
+        image_metadata: &AuthManifestImageMetadataCollection,
+    ) -> anyhow::Result<AuthManifest> {
+        if image_metadata.entry_count as usize > AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT {
+            bail!(
+                "Authorization manifest supports at most {AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT} images"
+            );
+        }
+        let Some(vendor_priv_keys) = &vendor_config.priv_keys else {
+            bail!("Vendor private keys are required to sign the authorization manifest");
+        };
+        let Some(owner_priv_keys) = &owner_config.priv_keys else {
+            bail!("Owner private keys are required to sign the authorization manifest");
+        };
+
+        let digest = self.crypto.sha384_digest(image_metadata.as_bytes())?;
+        let ecc_key_idx = vendor_config.ecc_key_idx as usize;
+        let lms_key_idx = vendor_config.lms_key_idx as usize;
+
+        let vendor_sigs = ImageSignatures {
+            ecc_sig: self.crypto.ecdsa384_sign(
+                &digest,
+                &vendor_priv_keys.ecc_priv_keys[ecc_key_idx],
+                &vendor_config.pub_keys.ecc_pub_keys[ecc_key_idx],
+            )?,
+            lms_sig: self
+                .crypto
+                .lms_sign(&digest, &vendor_priv_keys.lms_priv_keys[lms_key_idx])?,
+        };
+        let owner_sigs = ImageSignatures {
+            ecc_sig: self.crypto.ecdsa384_sign(
+                &digest,
+                &owner_priv_keys.ecc_priv_key,
+                &owner_config.pub_keys.ecc_pub_key,
+            )?,
+            lms_sig: self
+                .crypto
+                .lms_sign(&digest, &owner_priv_keys.lms_priv_key)?,
+        };
+
+        Ok(AuthManifest {
+            preamble: AuthManifestPreamble {
+                marker: AUTH_MANIFEST_MARKER,
+                size: AUTH_MANIFEST_BYTE_SIZE as u32,
+                vendor_sigs,
+                owner_sigs,
+            },
+            image_metadata: *image_metadata,
+        })
+    }
+
     /// Create preable
     pub fn gen_preamble<E>(
         &self,
@@ -185,7 +327,7 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             vendor_ecc_pub_key_idx: ecc_key_idx,
             vendor_lms_pub_key_idx: lms_key_idx,
             flags: Self::DEFAULT_FLAGS,
-            toc_len: MAX_TOC_ENTRY_COUNT,
+            toc_len: MIN_TOC_ENTRY_COUNT + config.aux.len() as u32,
             toc_digest: digest,
             ..Default::default()
         };
@@ -198,6 +340,14 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             header.pl0_pauser = pauser;
         }
 
+        if let Some(policy) = config.vendor_config.pauser_policy {
+            if policy.entry_count as usize > PAUSER_POLICY_ENTRY_COUNT {
+                bail!("PAUSER policy has too many entries");
+            }
+            header.flags |= Self::PAUSER_POLICY_FLAG;
+            header.pauser_policy = policy;
+        }
+
         if let Some(owner_config) = &config.owner_config {
             header.owner_data.owner_not_before = owner_config.not_before;
             header.owner_data.owner_not_after = owner_config.not_after;
@@ -236,12 +386,12 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         &self,
         image: &E,
         id: ImageTocEntryId,
+        r#type: ImageTocEntryType,
         offset: u32,
     ) -> anyhow::Result<(ImageTocEntry, Vec<u8>)>
     where
         E: ImageGenratorExecutable,
     {
-        let r#type = ImageTocEntryType::Executable;
         let digest = self.crypto.sha384_digest(image.content())?;
 
         let entry = ImageTocEntry {
@@ -266,10 +416,12 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         &self,
         fmc_toc: &ImageTocEntry,
         rt_toc: &ImageTocEntry,
+        aux_tocs: &[ImageTocEntry],
     ) -> anyhow::Result<ImageDigest> {
         let mut toc_content: Vec<u8> = Vec::new();
         toc_content.extend_from_slice(fmc_toc.as_bytes());
         toc_content.extend_from_slice(rt_toc.as_bytes());
+        toc_content.extend_from_slice(aux_tocs.as_bytes());
         self.crypto.sha384_digest(&toc_content)
     }
 }
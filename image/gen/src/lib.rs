@@ -82,6 +82,8 @@ pub struct ImageGeneratorVendorConfig {
     pub not_after: [u8; 15],
 
     pub pl0_pauser: Option<u32>,
+
+    pub pauser_policy: Option<ImagePauserPolicy>,
 }
 
 /// Image Generator Owner Configuration
@@ -109,4 +111,60 @@ where
     pub fmc: T,
 
     pub runtime: T,
+
+    pub aux: Vec<ImageGeneratorAuxImage>,
+}
+
+/// Auxiliary image carried in an additional TOC entry
+#[derive(Clone)]
+pub struct ImageGeneratorAuxImage {
+    pub id: ImageTocEntryId,
+
+    pub r#type: ImageTocEntryType,
+
+    pub version: u32,
+
+    pub svn: u32,
+
+    pub min_svn: u32,
+
+    pub rev: ImageRevision,
+
+    pub load_addr: u32,
+
+    pub content: Vec<u8>,
+}
+
+impl ImageGenratorExecutable for ImageGeneratorAuxImage {
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn svn(&self) -> u32 {
+        self.svn
+    }
+
+    fn min_svn(&self) -> u32 {
+        self.min_svn
+    }
+
+    fn rev(&self) -> &ImageRevision {
+        &self.rev
+    }
+
+    fn load_addr(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn entry_point(&self) -> u32 {
+        0
+    }
+
+    fn content(&self) -> &Vec<u8> {
+        &self.content
+    }
+
+    fn size(&self) -> u32 {
+        self.content.len() as u32
+    }
 }
@@ -0,0 +1,198 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   external.rs
+
+Abstract:
+
+    File contains the crypto implementation that delegates signing to an
+    external signer command.
+
+--*/
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, Context};
+
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::*;
+use zerocopy::AsBytes;
+
+use crate::{ecc_sig_from_bytes, from_hw_format, lms_sig_from_bytes, OsslCrypto};
+
+/// Crypto implementation that delegates signing to an external command
+///
+/// The signer is invoked as `<program> <args>... ecc <public key>` or
+/// `<program> <args>... lms <LMS identifier>`, with the key hex encoded.
+/// The 48 byte big-endian digest to sign is written to its standard input
+/// and the signature is read from its standard output: DER or raw `r || s`
+/// for ECC-384, and the raw LMS signature for LMS.
+///
+/// The private keys handed to the signing routines are never used as key
+/// material; [`ExternalSignerCrypto::lms_key_handle`] builds the LMS key
+/// handle from the public key.
+pub struct ExternalSignerCrypto {
+    program: PathBuf,
+    args: Vec<String>,
+    ossl: OsslCrypto,
+}
+
+impl ExternalSignerCrypto {
+    /// Create an instance of `ExternalSignerCrypto`
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - Signer program
+    /// * `args` - Arguments passed to the signer ahead of the key identification
+    pub fn new(program: impl Into<PathBuf>, args: Vec<String>) -> Self {
+        Self {
+            program: program.into(),
+            args,
+            ossl: OsslCrypto::default(),
+        }
+    }
+
+    /// LMS private key handle identifying the key of `pub_key`
+    pub fn lms_key_handle(pub_key: &ImageLmsPublicKey) -> ImageLmsPrivKey {
+        ImageLmsPrivKey {
+            tree_type: pub_key.tree_type,
+            otstype: pub_key.otstype,
+            id: pub_key.id,
+            ..Default::default()
+        }
+    }
+
+    /// Run the signer for `digest` and return its output
+    fn sign(&self, alg: &str, key_id: &[u8], digest: &ImageDigest) -> anyhow::Result<Vec<u8>> {
+        let digest: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .arg(alg)
+            .arg(
+                key_id
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>(),
+            )
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to run signer {}", self.program.display()))?;
+
+        child
+            .stdin
+            .take()
+            .with_context(|| "Signer stdin not available")?
+            .write_all(&digest)?;
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Signer {} failed: {}",
+                self.program.display(),
+                output.status
+            ));
+        }
+
+        Ok(output.stdout)
+    }
+}
+
+impl ImageGeneratorCrypto for ExternalSignerCrypto {
+    /// Calculate SHA-384 Digest
+    fn sha384_digest(&self, data: &[u8]) -> anyhow::Result<ImageDigest> {
+        self.ossl.sha384_digest(data)
+    }
+
+    /// Calculate ECDSA-384 Signature through the signer
+    fn ecdsa384_sign(
+        &self,
+        digest: &ImageDigest,
+        _priv_key: &ImageEccPrivKey,
+        pub_key: &ImageEccPubKey,
+    ) -> anyhow::Result<ImageEccSignature> {
+        let x: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.x);
+        let y: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(&pub_key.y);
+        let key_id = [x, y].concat();
+
+        ecc_sig_from_bytes(&self.sign("ecc", &key_id, digest)?)
+    }
+
+    /// Calculate LMS Signature through the signer
+    fn lms_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageLmsPrivKey,
+    ) -> anyhow::Result<ImageLmsSignature> {
+        lms_sig_from_bytes(&self.sign("lms", priv_key.id.as_bytes(), digest)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_signer(script: &str) -> ExternalSignerCrypto {
+        ExternalSignerCrypto::new("sh", vec!["-c".into(), script.into(), "mock-signer".into()])
+    }
+
+    #[test]
+    fn test_ecdsa384_sign_raw() {
+        // Return a raw signature with all bytes set to 0x01
+        let crypto = mock_signer("cat > /dev/null; printf '%096d' 0 | tr 0 '\\001'");
+        let digest = crypto.sha384_digest(b"caliptra").unwrap();
+
+        let sig = crypto
+            .ecdsa384_sign(
+                &digest,
+                &ImageEccPrivKey::default(),
+                &ImageEccPubKey::default(),
+            )
+            .unwrap();
+
+        assert_eq!(sig.r, [0x01010101; ECC384_SCALAR_WORD_SIZE]);
+        assert_eq!(sig.s, [0x01010101; ECC384_SCALAR_WORD_SIZE]);
+    }
+
+    #[test]
+    fn test_ecdsa384_sign_key_id() {
+        // Fail unless the signer is asked for the expected key
+        let pub_key = ImageEccPubKey {
+            x: [0x11111111; ECC384_SCALAR_WORD_SIZE],
+            y: [0x22222222; ECC384_SCALAR_WORD_SIZE],
+        };
+        let key_id = format!("{}{}", "11".repeat(48), "22".repeat(48));
+        let crypto = mock_signer(&format!(
+            "cat > /dev/null && [ \"$1\" = ecc ] && [ \"$2\" = {key_id} ] && head -c 96 /dev/zero"
+        ));
+
+        let sig = crypto
+            .ecdsa384_sign(
+                &ImageDigest::default(),
+                &ImageEccPrivKey::default(),
+                &pub_key,
+            )
+            .unwrap();
+
+        assert_eq!(sig.r, [0; ECC384_SCALAR_WORD_SIZE]);
+        assert_eq!(sig.s, [0; ECC384_SCALAR_WORD_SIZE]);
+    }
+
+    #[test]
+    fn test_signer_failure() {
+        let crypto = mock_signer("exit 1");
+
+        let result = crypto.lms_sign(
+            &ImageDigest::default(),
+            &ExternalSignerCrypto::lms_key_handle(&ImageLmsPublicKey::default()),
+        );
+
+        assert!(result.is_err());
+    }
+}
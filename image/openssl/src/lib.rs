@@ -12,6 +12,12 @@ Abstract:
 
 --*/
 
+mod external;
+mod lms_state;
+
+pub use external::ExternalSignerCrypto;
+pub use lms_state::{lms_state_init, lms_state_path, StatefulLmsCrypto};
+
 use std::path::PathBuf;
 
 use anyhow::{anyhow, Context};
@@ -99,7 +105,7 @@ impl ImageGeneratorCrypto for OsslCrypto {
         let message: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(digest);
         let mut nonce = [0u8; SHA192_DIGEST_BYTE_SIZE];
         rand_bytes(&mut nonce).unwrap();
-        sign_with_lms_key(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE)
+        sign_with_lms_key(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE, false)
     }
 }
 
@@ -156,6 +162,69 @@ pub fn lms_priv_key_from_pem(path: &PathBuf) -> anyhow::Result<ImageLmsPrivKey>
     ImageLmsPrivKey::read_from(&key_bytes[..]).ok_or(anyhow!("Error parsing LMS priv key"))
 }
 
+/// Generate ECC-384 key pair, returned as private and public key PEM
+pub fn ecc_key_pair_pem() -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+    let key = EcKey::generate(&group)?;
+
+    Ok((key.private_key_to_pem()?, key.public_key_to_pem()?))
+}
+
+/// Generate LMS SHA192 key pair
+pub fn lms_key_pair() -> anyhow::Result<(ImageLmsPrivKey, ImageLmsPublicKey)> {
+    let mut priv_key = ImageLmsPrivKey {
+        tree_type: IMAGE_LMS_TREE_TYPE,
+        otstype: IMAGE_LMS_OTS_TYPE,
+        ..Default::default()
+    };
+    rand_bytes(&mut priv_key.id)?;
+    rand_bytes(priv_key.seed.as_bytes_mut())?;
+
+    let pub_key = generate_lms_pubkey(&priv_key, true)?;
+    Ok((priv_key, pub_key))
+}
+
+/// Read ECC-384 Signature from a DER or raw (r || s) file
+pub fn ecc_sig_from_file(path: &PathBuf) -> anyhow::Result<ImageEccSignature> {
+    let sig_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signature file {}", path.display()))?;
+
+    ecc_sig_from_bytes(&sig_bytes)
+}
+
+/// Read LMS SHA192 Signature from a raw file
+pub fn lms_sig_from_file(path: &PathBuf) -> anyhow::Result<ImageLmsSignature> {
+    let sig_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signature file {}", path.display()))?;
+
+    lms_sig_from_bytes(&sig_bytes)
+}
+
+/// Parse ECC-384 Signature in DER or raw (r || s) format
+fn ecc_sig_from_bytes(sig_bytes: &[u8]) -> anyhow::Result<ImageEccSignature> {
+    if sig_bytes.len() == 2 * ECC384_SCALAR_BYTE_SIZE {
+        let (r, s) = sig_bytes.split_at(ECC384_SCALAR_BYTE_SIZE);
+        return Ok(ImageEccSignature {
+            r: to_hw_format(r),
+            s: to_hw_format(s),
+        });
+    }
+
+    let sig = EcdsaSig::from_der(sig_bytes).with_context(|| "Error parsing ECC signature")?;
+    let r = sig.r().to_vec_padded(ECC384_SCALAR_BYTE_SIZE as i32)?;
+    let s = sig.s().to_vec_padded(ECC384_SCALAR_BYTE_SIZE as i32)?;
+
+    Ok(ImageEccSignature {
+        r: to_hw_format(&r),
+        s: to_hw_format(&s),
+    })
+}
+
+/// Parse LMS SHA192 Signature in raw format
+fn lms_sig_from_bytes(sig_bytes: &[u8]) -> anyhow::Result<ImageLmsSignature> {
+    ImageLmsSignature::read_from(sig_bytes).ok_or(anyhow!("Error parsing LMS signature"))
+}
+
 /// Convert the slice to hardware format
 fn to_hw_format(value: &[u8]) -> [u32; ECC384_SCALAR_WORD_SIZE] {
     let arr = TryInto::<[u8; ECC384_SCALAR_BYTE_SIZE]>::try_into(value).unwrap();
@@ -267,12 +336,17 @@ fn stack_top(st: &[u8], st_index: usize) -> &[u8] {
 }
 
 // // https://datatracker.ietf.org/doc/html/rfc8554#appendix-C
+//
+// Unless `full_tree` is set, trees higher than LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT
+// only carry the leaf SUPPORTED_LMS_Q_VALUE, which is enough for test keys.
+#[allow(clippy::too_many_arguments)]
 fn generate_lms_pubkey_helper(
     id: &[u8],
     ots_alg: LmotsAlgorithmType,
     tree_height: u8,
     seed: &[u8],
     q: Option<u32>,
+    full_tree: bool,
     pub_key: &mut Option<ImageLmsPublicKey>,
     sig: &mut Option<ImageLmsSignature>,
 ) {
@@ -294,7 +368,10 @@ fn generate_lms_pubkey_helper(
     };
     for i in 0..max_idx {
         // TODO: We only support a fixed Q in larger trees
-        if tree_height <= LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT || i == SUPPORTED_LMS_Q_VALUE {
+        if full_tree
+            || tree_height <= LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT
+            || i == SUPPORTED_LMS_Q_VALUE
+        {
             generate_lmots_pubkey_helper(id, i, p, w, seed, &mut k[..]);
         } else {
             k[..].copy_from_slice(&zero_k[..]);
@@ -435,8 +512,19 @@ fn generate_ots_signature_helper(
     sig
 }
 
-#[allow(unused)]
-fn generate_lms_pubkey(priv_key: &ImageLmsPrivKey) -> anyhow::Result<ImageLmsPublicKey> {
+/// Height of the LMS tree of `tree_type`
+fn lms_tree_height(tree_type: LmsAlgorithmType) -> anyhow::Result<u8> {
+    match tree_type {
+        IMAGE_LMS_TREE_TYPE => Ok(15),
+        IMAGE_LMS_TREE_TYPE_HT_5 => Ok(5),
+        _ => Err(anyhow!("Error parsing lms parameters")),
+    }
+}
+
+fn generate_lms_pubkey(
+    priv_key: &ImageLmsPrivKey,
+    full_tree: bool,
+) -> anyhow::Result<ImageLmsPublicKey> {
     match priv_key.tree_type {
         IMAGE_LMS_TREE_TYPE => {}
         IMAGE_LMS_TREE_TYPE_HT_5 => {}
@@ -447,11 +535,7 @@ fn generate_lms_pubkey(priv_key: &ImageLmsPrivKey) -> anyhow::Result<ImageLmsPub
         IMAGE_LMS_OTS_TYPE_8 => {}
         _ => return Err(anyhow!("Error looking up lms ots type")),
     };
-    let height = match priv_key.tree_type {
-        IMAGE_LMS_TREE_TYPE => 15,
-        IMAGE_LMS_TREE_TYPE_HT_5 => 5,
-        _ => return Err(anyhow!("Error parsing lms parameters")),
-    };
+    let height = lms_tree_height(priv_key.tree_type)?;
     let mut pub_key = Some(ImageLmsPublicKey::default());
     if let Some(x) = pub_key.as_mut() {
         x.otstype = priv_key.otstype;
@@ -464,6 +548,7 @@ fn generate_lms_pubkey(priv_key: &ImageLmsPrivKey) -> anyhow::Result<ImageLmsPub
         height,
         priv_key.seed.as_bytes(),
         None,
+        full_tree,
         &mut pub_key,
         &mut None,
     );
@@ -475,6 +560,7 @@ fn sign_with_lms_key(
     message: &[u8],
     nonce: &[u8],
     q: u32,
+    full_tree: bool,
 ) -> anyhow::Result<ImageLmsSignature> {
     match priv_key.tree_type {
         IMAGE_LMS_TREE_TYPE => {}
@@ -486,11 +572,7 @@ fn sign_with_lms_key(
         IMAGE_LMS_OTS_TYPE_8 => {}
         _ => return Err(anyhow!("Error looking up lms ots type")),
     };
-    let height = match priv_key.tree_type {
-        IMAGE_LMS_TREE_TYPE => 15,
-        IMAGE_LMS_TREE_TYPE_HT_5 => 5,
-        _ => return Err(anyhow!("Error parsing lms parameters")),
-    };
+    let height = lms_tree_height(priv_key.tree_type)?;
     if q >= (1 << height) {
         return Err(anyhow!("Invalid q"));
     }
@@ -515,12 +597,121 @@ fn sign_with_lms_key(
         height,
         priv_key.seed.as_bytes(),
         Some(q),
+        full_tree,
         &mut None,
         &mut sig,
     );
     Ok(sig.unwrap())
 }
 
+/// LMS tree node
+type LmsTreeNode = [u8; SHA192_DIGEST_BYTE_SIZE];
+
+/// Winternitz parameters (p, w) of the LM-OTS algorithm of `priv_key`
+fn lmots_params(priv_key: &ImageLmsPrivKey) -> anyhow::Result<(usize, u8)> {
+    match priv_key.otstype {
+        IMAGE_LMS_OTS_TYPE => Ok((51, 4)),
+        IMAGE_LMS_OTS_TYPE_8 => Ok((26, 8)),
+        _ => Err(anyhow!("Error looking up lms ots type")),
+    }
+}
+
+/// Hash of the LMS tree node `r` with the given children, or of the leaf
+/// `r` with the given LM-OTS public key
+fn lms_node_hash(id: &[u8], r: u32, children: &[&[u8]]) -> LmsTreeNode {
+    let d = if children.len() == 1 { D_LEAF } else { D_INTR };
+    let mut hasher = Sha256::new();
+    hasher.update(id);
+    hasher.update(&r.to_be_bytes());
+    hasher.update(&d.to_be_bytes());
+    for child in children {
+        hasher.update(child);
+    }
+    let mut node = LmsTreeNode::default();
+    node.copy_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+    node
+}
+
+/// Compute every node of the LMS tree of `priv_key`
+///
+/// Nodes are indexed as in RFC 8554: node 1 is the root and nodes
+/// `2^h..2^(h+1)` are the leaves. Node 0 is unused.
+fn lms_tree(priv_key: &ImageLmsPrivKey) -> anyhow::Result<Vec<LmsTreeNode>> {
+    let height = lms_tree_height(priv_key.tree_type)?;
+    let (p, w) = lmots_params(priv_key)?;
+    let leaves: u32 = 1 << height;
+
+    let mut tree = vec![LmsTreeNode::default(); 2 * leaves as usize];
+    let mut k = LmsTreeNode::default();
+    for q in 0..leaves {
+        generate_lmots_pubkey_helper(&priv_key.id, q, p, w, priv_key.seed.as_bytes(), &mut k);
+        let r = leaves + q;
+        tree[r as usize] = lms_node_hash(&priv_key.id, r, &[&k]);
+    }
+    for r in (1..leaves).rev() {
+        let (left, right) = (tree[2 * r as usize], tree[2 * r as usize + 1]);
+        tree[r as usize] = lms_node_hash(&priv_key.id, r, &[&left, &right]);
+    }
+    Ok(tree)
+}
+
+/// Sign `message` with the leaf `q` of `priv_key`, taking the
+/// authentication path from `tree`, the precomputed LMS tree of the key
+fn sign_with_lms_tree(
+    priv_key: &ImageLmsPrivKey,
+    tree: &[LmsTreeNode],
+    message: &[u8],
+    nonce: &[u8],
+    q: u32,
+) -> anyhow::Result<ImageLmsSignature> {
+    let height = lms_tree_height(priv_key.tree_type)?;
+    let (p, w) = lmots_params(priv_key)?;
+    if q >= (1 << height) {
+        return Err(anyhow!("Invalid q"));
+    }
+    if tree.len() != 2 << height {
+        return Err(anyhow!("LMS tree does not match the key"));
+    }
+
+    let mut sig = ImageLmsSignature {
+        q: q.into(),
+        ots: generate_ots_signature_helper(
+            message,
+            priv_key.otstype,
+            &priv_key.id,
+            priv_key.seed.as_bytes(),
+            nonce,
+            q,
+        ),
+        tree_type: priv_key.tree_type,
+        ..Default::default()
+    };
+
+    // Walk from the leaf to the root, collecting the siblings and checking
+    // that the path leads to the root of the tree.
+    let mut k = LmsTreeNode::default();
+    generate_lmots_pubkey_helper(&priv_key.id, q, p, w, priv_key.seed.as_bytes(), &mut k);
+    let mut r = (1 << height) + q;
+    let mut node = lms_node_hash(&priv_key.id, r, &[&k]);
+    for level in 0..height as usize {
+        let sibling = tree[(r ^ 1) as usize];
+        sig.tree_path[level]
+            .as_bytes_mut()
+            .copy_from_slice(&sibling);
+        node = if r % 2 == 0 {
+            lms_node_hash(&priv_key.id, r / 2, &[&node, &sibling])
+        } else {
+            lms_node_hash(&priv_key.id, r / 2, &[&sibling, &node])
+        };
+        r /= 2;
+    }
+    if node != tree[1] {
+        return Err(anyhow!("LMS tree does not match the key"));
+    }
+
+    Ok(sig)
+}
+
 #[cfg(test)]
 use caliptra_lms_types::bytes_to_words_6;
 
@@ -538,14 +729,14 @@ fn test_print_lms_private_pub_key() {
     for i in 0..4 {
         rand_bytes(&mut priv_key.id).unwrap();
         rand_bytes(priv_key.seed.as_bytes_mut()).unwrap();
-        let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+        let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
         println!("pub const VENDOR_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
         println!("pub const VENDOR_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
     }
     for i in 0..1 {
         rand_bytes(&mut priv_key.id).unwrap();
         rand_bytes(priv_key.seed.as_bytes_mut()).unwrap();
-        let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+        let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
         println!("pub const OWNER_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
         println!("pub const OWNER_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
     }
@@ -574,7 +765,7 @@ fn test_lms() {
             0x88, 0x27, 0x96, 0x61, 0x83, 0x14, 0x50, 0x8b, 0x12, 0xd2,
         ]),
     };
-    let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+    let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
     assert_eq!(expected_pub_key, pub_key);
 }
 
@@ -763,7 +954,7 @@ fn test_lms_sig() {
         Default::default(),
         Default::default(),
     ];
-    let sig = sign_with_lms_key(&priv_key, &message, &nonce, 5).unwrap();
+    let sig = sign_with_lms_key(&priv_key, &message, &nonce, 5, false).unwrap();
 
     assert_eq!(
         sig,
@@ -1070,7 +1261,7 @@ fn test_lms_sig_h15() {
             0xa4, 0x9a, 0x35, 0x8f, 0xb5, 0x13, 0x71, 0x64, 0xa9, 0x5d,
         ]),
     ];
-    let sig = sign_with_lms_key(&priv_key, &message, nonce.as_bytes(), 5).unwrap();
+    let sig = sign_with_lms_key(&priv_key, &message, nonce.as_bytes(), 5, false).unwrap();
 
     assert_eq!(
         sig,
@@ -1086,3 +1277,37 @@ fn test_lms_sig_h15() {
         }
     );
 }
+
+#[test]
+fn test_sign_with_lms_tree() {
+    let priv_key = ImageLmsPrivKey {
+        tree_type: IMAGE_LMS_TREE_TYPE_HT_5,
+        otstype: IMAGE_LMS_OTS_TYPE_8,
+        id: [0x5a; 16],
+        seed: bytes_to_words_6([0xa5; 24]),
+    };
+    let message = [0x11; SHA384_DIGEST_BYTE_SIZE];
+    let nonce = [0x22; SHA192_DIGEST_BYTE_SIZE];
+
+    let tree = lms_tree(&priv_key).unwrap();
+    assert_eq!(
+        tree[1].as_bytes(),
+        generate_lms_pubkey(&priv_key, true)
+            .unwrap()
+            .digest
+            .as_bytes()
+    );
+
+    for q in [0, 5, 31] {
+        assert_eq!(
+            sign_with_lms_tree(&priv_key, &tree, &message, &nonce, q).unwrap(),
+            sign_with_lms_key(&priv_key, &message, &nonce, q, true).unwrap()
+        );
+    }
+    assert!(sign_with_lms_tree(&priv_key, &tree, &message, &nonce, 32).is_err());
+
+    // A tree of another key is rejected.
+    let mut other_tree = tree;
+    other_tree[33][0] ^= 1;
+    assert!(sign_with_lms_tree(&priv_key, &other_tree, &message, &nonce, 0).is_err());
+}
@@ -0,0 +1,495 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   lms_state.rs
+
+Abstract:
+
+    File contains the crypto implementation that tracks the one-time
+    signature leaf of every LMS private key in a state file.
+
+--*/
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context};
+
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_types::*;
+use caliptra_lms_types::LmsIdentifier;
+use openssl::rand::rand_bytes;
+
+use crate::{
+    from_hw_format, lms_tree, lms_tree_height, sign_with_lms_tree, LmsTreeNode, OsslCrypto,
+};
+
+/// Maximum time to wait for another process to release an LMS state file
+const LMS_STATE_LOCK_TIMEOUT: Duration = Duration::from_secs(if cfg!(test) { 1 } else { 60 });
+
+/// Path of the state file of the LMS private key stored at `priv_key_path`
+pub fn lms_state_path(priv_key_path: &Path) -> PathBuf {
+    path_with_suffix(priv_key_path, ".state")
+}
+
+/// `path` with `suffix` appended to its file name
+fn path_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Atomically replace the contents of `path` with `data`
+fn write_atomic(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    let tmp_path = path_with_suffix(path, ".tmp");
+
+    let mut file = File::create(&tmp_path)
+        .with_context(|| format!("Failed to create file {}", tmp_path.display()))?;
+    file.write_all(data)?;
+    file.sync_all()?;
+
+    fs::rename(&tmp_path, path).with_context(|| format!("Failed to update file {}", path.display()))
+}
+
+/// Create the state file of a new LMS private key
+///
+/// The state file is never overwritten, as that would allow reusing leaves
+/// that have already been used.
+pub fn lms_state_init(path: &Path, priv_key: &ImageLmsPrivKey) -> anyhow::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create LMS state file {}", path.display()))?;
+    file.write_all(
+        LmsState {
+            id: priv_key.id,
+            q: 0,
+        }
+        .to_string()
+        .as_bytes(),
+    )?;
+    file.sync_all()?;
+    Ok(())
+}
+
+/// Leaf index state of an LMS private key
+///
+/// The state file holds the hex encoded LMS identifier of the key followed
+/// by the index of the next unused leaf.
+struct LmsState {
+    id: LmsIdentifier,
+    q: u32,
+}
+
+impl LmsState {
+    /// Load the state from `path`
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let state = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read LMS state file {}", path.display()))?;
+
+        let err = || anyhow!("Error parsing LMS state file {}", path.display());
+        let mut fields = state.split_whitespace();
+        let (Some(id), Some(q), None) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(err());
+        };
+        if id.len() != 2 * core::mem::size_of::<LmsIdentifier>() {
+            return Err(err());
+        }
+        let mut state = Self {
+            id: LmsIdentifier::default(),
+            q: q.parse().map_err(|_| err())?,
+        };
+        for (i, byte) in state.id.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(id.get(2 * i..2 * i + 2).ok_or_else(err)?, 16)
+                .map_err(|_| err())?;
+        }
+        Ok(state)
+    }
+
+    /// Atomically replace the state stored at `path`
+    fn store(&self, path: &Path) -> anyhow::Result<()> {
+        write_atomic(path, self.to_string().as_bytes())
+    }
+}
+
+impl std::fmt::Display for LmsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.id {
+            write!(f, "{byte:02x}")?;
+        }
+        writeln!(f, " {}", self.q)
+    }
+}
+
+/// Exclusive lock of an LMS state file
+///
+/// The lock is a file next to the state file that is created exclusively
+/// and removed when the lock is dropped. If a signing process is killed
+/// while holding the lock, the lock file has to be removed manually.
+struct LmsStateLock(PathBuf);
+
+impl LmsStateLock {
+    /// Lock the state file at `path`, waiting for other processes to
+    /// release it
+    fn acquire(path: &Path) -> anyhow::Result<Self> {
+        let lock_path = path_with_suffix(path, ".lock");
+        let start = Instant::now();
+        loop {
+            match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&lock_path)
+            {
+                Ok(_) => return Ok(Self(lock_path)),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if start.elapsed() >= LMS_STATE_LOCK_TIMEOUT {
+                        return Err(anyhow!(
+                            "LMS state file {} is locked, remove {} if no other process is using it",
+                            path.display(),
+                            lock_path.display()
+                        ));
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to create lock file {}", lock_path.display())
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl Drop for LmsStateLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Load the LMS tree of `priv_key` cached at `path`
+///
+/// The tree file holds the LMS identifier of the key followed by the nodes
+/// of the tree. Returns `None` if the file is missing or does not belong
+/// to the key.
+fn lms_tree_load(
+    path: &Path,
+    priv_key: &ImageLmsPrivKey,
+) -> anyhow::Result<Option<Vec<LmsTreeNode>>> {
+    let data = match fs::read(path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("Failed to read LMS tree file {}", path.display()))
+        }
+    };
+
+    let height = lms_tree_height(priv_key.tree_type)?;
+    let (id, nodes) = data.split_at(data.len().min(priv_key.id.len()));
+    if id != priv_key.id || nodes.len() != (2 << height) * SHA192_DIGEST_BYTE_SIZE {
+        return Ok(None);
+    }
+    Ok(Some(
+        nodes
+            .chunks_exact(SHA192_DIGEST_BYTE_SIZE)
+            .map(|node| node.try_into().unwrap())
+            .collect(),
+    ))
+}
+
+/// Cache the LMS tree of `priv_key` at `path`
+fn lms_tree_store(
+    path: &Path,
+    priv_key: &ImageLmsPrivKey,
+    tree: &[LmsTreeNode],
+) -> anyhow::Result<()> {
+    let mut data = priv_key.id.to_vec();
+    data.extend(tree.iter().flatten());
+    write_atomic(path, &data)
+}
+
+/// Crypto implementation that never reuses an LMS one-time signature leaf
+///
+/// Every LMS private key used for signing must be registered with the path
+/// of its state file. The leaf index is advanced in the state file before
+/// the signature is produced, and signing fails if the state file is
+/// missing or all leaves of the key have been used.
+///
+/// The state file is locked while the leaf index is advanced, and the LMS
+/// tree of the key is cached next to the state file, so that it is only
+/// computed once.
+#[derive(Default)]
+pub struct StatefulLmsCrypto {
+    ossl: OsslCrypto,
+    states: HashMap<LmsIdentifier, PathBuf>,
+    trees: RefCell<HashMap<LmsIdentifier, Vec<LmsTreeNode>>>,
+}
+
+impl StatefulLmsCrypto {
+    /// Register the state file of `priv_key`
+    pub fn add_lms_state(&mut self, priv_key: &ImageLmsPrivKey, path: PathBuf) {
+        self.states.insert(priv_key.id, path);
+    }
+
+    /// Path of the state file of `priv_key`
+    fn state_path(&self, priv_key: &ImageLmsPrivKey) -> anyhow::Result<&Path> {
+        self.states
+            .get(&priv_key.id)
+            .map(PathBuf::as_path)
+            .ok_or_else(|| anyhow!("No LMS state file for the LMS private key"))
+    }
+
+    /// Make sure the LMS tree of `priv_key` is loaded, computing and caching
+    /// it next to the state file at `path` if needed
+    fn load_tree(&self, path: &Path, priv_key: &ImageLmsPrivKey) -> anyhow::Result<()> {
+        if self.trees.borrow().contains_key(&priv_key.id) {
+            return Ok(());
+        }
+
+        let tree_path = path_with_suffix(path, ".tree");
+        let tree = match lms_tree_load(&tree_path, priv_key)? {
+            Some(tree) => tree,
+            None => {
+                let tree = lms_tree(priv_key)?;
+                lms_tree_store(&tree_path, priv_key, &tree)?;
+                tree
+            }
+        };
+        self.trees.borrow_mut().insert(priv_key.id, tree);
+        Ok(())
+    }
+
+    /// Reserve the next unused leaf of `priv_key`
+    ///
+    /// The caller must hold the lock of the state file at `path`.
+    fn next_leaf(&self, path: &Path, priv_key: &ImageLmsPrivKey) -> anyhow::Result<u32> {
+        let state = LmsState::load(path)?;
+        if state.id != priv_key.id {
+            return Err(anyhow!(
+                "LMS state file {} belongs to a different key",
+                path.display()
+            ));
+        }
+
+        let height = lms_tree_height(priv_key.tree_type)?;
+        if state.q >= 1 << height {
+            return Err(anyhow!(
+                "LMS private key of state file {} is exhausted",
+                path.display()
+            ));
+        }
+
+        LmsState {
+            id: state.id,
+            q: state.q + 1,
+        }
+        .store(path)?;
+
+        Ok(state.q)
+    }
+}
+
+impl ImageGeneratorCrypto for StatefulLmsCrypto {
+    /// Calculate SHA-384 Digest
+    fn sha384_digest(&self, data: &[u8]) -> anyhow::Result<ImageDigest> {
+        self.ossl.sha384_digest(data)
+    }
+
+    /// Calculate ECDSA-384 Signature
+    fn ecdsa384_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageEccPrivKey,
+        pub_key: &ImageEccPubKey,
+    ) -> anyhow::Result<ImageEccSignature> {
+        self.ossl.ecdsa384_sign(digest, priv_key, pub_key)
+    }
+
+    /// Calculate LMS Signature with the next unused leaf
+    fn lms_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageLmsPrivKey,
+    ) -> anyhow::Result<ImageLmsSignature> {
+        let path = self.state_path(priv_key)?;
+        let _lock = LmsStateLock::acquire(path)?;
+        self.load_tree(path, priv_key)?;
+        let q = self.next_leaf(path, priv_key)?;
+
+        let message: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+        let mut nonce = [0u8; SHA192_DIGEST_BYTE_SIZE];
+        rand_bytes(&mut nonce)?;
+        let trees = self.trees.borrow();
+        sign_with_lms_tree(priv_key, &trees[&priv_key.id], &message, &nonce, q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IMAGE_LMS_OTS_TYPE_8, IMAGE_LMS_TREE_TYPE_HT_5};
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn priv_key() -> ImageLmsPrivKey {
+        ImageLmsPrivKey {
+            tree_type: IMAGE_LMS_TREE_TYPE_HT_5,
+            otstype: IMAGE_LMS_OTS_TYPE_8,
+            id: [0x5a; 16],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lms_sign_advances_state() {
+        let dir = TempDir::new("lms-state-advance");
+        let priv_key = priv_key();
+        let path = lms_state_path(&dir.0.join("lms-priv-key.pem"));
+        lms_state_init(&path, &priv_key).unwrap();
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path.clone());
+
+        let sig = crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        assert_eq!(sig.q.get(), 0);
+        let sig = crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        assert_eq!(sig.q.get(), 1);
+        assert_eq!(LmsState::load(&path).unwrap().q, 2);
+
+        // The state file of a key in use is never reset
+        assert!(lms_state_init(&path, &priv_key).is_err());
+    }
+
+    #[test]
+    fn test_lms_sign_caches_tree() {
+        let dir = TempDir::new("lms-state-tree");
+        let priv_key = priv_key();
+        let path = lms_state_path(&dir.0.join("lms-priv-key.pem"));
+        let tree_path = path_with_suffix(&path, ".tree");
+        lms_state_init(&path, &priv_key).unwrap();
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path.clone());
+        crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        let tree = lms_tree_load(&tree_path, &priv_key).unwrap().unwrap();
+        assert_eq!(tree, lms_tree(&priv_key).unwrap());
+
+        // A new signer reuses the cached tree.
+        let mut tampered = tree;
+        tampered[1][0] ^= 1;
+        lms_tree_store(&tree_path, &priv_key, &tampered).unwrap();
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path.clone());
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+
+        // The tree of another key is recomputed. The leaf reserved by the
+        // failed signature is not reused.
+        let other_key = ImageLmsPrivKey {
+            id: [0xa5; 16],
+            ..priv_key
+        };
+        lms_tree_store(&tree_path, &other_key, &tampered).unwrap();
+        assert_eq!(lms_tree_load(&tree_path, &priv_key).unwrap(), None);
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path);
+        let sig = crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        assert_eq!(sig.q.get(), 2);
+    }
+
+    #[test]
+    fn test_lms_sign_state_locked() {
+        let dir = TempDir::new("lms-state-locked");
+        let priv_key = priv_key();
+        let path = lms_state_path(&dir.0.join("lms-priv-key.pem"));
+        lms_state_init(&path, &priv_key).unwrap();
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path.clone());
+
+        let lock = LmsStateLock::acquire(&path).unwrap();
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+        assert_eq!(LmsState::load(&path).unwrap().q, 0);
+
+        drop(lock);
+        crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        assert!(!path_with_suffix(&path, ".lock").exists());
+        assert_eq!(LmsState::load(&path).unwrap().q, 1);
+    }
+
+    #[test]
+    fn test_lms_sign_state_missing() {
+        let dir = TempDir::new("lms-state-missing");
+        let priv_key = priv_key();
+
+        let crypto = StatefulLmsCrypto::default();
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, lms_state_path(&dir.0.join("lms-priv-key.pem")));
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+    }
+
+    #[test]
+    fn test_lms_sign_state_exhausted() {
+        let dir = TempDir::new("lms-state-exhausted");
+        let priv_key = priv_key();
+        let path = lms_state_path(&dir.0.join("lms-priv-key.pem"));
+        LmsState {
+            id: priv_key.id,
+            q: 31,
+        }
+        .store(&path)
+        .unwrap();
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path.clone());
+
+        let sig = crypto.lms_sign(&ImageDigest::default(), &priv_key).unwrap();
+        assert_eq!(sig.q.get(), 31);
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+        assert_eq!(LmsState::load(&path).unwrap().q, 32);
+    }
+
+    #[test]
+    fn test_lms_sign_state_other_key() {
+        let dir = TempDir::new("lms-state-other-key");
+        let priv_key = priv_key();
+        let path = lms_state_path(&dir.0.join("lms-priv-key.pem"));
+        LmsState {
+            id: [0xa5; 16],
+            q: 0,
+        }
+        .store(&path)
+        .unwrap();
+
+        let mut crypto = StatefulLmsCrypto::default();
+        crypto.add_lms_state(&priv_key, path);
+
+        assert!(crypto.lms_sign(&ImageDigest::default(), &priv_key).is_err());
+    }
+}
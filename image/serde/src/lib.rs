@@ -11,9 +11,10 @@ Abstract:
     Caliptra Image Bundle serialization & deserialization routines.
 
 --*/
+use anyhow::{anyhow, Context};
 use caliptra_image_types::*;
-use std::io::Write;
-use zerocopy::AsBytes;
+use std::io::{Read, Write};
+use zerocopy::{AsBytes, FromBytes};
 
 /// Image Bundle Writer
 pub struct ImageBundleWriter<W: Write> {
@@ -31,6 +32,52 @@ impl<W: Write> ImageBundleWriter<W> {
         self.writer.write_all(image.manifest.as_bytes())?;
         self.writer.write_all(&image.fmc)?;
         self.writer.write_all(&image.runtime)?;
+        for aux in &image.aux {
+            self.writer.write_all(aux)?;
+        }
         Ok(())
     }
 }
+
+/// Image Bundle Reader
+pub struct ImageBundleReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> ImageBundleReader<R> {
+    /// Create an instance of `ImageBundleReader`
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Read Image Bundle
+    ///
+    /// The images are located through the offsets and sizes in the TOC.
+    pub fn read(&mut self) -> anyhow::Result<ImageBundle> {
+        let mut image = vec![];
+        self.reader.read_to_end(&mut image)?;
+
+        let manifest = ImageManifest::read_from_prefix(image.as_slice())
+            .with_context(|| "Image is smaller than the manifest")?;
+
+        let section = |name: &str, entry: &ImageTocEntry| -> anyhow::Result<Vec<u8>> {
+            let start = entry.offset as usize;
+            let end = start
+                .checked_add(entry.size as usize)
+                .filter(|end| *end <= image.len())
+                .ok_or_else(|| anyhow!("{name} image is outside of the image bundle"))?;
+            Ok(image[start..end].to_vec())
+        };
+
+        Ok(ImageBundle {
+            fmc: section("FMC", &manifest.fmc)?,
+            runtime: section("Runtime", &manifest.runtime)?,
+            aux: manifest
+                .aux_entries()
+                .iter()
+                .map(|entry| section("Auxiliary", entry))
+                .collect::<anyhow::Result<_>>()?,
+            manifest,
+        })
+    }
+}
@@ -0,0 +1,120 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   auth_manifest.rs
+
+Abstract:
+
+    File contains data structures for the SoC image authorization manifest.
+
+--*/
+
+use crate::{ImageSignatures, SHA384_DIGEST_BYTE_SIZE};
+use core::mem::size_of;
+use memoffset::offset_of;
+use zerocopy::{AsBytes, FromBytes};
+use zeroize::Zeroize;
+
+pub const AUTH_MANIFEST_MARKER: u32 = 0x4154_4D4E;
+pub const AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT: usize = 16;
+pub const AUTH_MANIFEST_BYTE_SIZE: usize = size_of::<AuthManifest>();
+
+/// Digest of a single SoC image authorized by the manifest
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Debug, Copy, Clone, Eq, PartialEq, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AuthManifestImageMetadata {
+    /// Firmware ID of the SoC image
+    pub fw_id: [u8; 4],
+
+    /// Security version number of the SoC image, recorded in the measurement
+    /// log when the image is stashed
+    pub svn: u32,
+
+    /// SHA-384 digest of the SoC image
+    pub digest: [u8; SHA384_DIGEST_BYTE_SIZE],
+}
+
+impl Default for AuthManifestImageMetadata {
+    fn default() -> Self {
+        Self {
+            fw_id: Default::default(),
+            svn: Default::default(),
+            digest: [0u8; SHA384_DIGEST_BYTE_SIZE],
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Default, Debug, Copy, Clone, Eq, PartialEq, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AuthManifestImageMetadataCollection {
+    /// Number of valid entries
+    pub entry_count: u32,
+
+    /// Image metadata entries
+    pub entries: [AuthManifestImageMetadata; AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT],
+}
+
+impl AuthManifestImageMetadataCollection {
+    /// Returns the valid entries of the collection
+    pub fn valid_entries(&self) -> &[AuthManifestImageMetadata] {
+        let count = (self.entry_count as usize).min(AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT);
+        &self.entries[..count]
+    }
+}
+
+/// Authorization Manifest Preamble
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Default, Debug, Copy, Clone, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AuthManifestPreamble {
+    /// Marker
+    pub marker: u32,
+
+    /// Size of the authorization manifest
+    pub size: u32,
+
+    /// Vendor signatures over the image metadata collection
+    pub vendor_sigs: ImageSignatures,
+
+    /// Owner signatures over the image metadata collection
+    pub owner_sigs: ImageSignatures,
+}
+
+/// Authorization Manifest
+///
+/// The image metadata collection is signed with the vendor and owner keys
+/// of the Caliptra firmware manifest.
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Default, Debug, Copy, Clone, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AuthManifest {
+    /// Preamble
+    pub preamble: AuthManifestPreamble,
+
+    /// Image metadata collection
+    pub image_metadata: AuthManifestImageMetadataCollection,
+}
+
+impl AuthManifest {
+    /// Returns the byte range of the signed image metadata collection
+    pub fn image_metadata_range() -> core::ops::Range<u32> {
+        let offset = offset_of!(AuthManifest, image_metadata) as u32;
+        offset..offset + size_of::<AuthManifestImageMetadataCollection>() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_manifest_size() {
+        assert_eq!(AUTH_MANIFEST_BYTE_SIZE, 4340);
+        assert_eq!(AuthManifest::image_metadata_range(), 3440..4340);
+    }
+}
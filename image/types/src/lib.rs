@@ -25,10 +25,16 @@ use caliptra_lms_types::{
 use memoffset::{offset_of, span_of};
 use zerocopy::{AsBytes, FromBytes};
 
+mod auth_manifest;
+pub use auth_manifest::*;
+
 pub const MANIFEST_MARKER: u32 = 0x4E414D43;
 pub const VENDOR_ECC_KEY_COUNT: u32 = 4;
 pub const VENDOR_LMS_KEY_COUNT: u32 = 32;
-pub const MAX_TOC_ENTRY_COUNT: u32 = 2;
+pub const MIN_TOC_ENTRY_COUNT: u32 = 2;
+pub const AUX_TOC_ENTRY_COUNT: usize = 4;
+pub const MAX_TOC_ENTRY_COUNT: u32 = MIN_TOC_ENTRY_COUNT + AUX_TOC_ENTRY_COUNT as u32;
+pub const PAUSER_POLICY_ENTRY_COUNT: usize = 4;
 pub const IMAGE_REVISION_BYTE_SIZE: usize = 20;
 pub const ECC384_SCALAR_WORD_SIZE: usize = 12;
 pub const ECC384_SCALAR_BYTE_SIZE: usize = 48;
@@ -91,6 +97,9 @@ pub struct ImageBundle {
 
     /// Runtime
     pub runtime: Vec<u8>,
+
+    /// Auxiliary images, in TOC order
+    pub aux: Vec<Vec<u8>>,
 }
 
 #[cfg(feature = "std")]
@@ -125,6 +134,28 @@ impl ImageBundle {
             ));
         }
         result.extend_from_slice(&self.runtime);
+        let aux_entries = self.manifest.aux_entries();
+        if aux_entries.len() != self.aux.len() {
+            return Err(std::io::Error::new(
+                ErrorKind::Other,
+                "actual aux image count does not match manifest",
+            ));
+        }
+        for (entry, aux) in aux_entries.iter().zip(self.aux.iter()) {
+            if entry.offset as usize != result.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual aux image offset does not match manifest",
+                ));
+            }
+            if entry.size as usize != aux.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual aux image size does not match manifest",
+                ));
+            }
+            result.extend_from_slice(aux);
+        }
         Ok(result)
     }
 }
@@ -151,6 +182,10 @@ pub struct ImageManifest {
 
     /// Runtime TOC Entry
     pub runtime: ImageTocEntry,
+
+    /// Auxiliary TOC Entries. Only the first `header.toc_len - MIN_TOC_ENTRY_COUNT`
+    /// entries are part of the image.
+    pub aux: [ImageTocEntry; AUX_TOC_ENTRY_COUNT],
 }
 
 impl Default for ImageManifest {
@@ -162,6 +197,7 @@ impl Default for ImageManifest {
             header: ImageHeader::default(),
             fmc: ImageTocEntry::default(),
             runtime: ImageTocEntry::default(),
+            aux: Default::default(),
         }
     }
 }
@@ -186,10 +222,25 @@ impl ImageManifest {
         span.start as u32..span.end as u32
     }
 
-    /// Returns `Range<u32>` containing the table of contents
-    pub fn toc_range() -> Range<u32> {
-        let span = span_of!(ImageManifest, fmc..=runtime);
-        span.start as u32..span.end as u32
+    /// Returns `Range<u32>` containing the first `toc_len` entries of the
+    /// table of contents
+    pub fn toc_range(toc_len: u32) -> Range<u32> {
+        let start = offset_of!(ImageManifest, fmc) as u32;
+        let len = toc_len.min(MAX_TOC_ENTRY_COUNT) * size_of::<ImageTocEntry>() as u32;
+        start..start + len
+    }
+
+    /// Returns the auxiliary TOC entries that are part of the image
+    pub fn aux_entries(&self) -> &[ImageTocEntry] {
+        let count = (self.header.toc_len.saturating_sub(MIN_TOC_ENTRY_COUNT) as usize)
+            .min(AUX_TOC_ENTRY_COUNT);
+        &self.aux[..count]
+    }
+
+    /// Returns the auxiliary TOC entry with the given `id`, if any
+    pub fn aux_entry(&self, id: ImageTocEntryId) -> Option<&ImageTocEntry> {
+        let id = u32::from(id);
+        self.aux_entries().iter().find(|e| e.id == id)
     }
 }
 
@@ -304,6 +355,8 @@ pub struct ImageHeader {
 
     /// Flags
     /// Bit 0: Interpret the pl0_pauser field. If not set, all PAUSERs are PL1.
+    /// Bit 1: Enforce the pauser_policy field. If not set, all PAUSERs may
+    /// issue all runtime mailbox commands.
     pub flags: u32,
 
     /// TOC Entry Count
@@ -313,6 +366,9 @@ pub struct ImageHeader {
     /// only one PAUSER to be PL0.
     pub pl0_pauser: u32,
 
+    /// Runtime mailbox commands each PAUSER is allowed to issue
+    pub pauser_policy: ImagePauserPolicy,
+
     /// TOC Digest
     pub toc_digest: ImageDigest,
 
@@ -323,10 +379,47 @@ pub struct ImageHeader {
     pub owner_data: OwnerSignedData,
 }
 
-/// Caliptra table contents entry id
+/// Runtime mailbox command policy for a single PAUSER
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Default, Debug, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImagePauserPolicyEntry {
+    /// PAUSER the entry applies to
+    pub pauser: u32,
+
+    /// Bitmap of allowed commands, least significant word first. The bit
+    /// assigned to each command is defined by `caliptra_api::CommandPermissions`.
+    pub allowed_cmds: [u32; 2],
+}
+
+/// Runtime mailbox command policy
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Default, Debug, Zeroize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImagePauserPolicy {
+    /// Number of valid entries
+    pub entry_count: u32,
+
+    /// Policy entries. PAUSERs without an entry may not issue any command.
+    pub entries: [ImagePauserPolicyEntry; PAUSER_POLICY_ENTRY_COUNT],
+}
+
+impl ImagePauserPolicy {
+    /// Returns the policy entry for `pauser`, if any
+    pub fn entry(&self, pauser: u32) -> Option<&ImagePauserPolicyEntry> {
+        let count = (self.entry_count as usize).min(PAUSER_POLICY_ENTRY_COUNT);
+        self.entries[..count].iter().find(|e| e.pauser == pauser)
+    }
+}
+
+/// Caliptra table contents entry type
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ImageTocEntryType {
-    /// First mutable code
+    /// Executable code
     Executable = 1,
+
+    /// Data
+    Data = 2,
 }
 
 impl From<ImageTocEntryType> for u32 {
@@ -336,13 +429,33 @@ impl From<ImageTocEntryType> for u32 {
     }
 }
 
+impl TryFrom<u32> for ImageTocEntryType {
+    type Error = ();
+
+    /// Converts to this type from the input type.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Executable),
+            2 => Ok(Self::Data),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Caliptra table contents entry id
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ImageTocEntryId {
     /// First mutable code
     Fmc = 1,
 
     /// Runtime
     Runtime = 2,
+
+    /// DPE configuration
+    DpeConfig = 3,
+
+    /// SoC policy
+    SocPolicy = 4,
 }
 
 impl From<ImageTocEntryId> for u32 {
@@ -352,6 +465,21 @@ impl From<ImageTocEntryId> for u32 {
     }
 }
 
+impl TryFrom<u32> for ImageTocEntryId {
+    type Error = ();
+
+    /// Converts to this type from the input type.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Fmc),
+            2 => Ok(Self::Runtime),
+            3 => Ok(Self::DpeConfig),
+            4 => Ok(Self::SocPolicy),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Caliptra Table of contents entry
 #[repr(C)]
 #[derive(AsBytes, Clone, Copy, FromBytes, Default, Debug, Zeroize)]
@@ -428,6 +556,43 @@ mod tests {
         assert_eq!(std::mem::size_of::<ImageManifest>() % 4, 0);
     }
 
+    #[test]
+    fn test_toc_range() {
+        let start = offset_of!(ImageManifest, fmc) as u32;
+        assert_eq!(
+            ImageManifest::toc_range(MIN_TOC_ENTRY_COUNT),
+            start..offset_of!(ImageManifest, aux) as u32
+        );
+        assert_eq!(
+            ImageManifest::toc_range(MAX_TOC_ENTRY_COUNT),
+            start..size_of::<ImageManifest>() as u32
+        );
+        assert_eq!(
+            ImageManifest::toc_range(u32::MAX),
+            ImageManifest::toc_range(MAX_TOC_ENTRY_COUNT)
+        );
+    }
+
+    #[test]
+    fn test_aux_entries() {
+        let mut manifest = ImageManifest::default();
+        manifest.aux[0].id = ImageTocEntryId::DpeConfig.into();
+        manifest.aux[1].id = ImageTocEntryId::SocPolicy.into();
+
+        manifest.header.toc_len = MIN_TOC_ENTRY_COUNT;
+        assert!(manifest.aux_entries().is_empty());
+        assert!(manifest.aux_entry(ImageTocEntryId::DpeConfig).is_none());
+
+        manifest.header.toc_len = MIN_TOC_ENTRY_COUNT + 1;
+        assert_eq!(manifest.aux_entries().len(), 1);
+        assert!(manifest.aux_entry(ImageTocEntryId::DpeConfig).is_some());
+        assert!(manifest.aux_entry(ImageTocEntryId::SocPolicy).is_none());
+
+        manifest.header.toc_len = u32::MAX;
+        assert_eq!(manifest.aux_entries().len(), AUX_TOC_ENTRY_COUNT);
+        assert!(manifest.aux_entry(ImageTocEntryId::SocPolicy).is_some());
+    }
+
     #[test]
     fn test_image_overlap() {
         let mut image1 = ImageTocEntry::default();
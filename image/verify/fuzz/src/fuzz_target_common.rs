@@ -136,6 +136,13 @@ impl ImageVerificationEnv for TestEnv {
         }
     }
 
+    fn aux_image_range(&self) -> Range<u32> {
+        Range {
+            start: AUX_IMAGE_ORG,
+            end: AUX_IMAGE_ORG + AUX_IMAGE_SIZE,
+        }
+    }
+
     fn lms_verify_enabled(&self) -> bool {
         true
     }
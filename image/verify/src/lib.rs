@@ -160,6 +160,9 @@ pub trait ImageVerificationEnv {
     // ICCM Range
     fn iccm_range(&self) -> Range<u32>;
 
+    // DCCM Range auxiliary images are loaded into
+    fn aux_image_range(&self) -> Range<u32>;
+
     // LMS Verification enabled
     fn lms_verify_enabled(&self) -> bool;
 
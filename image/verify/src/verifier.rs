@@ -569,13 +569,16 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         verify_info: &TocInfo,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageInfo<'a>> {
-        if cfi_launder(verify_info.len) != MAX_TOC_ENTRY_COUNT {
+        if cfi_launder(verify_info.len) < MIN_TOC_ENTRY_COUNT
+            || cfi_launder(verify_info.len) > MAX_TOC_ENTRY_COUNT
+        {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)?;
         } else {
-            cfi_assert_eq(verify_info.len, MAX_TOC_ENTRY_COUNT);
+            cfi_assert_ge(verify_info.len, MIN_TOC_ENTRY_COUNT);
+            cfi_assert_le(verify_info.len, MAX_TOC_ENTRY_COUNT);
         }
 
-        let range = ImageManifest::toc_range();
+        let range = ImageManifest::toc_range(verify_info.len);
 
         let actual = self
             .env
@@ -604,7 +607,12 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         // Image length does not exceed the Image Bundle size
         let img_len: u64 = manifest.size as u64
             + manifest.fmc.image_size() as u64
-            + manifest.runtime.image_size() as u64;
+            + manifest.runtime.image_size() as u64
+            + manifest
+                .aux_entries()
+                .iter()
+                .map(|entry| entry.image_size() as u64)
+                .sum::<u64>();
 
         if img_len > img_bundle_sz.into() {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)?;
@@ -643,6 +651,16 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             Err(CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP)?;
         }
 
+        // Verify the auxiliary images.
+        let aux_entries = manifest.aux_entries();
+        for (i, entry) in aux_entries.iter().enumerate() {
+            self.verify_aux(
+                entry,
+                &aux_entries[..i],
+                &[fmc_range.clone(), runtime_range.clone()],
+            )?;
+        }
+
         let info = ImageInfo {
             fmc: &manifest.fmc,
             runtime: &manifest.runtime,
@@ -651,6 +669,84 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok(info)
     }
 
+    /// Verify an auxiliary image
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_info`  - Auxiliary TOC entry
+    /// * `prev_entries` - Auxiliary TOC entries preceding `verify_info`
+    /// * `fw_ranges`    - Image bundle ranges of the FMC and Runtime images
+    #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
+    fn verify_aux(
+        &mut self,
+        verify_info: &ImageTocEntry,
+        prev_entries: &[ImageTocEntry],
+        fw_ranges: &[Range<u32>],
+    ) -> CaliptraResult<()> {
+        match ImageTocEntryId::try_from(verify_info.id) {
+            Ok(ImageTocEntryId::DpeConfig) | Ok(ImageTocEntryId::SocPolicy) => {}
+            _ => Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_INVALID)?,
+        }
+
+        if prev_entries.iter().any(|entry| entry.id == verify_info.id) {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_DUPLICATE)?;
+        }
+
+        // The auxiliary image region is not executable.
+        if ImageTocEntryType::try_from(verify_info.r#type) != Ok(ImageTocEntryType::Data) {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_TYPE_INVALID)?;
+        }
+
+        if verify_info.image_size() == 0 {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_SIZE_ZERO)?;
+        }
+
+        let range = verify_info.image_range()?;
+
+        // The image must be loaded into the auxiliary image region.
+        let aux_image_range = self.env.aux_image_range();
+        match verify_info.load_addr.checked_add(verify_info.image_size()) {
+            Some(load_addr_end)
+                if verify_info.load_addr >= aux_image_range.start
+                    && load_addr_end <= aux_image_range.end => {}
+            _ => Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_INVALID)?,
+        }
+        if verify_info.load_addr % 4 != 0 {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_UNALIGNED)?;
+        }
+        if prev_entries.iter().any(|entry| entry.overlaps(verify_info)) {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_OVERLAP)?;
+        }
+
+        // The image must not overlap the FMC, Runtime or another auxiliary
+        // image in the image bundle.
+        let overlaps = |other: &Range<u32>| range.start < other.end && range.end > other.start;
+        if fw_ranges.iter().any(overlaps) {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP)?;
+        }
+        for entry in prev_entries {
+            if overlaps(&entry.image_range()?) {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP)?;
+            }
+        }
+
+        let actual = self
+            .env
+            .sha384_digest(range.start, range.len() as u32)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_AUX_DIGEST_FAILURE
+            })?;
+
+        if cfi_launder(verify_info.digest) != actual {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_DIGEST_MISMATCH)?;
+        } else {
+            caliptra_cfi_lib::cfi_assert_eq_12_words(&verify_info.digest, &actual);
+        }
+
+        Ok(())
+    }
+
     // Check if SVN check is required
     #[inline(always)]
     fn svn_check_required(&mut self) -> bool {
@@ -1383,15 +1479,17 @@ mod tests {
         let manifest = ImageManifest::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
-        let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT / 2,
-            digest: &ImageDigest::default(),
-        };
-        let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
-        assert_eq!(
-            result.err(),
-            Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
-        );
+        for len in [MIN_TOC_ENTRY_COUNT - 1, MAX_TOC_ENTRY_COUNT + 1] {
+            let toc_info = TocInfo {
+                len,
+                digest: &ImageDigest::default(),
+            };
+            let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
+            assert_eq!(
+                result.err(),
+                Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
+            );
+        }
     }
 
     #[test]
@@ -1400,7 +1498,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &DUMMY_DATA,
         };
         let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
@@ -1416,7 +1514,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1546,7 +1644,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1591,7 +1689,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1618,7 +1716,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1645,7 +1743,7 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: MIN_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
         };
 
@@ -1817,6 +1915,209 @@ mod tests {
         assert_eq!(info.size, 100);
     }
 
+    fn aux_entry(id: ImageTocEntryId, load_addr: u32, size: u32) -> ImageTocEntry {
+        ImageTocEntry {
+            id: id.into(),
+            r#type: ImageTocEntryType::Data.into(),
+            load_addr,
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_aux_success() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let dpe_config = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        let mut soc_policy = aux_entry(ImageTocEntryId::SocPolicy, AUX_IMAGE_ORG + 100, 100);
+        soc_policy.offset = 100;
+        assert_eq!(verifier.verify_aux(&dpe_config, &[], &[]), Ok(()));
+        assert_eq!(verifier.verify_aux(&soc_policy, &[dpe_config], &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_aux_id_invalid() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        for id in [ImageTocEntryId::Fmc, ImageTocEntryId::Runtime] {
+            let entry = aux_entry(id, AUX_IMAGE_ORG, 100);
+            assert_eq!(
+                verifier.verify_aux(&entry, &[], &[]),
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_INVALID)
+            );
+        }
+        let mut entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        entry.id = 0xff;
+        assert_eq!(
+            verifier.verify_aux(&entry, &[], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_INVALID)
+        );
+    }
+
+    #[test]
+    fn test_aux_id_duplicate() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let first = aux_entry(ImageTocEntryId::SocPolicy, AUX_IMAGE_ORG, 100);
+        let second = aux_entry(ImageTocEntryId::SocPolicy, AUX_IMAGE_ORG + 100, 100);
+        assert_eq!(
+            verifier.verify_aux(&second, &[first], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_DUPLICATE)
+        );
+    }
+
+    #[test]
+    fn test_aux_type_invalid() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        for r#type in [0, ImageTocEntryType::Executable.into()] {
+            entry.r#type = r#type;
+            assert_eq!(
+                verifier.verify_aux(&entry, &[], &[]),
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_TYPE_INVALID)
+            );
+        }
+    }
+
+    #[test]
+    fn test_aux_size_zero() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 0);
+        assert_eq!(
+            verifier.verify_aux(&entry, &[], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_SIZE_ZERO)
+        );
+    }
+
+    #[test]
+    fn test_aux_contained_in_aux_image_region() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        for (load_addr, size) in [
+            (ICCM_ORG, 100),
+            (AUX_IMAGE_ORG - 4, 100),
+            (AUX_IMAGE_ORG, AUX_IMAGE_SIZE + 1),
+            (u32::MAX - 3, 100),
+        ] {
+            let entry = aux_entry(ImageTocEntryId::DpeConfig, load_addr, size);
+            assert_eq!(
+                verifier.verify_aux(&entry, &[], &[]),
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_INVALID)
+            );
+        }
+
+        let entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, AUX_IMAGE_SIZE);
+        assert_eq!(verifier.verify_aux(&entry, &[], &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_aux_load_addr_unaligned() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG + 1, 100);
+        assert_eq!(
+            verifier.verify_aux(&entry, &[], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_UNALIGNED)
+        );
+    }
+
+    #[test]
+    fn test_aux_load_addr_overlap() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let first = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        let second = aux_entry(ImageTocEntryId::SocPolicy, AUX_IMAGE_ORG + 96, 100);
+        assert_eq!(
+            verifier.verify_aux(&second, &[first], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_LOAD_ADDR_OVERLAP)
+        );
+    }
+
+    #[test]
+    fn test_aux_image_overlap() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let fw_ranges = [0..100, 100..300];
+        let mut entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        for offset in [0, 150, 296] {
+            entry.offset = offset;
+            assert_eq!(
+                verifier.verify_aux(&entry, &[], &fw_ranges),
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP)
+            );
+        }
+
+        entry.offset = 300;
+        assert_eq!(verifier.verify_aux(&entry, &[], &fw_ranges), Ok(()));
+
+        let mut second = aux_entry(ImageTocEntryId::SocPolicy, AUX_IMAGE_ORG + 100, 100);
+        second.offset = 396;
+        assert_eq!(
+            verifier.verify_aux(&second, &[entry], &fw_ranges),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_IMAGE_OVERLAP)
+        );
+
+        second.offset = 400;
+        assert_eq!(verifier.verify_aux(&second, &[entry], &fw_ranges), Ok(()));
+    }
+
+    #[test]
+    fn test_aux_digest_mismatch() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut entry = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        entry.digest = DUMMY_DATA;
+        assert_eq!(
+            verifier.verify_aux(&entry, &[], &[]),
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_AUX_DIGEST_MISMATCH)
+        );
+    }
+
+    #[test]
+    fn test_toc_aux_entries() {
+        let mut manifest = ImageManifest::default();
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let toc_info = TocInfo {
+            len: MIN_TOC_ENTRY_COUNT + 2,
+            digest: &ImageDigest::default(),
+        };
+
+        manifest.header.toc_len = toc_info.len;
+        manifest.fmc.offset = 0;
+        manifest.fmc.size = 100;
+        manifest.runtime.offset = 100;
+        manifest.runtime.size = 200;
+        manifest.fmc.load_addr = 0x1000;
+        manifest.runtime.load_addr = 0x2000;
+        manifest.aux[0] = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, 100);
+        manifest.aux[0].offset = 300;
+        manifest.aux[1] = aux_entry(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG + 100, 100);
+        manifest.aux[1].offset = 400;
+        let bundle_size = manifest.size + 500;
+
+        // The image does not fit the bundle once the auxiliary images are accounted for.
+        let result = verifier.verify_toc(&manifest, &toc_info, bundle_size - 1);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)
+        );
+
+        // Every auxiliary entry is verified.
+        let result = verifier.verify_toc(&manifest, &toc_info, bundle_size);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_AUX_ID_DUPLICATE)
+        );
+
+        manifest.aux[1].id = ImageTocEntryId::SocPolicy.into();
+        let result = verifier.verify_toc(&manifest, &toc_info, bundle_size);
+        assert!(result.is_ok());
+    }
+
     struct TestEnv {
         digest: ImageDigest,
         fmc_digest: ImageDigest,
@@ -1931,6 +2232,13 @@ mod tests {
             }
         }
 
+        fn aux_image_range(&self) -> Range<u32> {
+            Range {
+                start: AUX_IMAGE_ORG,
+                end: AUX_IMAGE_ORG + AUX_IMAGE_SIZE,
+            }
+        }
+
         fn lms_verify_enabled(&self) -> bool {
             true
         }
@@ -0,0 +1,77 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    drbg_kat.rs
+
+Abstract:
+
+    File contains the Known Answer Tests (KAT) for the CSRNG CTR_DRBG.
+
+--*/
+
+use caliptra_drivers::{CaliptraError, CaliptraResult, Csrng, CsrngSeed};
+
+const SEED: [u32; 12] = [
+    0x73bec010, 0x9262474c, 0x16a30f76, 0x531b51de, 0x2ee494e5, 0xdfec9db3, 0xcb7a879d, 0x5600419c,
+    0xca79b0b0, 0xdda33b5c, 0xa468649e, 0xdf5d73fa,
+];
+
+const EXPECTED_OUTPUT: [u32; 12] = [
+    0x725eda90, 0xc79b4a14, 0xe43b74ac, 0x9d9a938b, 0xc395a610, 0x4c5a1483, 0xa45f15e8, 0x2708cbef,
+    0x89eb63a9, 0x70cdc6bc, 0x710daba1, 0xed39808c,
+];
+
+#[derive(Default, Debug)]
+pub struct DrbgKat {}
+
+impl DrbgKat {
+    /// This function executes the Known Answer Tests (aka KAT) for the
+    /// AES-256 CTR_DRBG inside the CSRNG.
+    ///
+    /// The DRBG is instantiated with a constant seed for the test and
+    /// re-instantiated from the entropy source afterwards, whatever the
+    /// outcome.
+    ///
+    /// Test vector source:
+    /// https://github.com/lowRISC/opentitan/blob/ff70cfe194f5a2bb08c1a87a949b5c45746a5d99/sw/device/tests/csrng_smoketest.c#L27
+    ///
+    /// # Arguments
+    ///
+    /// * `csrng` - CSRNG Driver
+    ///
+    /// # Returns
+    ///
+    /// * `CaliptraResult` - Result denoting the KAT outcome.
+    pub fn execute(&self, csrng: &mut Csrng) -> CaliptraResult<()> {
+        let result = self.kat_ctr_drbg(csrng);
+
+        csrng
+            .reinstantiate(CsrngSeed::EntropySrc)
+            .map_err(|_| CaliptraError::ROM_KAT_DRBG_INSTANTIATE_FAILURE)?;
+
+        result
+    }
+
+    fn kat_ctr_drbg(&self, csrng: &mut Csrng) -> CaliptraResult<()> {
+        csrng
+            .reinstantiate(CsrngSeed::Constant(&SEED))
+            .map_err(|_| CaliptraError::ROM_KAT_DRBG_INSTANTIATE_FAILURE)?;
+
+        // The reference test discards the first block of output.
+        csrng
+            .generate12()
+            .map_err(|_| CaliptraError::ROM_KAT_DRBG_GENERATE_FAILURE)?;
+        let output = csrng
+            .generate12()
+            .map_err(|_| CaliptraError::ROM_KAT_DRBG_GENERATE_FAILURE)?;
+
+        if output != EXPECTED_OUTPUT {
+            Err(CaliptraError::ROM_KAT_DRBG_OUTPUT_MISMATCH)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    ecc384_pct_kat.rs
+
+Abstract:
+
+    File contains the ECDSA pairwise consistency test for ECC-384 key generation.
+
+--*/
+
+use caliptra_drivers::{
+    Array4x12, CaliptraError, CaliptraResult, Ecc384, Ecc384PubKey, Ecc384Result, Trng,
+};
+use zeroize::Zeroize;
+
+// SHA2-384("abc")
+const DIGEST: Array4x12 = Array4x12::new([
+    0xcb00753f, 0x45a35e8b, 0xb5a03d69, 0x9ac65007, 0x272c32ab, 0x0eded163, 0x1a8b605a, 0x43ff5bed,
+    0x8086072b, 0xa1e7cc23, 0x58baeca1, 0x34c825a7,
+]);
+
+#[derive(Default, Debug)]
+pub struct Ecc384PctKat {}
+
+impl Ecc384PctKat {
+    /// This function executes the ECDSA pairwise consistency test.
+    ///
+    /// A key pair is generated from a random seed, a digest is signed with the
+    /// private key and the signature is verified with the public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `ecc` - ECC-384 Driver
+    /// * `trng` - TRNG Driver
+    ///
+    /// # Returns
+    ///
+    /// * `CaliptraResult` - Result denoting the KAT outcome.
+    pub fn execute(&self, ecc: &mut Ecc384, trng: &mut Trng) -> CaliptraResult<()> {
+        let seed = trng
+            .generate()
+            .map_err(|_| CaliptraError::ROM_KAT_ECC384_PCT_KEY_PAIR_FAILURE)?;
+        let nonce = trng
+            .generate()
+            .map_err(|_| CaliptraError::ROM_KAT_ECC384_PCT_KEY_PAIR_FAILURE)?;
+
+        let mut priv_key = Array4x12::default();
+        let result = self.sign_and_verify(ecc, trng, &seed, &nonce, &mut priv_key);
+        priv_key.zeroize();
+        result
+    }
+
+    fn sign_and_verify(
+        &self,
+        ecc: &mut Ecc384,
+        trng: &mut Trng,
+        seed: &Array4x12,
+        nonce: &Array4x12,
+        priv_key: &mut Array4x12,
+    ) -> CaliptraResult<()> {
+        let pub_key: Ecc384PubKey = ecc
+            .key_pair(&seed.into(), nonce, trng, (&mut *priv_key).into())
+            .map_err(|_| CaliptraError::ROM_KAT_ECC384_PCT_KEY_PAIR_FAILURE)?;
+
+        let signature = ecc
+            .sign(&(&*priv_key).into(), &pub_key, &DIGEST, trng)
+            .map_err(|_| CaliptraError::ROM_KAT_ECC384_PCT_SIGN_FAILURE)?;
+
+        let result = ecc
+            .verify(&pub_key, &DIGEST, &signature)
+            .map_err(|_| CaliptraError::ROM_KAT_ECC384_PCT_VERIFY_FAILURE)?;
+        if result != Ecc384Result::Success {
+            Err(CaliptraError::ROM_KAT_ECC384_PCT_VERIFY_FAILURE)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    kat_report.rs
+
+Abstract:
+
+    File contains the selection and per-test results of the Known Answer Tests.
+
+--*/
+
+use bitflags::bitflags;
+use caliptra_drivers::CaliptraResult;
+use zerocopy::{AsBytes, FromBytes};
+
+/// Number of Known Answer Tests in the suite.
+pub const KAT_COUNT: usize = 11;
+
+/// Identifies a Known Answer Test. The value is the index of its result in a
+/// [`KatReport`] and the bit position in a [`KatSet`].
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KatId {
+    Sha1 = 0,
+    Sha256 = 1,
+    Sha384 = 2,
+    Sha384Acc = 3,
+    Ecc384 = 4,
+    Ecc384Pct = 5,
+    Hmac384 = 6,
+    Hmac384Kdf = 7,
+    Lms = 8,
+    Drbg = 9,
+    TrngHealth = 10,
+}
+
+bitflags! {
+    /// Selection of Known Answer Tests to execute.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    pub struct KatSet: u32 {
+        const SHA1 = 1 << KatId::Sha1 as u32;
+        const SHA256 = 1 << KatId::Sha256 as u32;
+        const SHA384 = 1 << KatId::Sha384 as u32;
+        const SHA384_ACC = 1 << KatId::Sha384Acc as u32;
+        const ECC384 = 1 << KatId::Ecc384 as u32;
+        const ECC384_PCT = 1 << KatId::Ecc384Pct as u32;
+        const HMAC384 = 1 << KatId::Hmac384 as u32;
+        const HMAC384_KDF = 1 << KatId::Hmac384Kdf as u32;
+        const LMS = 1 << KatId::Lms as u32;
+        const DRBG = 1 << KatId::Drbg as u32;
+        const TRNG_HEALTH = 1 << KatId::TrngHealth as u32;
+    }
+}
+
+impl From<KatId> for KatSet {
+    fn from(id: KatId) -> Self {
+        Self::from_bits_retain(1 << id as u32)
+    }
+}
+
+/// Outcome of a single Known Answer Test.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KatStatus {
+    /// The test was not selected.
+    NotRun = 0,
+    Passed = 1,
+    Failed = 2,
+    /// The test was selected but does not apply to this device, e.g. the
+    /// DRBG KAT when the TRNG is external.
+    Skipped = 3,
+}
+
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct KatResult {
+    /// [`KatId`] of the test
+    pub kat_id: u32,
+
+    /// [`KatStatus`] of the test
+    pub status: u32,
+
+    /// Error code if the test failed, zero otherwise
+    pub error: u32,
+
+    /// Duration of the test in core clock cycles
+    pub cycles: u32,
+}
+
+#[repr(C)]
+#[derive(AsBytes, FromBytes, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KatReport {
+    /// Results indexed by [`KatId`]
+    pub results: [KatResult; KAT_COUNT],
+}
+
+impl Default for KatReport {
+    fn default() -> Self {
+        let mut results = [KatResult::default(); KAT_COUNT];
+        for (i, result) in results.iter_mut().enumerate() {
+            result.kat_id = i as u32;
+        }
+        Self { results }
+    }
+}
+
+impl KatReport {
+    /// Records the outcome of the test `id`.
+    pub fn record(&mut self, id: KatId, result: &CaliptraResult<()>, cycles: u64) {
+        if let Some(entry) = self.results.get_mut(id as usize) {
+            (entry.status, entry.error) = match result {
+                Ok(()) => (KatStatus::Passed as u32, 0),
+                Err(err) => (KatStatus::Failed as u32, u32::from(*err)),
+            };
+            entry.cycles = u32::try_from(cycles).unwrap_or(u32::MAX);
+        }
+    }
+
+    /// Records that the test `id` does not apply to this device.
+    pub fn record_skipped(&mut self, id: KatId) {
+        if let Some(entry) = self.results.get_mut(id as usize) {
+            entry.status = KatStatus::Skipped as u32;
+            entry.error = 0;
+            entry.cycles = 0;
+        }
+    }
+}
@@ -14,26 +14,36 @@ Abstract:
 
 #![no_std]
 
+mod drbg_kat;
 mod ecc384_kat;
+mod ecc384_pct_kat;
 mod hmac384_kat;
+mod hmac384_kdf_kat;
+mod kat_report;
 mod kats_env;
 mod lms_kat;
 mod sha1_kat;
 mod sha256_kat;
 mod sha384_kat;
 mod sha384acc_kat;
+mod trng_health_kat;
 
 pub use caliptra_drivers::{CaliptraError, CaliptraResult};
+pub use drbg_kat::DrbgKat;
 pub use ecc384_kat::Ecc384Kat;
+pub use ecc384_pct_kat::Ecc384PctKat;
 pub use hmac384_kat::Hmac384Kat;
+pub use hmac384_kdf_kat::Hmac384KdfKat;
+pub use kat_report::{KatId, KatReport, KatResult, KatSet, KatStatus, KAT_COUNT};
 pub use kats_env::KatsEnv;
 pub use lms_kat::LmsKat;
 pub use sha1_kat::Sha1Kat;
 pub use sha256_kat::Sha256Kat;
 pub use sha384_kat::Sha384Kat;
 pub use sha384acc_kat::Sha384AccKat;
+pub use trng_health_kat::TrngHealthKat;
 
-use caliptra_drivers::cprintln;
+use caliptra_drivers::{boot_timeline::cycle_count, cprintln, Trng};
 
 /// Execute Known Answer Tests
 ///
@@ -41,30 +51,135 @@ use caliptra_drivers::cprintln;
 ///
 /// * `env` - ROM Environment
 pub fn execute_kat(env: &mut KatsEnv) -> CaliptraResult<()> {
-    cprintln!("[kat] ++");
-
-    cprintln!("[kat] sha1");
-    Sha1Kat::default().execute(env.sha1)?;
-
-    cprintln!("[kat] SHA2-256");
-    Sha256Kat::default().execute(env.sha256)?;
-
-    cprintln!("[kat] SHA2-384");
-    Sha384Kat::default().execute(env.sha384)?;
+    execute_kats(env, KatSet::all(), &mut KatReport::default())
+}
 
-    cprintln!("[kat] SHA2-384-ACC");
-    Sha384AccKat::default().execute(env.sha384_acc, env.sha_acc_lock_state)?;
+/// Execute the selected Known Answer Tests
+///
+/// Every selected test runs even if an earlier one fails, so that `report`
+/// holds the outcome of each of them.
+///
+/// # Arguments
+///
+/// * `env` - ROM Environment
+/// * `kats` - Tests to execute
+/// * `report` - Per-test results
+///
+/// # Returns
+///
+/// * `CaliptraResult` - Error of the first failing test
+pub fn execute_kats(env: &mut KatsEnv, kats: KatSet, report: &mut KatReport) -> CaliptraResult<()> {
+    cprintln!("[kat] ++");
 
-    cprintln!("[kat] ECC-384");
-    Ecc384Kat::default().execute(env.ecc384, env.trng)?;
+    let mut result = Ok(());
+
+    result = result.and(run_kat(kats, report, KatId::Sha1, "sha1", &mut || {
+        Sha1Kat::default().execute(env.sha1)
+    }));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Sha256,
+        "SHA2-256",
+        &mut || Sha256Kat::default().execute(env.sha256),
+    ));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Sha384,
+        "SHA2-384",
+        &mut || Sha384Kat::default().execute(env.sha384),
+    ));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Sha384Acc,
+        "SHA2-384-ACC",
+        &mut || Sha384AccKat::default().execute(env.sha384_acc, env.sha_acc_lock_state),
+    ));
+
+    result = result.and(run_kat(kats, report, KatId::Ecc384, "ECC-384", &mut || {
+        Ecc384Kat::default().execute(env.ecc384, env.trng)
+    }));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Ecc384Pct,
+        "ECC-384 PCT",
+        &mut || Ecc384PctKat::default().execute(env.ecc384, env.trng),
+    ));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Hmac384,
+        "HMAC-384",
+        &mut || Hmac384Kat::default().execute(env.hmac384, env.trng),
+    ));
+
+    result = result.and(run_kat(
+        kats,
+        report,
+        KatId::Hmac384Kdf,
+        "HMAC-384 KDF",
+        &mut || Hmac384KdfKat::default().execute(env.hmac384, env.trng),
+    ));
+
+    result = result.and(run_kat(kats, report, KatId::Lms, "LMS", &mut || {
+        LmsKat::default().execute(env.sha256, env.lms)
+    }));
+
+    match &mut *env.trng {
+        Trng::Internal(csrng) => {
+            result = result.and(run_kat(kats, report, KatId::Drbg, "DRBG", &mut || {
+                DrbgKat::default().execute(csrng)
+            }));
+        }
+        _ => skip_kat(kats, report, KatId::Drbg, "DRBG"),
+    }
+
+    if matches!(*env.trng, Trng::Internal(_) | Trng::External(_)) {
+        result = result.and(run_kat(
+            kats,
+            report,
+            KatId::TrngHealth,
+            "TRNG",
+            &mut || TrngHealthKat::default().execute(env.trng),
+        ));
+    } else {
+        skip_kat(kats, report, KatId::TrngHealth, "TRNG");
+    }
 
-    cprintln!("[kat] HMAC-384");
-    Hmac384Kat::default().execute(env.hmac384, env.trng)?;
+    cprintln!("[kat] --");
 
-    cprintln!("[kat] LMS");
-    LmsKat::default().execute(env.sha256, env.lms)?;
+    result
+}
 
-    cprintln!("[kat] --");
+fn run_kat(
+    kats: KatSet,
+    report: &mut KatReport,
+    id: KatId,
+    name: &str,
+    kat: &mut dyn FnMut() -> CaliptraResult<()>,
+) -> CaliptraResult<()> {
+    if !kats.contains(id.into()) {
+        return Ok(());
+    }
+
+    cprintln!("[kat] {}", name);
+    let start = cycle_count();
+    let result = kat();
+    report.record(id, &result, cycle_count().wrapping_sub(start));
+    result
+}
 
-    Ok(())
+fn skip_kat(kats: KatSet, report: &mut KatReport, id: KatId, name: &str) {
+    if kats.contains(id.into()) {
+        cprintln!("[kat] {} skipped", name);
+        report.record_skipped(id);
+    }
 }
@@ -0,0 +1,54 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    trng_health_kat.rs
+
+Abstract:
+
+    File contains the health test checks for the TRNG.
+
+--*/
+
+use caliptra_drivers::{CaliptraError, CaliptraResult, Trng};
+
+#[derive(Default, Debug)]
+pub struct TrngHealthKat {}
+
+impl TrngHealthKat {
+    /// This function checks the health of the TRNG.
+    ///
+    /// For the internal TRNG, the entropy source must not have raised any
+    /// repetition count or adaptive proportion test alerts. For every TRNG,
+    /// two consecutive outputs must differ (continuous random number test).
+    ///
+    /// # Arguments
+    ///
+    /// * `trng` - TRNG Driver
+    ///
+    /// # Returns
+    ///
+    /// * `CaliptraResult` - Result denoting the KAT outcome.
+    pub fn execute(&self, trng: &mut Trng) -> CaliptraResult<()> {
+        if let Trng::Internal(csrng) = trng {
+            if csrng.health_fail_counts().total != 0 {
+                Err(CaliptraError::ROM_KAT_TRNG_HEALTH_TEST_FAILURE)?;
+            }
+        }
+
+        let first = trng
+            .generate()
+            .map_err(|_| CaliptraError::ROM_KAT_TRNG_GENERATE_FAILURE)?;
+        let second = trng
+            .generate()
+            .map_err(|_| CaliptraError::ROM_KAT_TRNG_GENERATE_FAILURE)?;
+
+        if first == second {
+            Err(CaliptraError::ROM_KAT_TRNG_REPEATED_OUTPUT)?;
+        }
+
+        Ok(())
+    }
+}
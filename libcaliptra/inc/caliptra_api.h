@@ -114,6 +114,9 @@ int caliptra_get_ldev_cert(struct caliptra_get_ldev_cert_resp *resp, bool async)
 // ECDSA384 Verify
 int caliptra_ecdsa384_verify(struct caliptra_ecdsa_verify_req *req, bool async);
 
+// LMS Verify
+int caliptra_lms_verify(struct caliptra_lms_verify_req *req, bool async);
+
 // Stash measurement
 int caliptra_stash_measurement(struct caliptra_stash_measurement_req *req, struct caliptra_stash_measurement_resp *resp, bool async);
 
@@ -132,8 +135,11 @@ int caliptra_fips_version(struct caliptra_fips_version_resp *resp, bool async);
 // Self test start
 int caliptra_self_test_start(bool async);
 
+// Self test start, running only the KATs selected in req->kats
+int caliptra_self_test_start_kats(struct caliptra_self_test_start_req *req, bool async);
+
 // Self test get results
-int caliptra_self_test_get_results(struct caliptra_test_get_fmc_alias_cert_resp *resp, bool async);
+int caliptra_self_test_get_results(struct caliptra_self_test_get_results_resp *resp, bool async);
 
 // Shutdown
 int caliptra_shutdown(bool async);
@@ -141,4 +147,53 @@ int caliptra_shutdown(bool async);
 // Capabilities
 int caliptra_capabilities(struct caliptra_capabilities_resp *resp, bool async);
 
+// Get PCR quote
+int caliptra_get_pcr_quote(struct caliptra_get_pcr_quote_req *req, struct caliptra_get_pcr_quote_resp *resp, bool async);
+
+// Extend PCR
+int caliptra_extend_pcr(struct caliptra_extend_pcr_req *req, bool async);
+
+// Get a chunk of the PCR log entries starting at req->offset
+int caliptra_get_pcr_log(struct caliptra_get_pcr_log_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Get a chunk of the fuse log entries starting at req->offset
+int caliptra_get_fuse_log(struct caliptra_get_fuse_log_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Get a chunk of the measurement log entries starting at req->offset
+int caliptra_get_measurement_log(struct caliptra_get_measurement_log_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Get a chunk of the boot timeline entries starting at req->offset: the boot statuses
+// reported so far and the cycle count at which each was reported
+int caliptra_get_boot_timeline(struct caliptra_get_boot_timeline_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Get FMC alias cert
+int caliptra_get_fmc_alias_cert(struct caliptra_get_fmc_alias_cert_resp *resp, bool async);
+
+// Get RT alias cert
+int caliptra_get_rt_alias_cert(struct caliptra_get_rt_alias_cert_resp *resp, bool async);
+
+// Get a chunk of the cert chain starting at req->offset
+int caliptra_get_cert_chain(struct caliptra_get_cert_chain_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Get a chunk of the CEL-encoded event log starting at req->offset
+int caliptra_get_event_log(struct caliptra_get_event_log_req *req, struct caliptra_chunked_resp *resp, bool async);
+
+// Hash req->data with the SHA accelerator, optionally extending the digest into a SoC PCR
+int caliptra_sha_digest(struct caliptra_sha_digest_req *req, struct caliptra_sha_digest_resp *resp, bool async);
+
+// Start a streaming digest of req->data_len bytes sent with caliptra_sha_update
+int caliptra_sha_init(struct caliptra_sha_init_req *req, bool async);
+
+// Add req->data to the streaming digest
+int caliptra_sha_update(struct caliptra_sha_update_req *req, bool async);
+
+// Finish the streaming digest, optionally extending it into a SoC PCR
+int caliptra_sha_final(struct caliptra_sha_digest_resp *resp, bool async);
+
+// Set the signed SoC image authorization manifest
+int caliptra_set_auth_manifest(struct caliptra_set_auth_manifest_req *req, bool async);
+
+// Check a SoC image digest against the authorization manifest and stash it in DPE if authorized
+int caliptra_authorize_and_stash(struct caliptra_authorize_and_stash_req *req, struct caliptra_authorize_and_stash_resp *resp, bool async);
+
 
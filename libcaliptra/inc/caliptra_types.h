@@ -91,6 +91,15 @@ struct caliptra_ecdsa_verify_req {
     uint8_t signature_s[48];
 };
 
+struct caliptra_lms_verify_req {
+    struct caliptra_req_header hdr;
+    uint8_t pub_key[56];
+    uint32_t signature_size;
+    uint8_t signature[1620];
+    uint32_t message_size;
+    uint8_t message[256];
+};
+
 struct caliptra_hmac_verify_req {
     struct caliptra_req_header hdr;
     uint8_t key[48];
@@ -125,6 +134,30 @@ struct caliptra_fips_version_resp {
     uint8_t name[12];
 };
 
+// Bit N of kats selects the KAT with ID N; zero runs all of them
+struct caliptra_self_test_start_req {
+    struct caliptra_req_header hdr;
+    uint32_t kats;
+};
+
+#define CALIPTRA_SELF_TEST_STATUS_NOT_RUN 0
+#define CALIPTRA_SELF_TEST_STATUS_PASSED  1
+#define CALIPTRA_SELF_TEST_STATUS_FAILED  2
+#define CALIPTRA_SELF_TEST_STATUS_SKIPPED 3
+
+struct caliptra_self_test_result {
+    uint32_t kat_id;
+    uint32_t status;
+    uint32_t error;
+    uint32_t cycles;
+};
+
+struct caliptra_self_test_get_results_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    struct caliptra_self_test_result results[16];
+};
+
 struct caliptra_fw_info_resp {
     struct caliptra_resp_header hdr;
     uint32_t pl0_pauser;
@@ -134,11 +167,188 @@ struct caliptra_fw_info_resp {
     uint32_t attestation_disabled;
 };
 
+// Capabilities are a big-endian 128-bit value; bit N is set in
+// capabilities[15 - N / 8] & (1 << (N % 8))
+#define CALIPTRA_CAPABILITY_ROM_BASE              0
+#define CALIPTRA_CAPABILITY_FMC_BASE              32
+#define CALIPTRA_CAPABILITY_RT_BASE               64
+#define CALIPTRA_CAPABILITY_RT_DPE_P256_SHA256    65
+#define CALIPTRA_CAPABILITY_RT_DPE_P384_SHA384    66
+#define CALIPTRA_CAPABILITY_RT_FIPS_SELF_TEST     67
+#define CALIPTRA_CAPABILITY_RT_PCR_QUOTE          68
+#define CALIPTRA_CAPABILITY_RT_LMS_VERIFY         69
+#define CALIPTRA_CAPABILITY_RT_SHA_DIGEST         70
+#define CALIPTRA_CAPABILITY_RT_PAUSER_POLICY      71
+#define CALIPTRA_CAPABILITY_RT_TEST_ONLY_COMMANDS 72
+#define CALIPTRA_CAPABILITY_RT_AUTH_MANIFEST      73
+#define CALIPTRA_CAPABILITY_RT_EVENT_LOG          74
+#define CALIPTRA_CAPABILITY_RT_BOOT_TIMELINE      75
+#define CALIPTRA_CAPABILITY_RT_SHA_STREAM         76
+
 struct caliptra_capabilities_resp {
     struct caliptra_resp_header hdr;
     uint8_t capabilities[16];
 };
 
+struct caliptra_get_pcr_quote_req {
+    struct caliptra_req_header hdr;
+    uint8_t nonce[32];
+};
+
+struct caliptra_get_pcr_quote_resp {
+    struct caliptra_resp_header hdr;
+    uint8_t nonce[32];
+    uint8_t pcrs[32][48];
+    uint8_t signature_r[48];
+    uint8_t signature_s[48];
+};
+
+struct caliptra_extend_pcr_req {
+    struct caliptra_req_header hdr;
+    uint32_t pcr_idx;
+    uint8_t data[48];
+};
+
+struct caliptra_pcr_log_entry {
+    uint16_t id;
+    uint8_t reserved0[2];
+    uint32_t pcr_ids;
+    uint32_t pcr_data[12];
+};
+
+struct caliptra_fuse_log_entry {
+    uint32_t entry_id;
+    uint32_t log_data[1];
+    uint32_t reserved[2];
+};
+
+struct caliptra_measurement_log_entry {
+    struct caliptra_pcr_log_entry pcr_entry;
+    uint8_t metadata[4];
+    uint32_t context[12];
+    uint32_t svn;
+    uint8_t reserved0[4];
+};
+
+struct caliptra_get_pcr_log_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+struct caliptra_get_fuse_log_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+struct caliptra_get_measurement_log_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+struct caliptra_boot_timeline_entry {
+    uint32_t boot_status;
+    uint32_t cycle_count_low;
+    uint32_t cycle_count_high;
+};
+
+struct caliptra_get_boot_timeline_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+struct caliptra_get_fmc_alias_cert_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+struct caliptra_get_rt_alias_cert_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+struct caliptra_get_cert_chain_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+struct caliptra_get_event_log_req {
+    struct caliptra_req_header hdr;
+    uint32_t offset;
+};
+
+// One chunk of a response that spans several mailbox transactions. Re-issue
+// the request with offset += data_size until flags has no MORE bit set.
+#define CALIPTRA_CHUNKED_RESP_FLAG_MORE (1 << 0)
+
+struct caliptra_chunked_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t total_size;
+    uint32_t offset;
+    uint32_t flags;
+    uint32_t payload_chksum;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+#define CALIPTRA_SHA_DIGEST_SHA384     1
+#define CALIPTRA_SHA_DIGEST_SHA512     2
+#define CALIPTRA_SHA_DIGEST_EXTEND_PCR (1 << 0)
+
+struct caliptra_sha_digest_req {
+    struct caliptra_req_header hdr;
+    uint32_t hash_algorithm;
+    uint32_t flags;
+    uint32_t pcr_idx;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+struct caliptra_sha_digest_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t data_size;
+    uint8_t digest[64];
+};
+
+struct caliptra_sha_init_req {
+    struct caliptra_req_header hdr;
+    uint32_t hash_algorithm;
+    uint32_t flags;
+    uint32_t pcr_idx;
+    uint32_t data_len;
+};
+
+struct caliptra_sha_update_req {
+    struct caliptra_req_header hdr;
+    uint32_t data_size;
+    uint8_t data[1024];
+};
+
+#define CALIPTRA_AUTH_MANIFEST_SIZE 4340
+
+struct caliptra_set_auth_manifest_req {
+    struct caliptra_req_header hdr;
+    uint8_t manifest[CALIPTRA_AUTH_MANIFEST_SIZE];
+};
+
+#define CALIPTRA_AUTHORIZE_AND_STASH_SKIP_STASH (1 << 0)
+#define CALIPTRA_AUTHORIZE_AND_STASH_AUTHORIZED 0xDEADC0DE
+#define CALIPTRA_AUTHORIZE_AND_STASH_DENIED     0x21523F21
+
+struct caliptra_authorize_and_stash_req {
+    struct caliptra_req_header hdr;
+    uint8_t fw_id[4];
+    uint8_t measurement[48];
+    uint8_t context[48];
+    uint32_t flags;
+};
+
+struct caliptra_authorize_and_stash_resp {
+    struct caliptra_resp_header hdr;
+    uint32_t auth_req_result;
+};
+
 // The below fields are placeholders to set up the baseline
 // required for communication of DPE commands to Caliptra
 // firmware.
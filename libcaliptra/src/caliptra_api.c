@@ -810,6 +810,27 @@ int caliptra_ecdsa384_verify(struct caliptra_ecdsa_verify_req *req, bool async)
     return pack_and_execute_command(&p, async);
 }
 
+// LMS Verify
+int caliptra_lms_verify(struct caliptra_lms_verify_req *req, bool async)
+{
+    if (!req)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_LMS_VERIFY,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
 // Stash measurement
 int caliptra_stash_measurement(struct caliptra_stash_measurement_req *req, struct caliptra_stash_measurement_resp *resp, bool async)
 {
@@ -929,8 +950,29 @@ int caliptra_self_test_start(bool async)
     return pack_and_execute_command(&p, async);
 }
 
+// Self test start, running only the selected KATs
+int caliptra_self_test_start_kats(struct caliptra_self_test_start_req *req, bool async)
+{
+    if (!req)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_SELF_TEST_START,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
 // Self test get results
-int caliptra_self_test_get_results(struct caliptra_test_get_fmc_alias_cert_resp *resp, bool async)
+int caliptra_self_test_get_results(struct caliptra_self_test_get_results_resp *resp, bool async)
 {
     if (!resp)
     {
@@ -986,4 +1028,329 @@ int caliptra_capabilities(struct caliptra_capabilities_resp *resp, bool async)
     };
 
     return pack_and_execute_command(&p, async);
-}
\ No newline at end of file
+}
+
+// Get PCR quote
+int caliptra_get_pcr_quote(struct caliptra_get_pcr_quote_req *req, struct caliptra_get_pcr_quote_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_PCR_QUOTE,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Extend PCR
+int caliptra_extend_pcr(struct caliptra_extend_pcr_req *req, bool async)
+{
+    if (!req)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_EXTEND_PCR,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the PCR log entries
+int caliptra_get_pcr_log(struct caliptra_get_pcr_log_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_PCR_LOG,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the fuse log entries
+int caliptra_get_fuse_log(struct caliptra_get_fuse_log_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_FUSE_LOG,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the measurement log entries
+int caliptra_get_measurement_log(struct caliptra_get_measurement_log_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_MEASUREMENT_LOG,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the boot timeline entries
+int caliptra_get_boot_timeline(struct caliptra_get_boot_timeline_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_BOOT_TIMELINE,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get FMC alias cert
+int caliptra_get_fmc_alias_cert(struct caliptra_get_fmc_alias_cert_resp *resp, bool async)
+{
+    if (!resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    caliptra_checksum checksum = 0;
+
+    struct parcel p = {
+        .command   = OP_GET_FMC_ALIAS_CERT,
+        .tx_buffer = (uint8_t*)&checksum,
+        .tx_bytes  = sizeof(checksum),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get RT alias cert
+int caliptra_get_rt_alias_cert(struct caliptra_get_rt_alias_cert_resp *resp, bool async)
+{
+    if (!resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    caliptra_checksum checksum = 0;
+
+    struct parcel p = {
+        .command   = OP_GET_RT_ALIAS_CERT,
+        .tx_buffer = (uint8_t*)&checksum,
+        .tx_bytes  = sizeof(checksum),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the cert chain
+int caliptra_get_cert_chain(struct caliptra_get_cert_chain_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_CERT_CHAIN,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Get a chunk of the event log
+int caliptra_get_event_log(struct caliptra_get_event_log_req *req, struct caliptra_chunked_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_GET_EVENT_LOG,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// SHA digest
+int caliptra_sha_digest(struct caliptra_sha_digest_req *req, struct caliptra_sha_digest_resp *resp, bool async)
+{
+    if (!req || !resp || req->data_size > sizeof(req->data))
+    {
+        return INVALID_PARAMS;
+    }
+
+    // The data is hashed in place in the mailbox, so only the populated part
+    // of the data buffer may be sent.
+    uint32_t actual_bytes = sizeof(*req) - sizeof(req->data) + req->data_size;
+
+    struct parcel p = {
+        .command   = OP_SHA_DIGEST,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = actual_bytes,
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// SHA init
+int caliptra_sha_init(struct caliptra_sha_init_req *req, bool async)
+{
+    if (!req)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_SHA_INIT,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// SHA update
+int caliptra_sha_update(struct caliptra_sha_update_req *req, bool async)
+{
+    if (!req || req->data_size > sizeof(req->data))
+    {
+        return INVALID_PARAMS;
+    }
+
+    // Only the populated part of the data buffer is sent.
+    uint32_t actual_bytes = sizeof(*req) - sizeof(req->data) + req->data_size;
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_SHA_UPDATE,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = actual_bytes,
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// SHA final
+int caliptra_sha_final(struct caliptra_sha_digest_resp *resp, bool async)
+{
+    if (!resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    caliptra_checksum checksum = 0;
+
+    struct parcel p = {
+        .command   = OP_SHA_FINAL,
+        .tx_buffer = (uint8_t*)&checksum,
+        .tx_bytes  = sizeof(checksum),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Set auth manifest
+int caliptra_set_auth_manifest(struct caliptra_set_auth_manifest_req *req, bool async)
+{
+    if (!req)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct caliptra_resp_header resp_hdr = {};
+
+    struct parcel p = {
+        .command   = OP_SET_AUTH_MANIFEST,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)&resp_hdr,
+        .rx_bytes  = sizeof(resp_hdr),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
+
+// Authorize and stash
+int caliptra_authorize_and_stash(struct caliptra_authorize_and_stash_req *req, struct caliptra_authorize_and_stash_resp *resp, bool async)
+{
+    if (!req || !resp)
+    {
+        return INVALID_PARAMS;
+    }
+
+    struct parcel p = {
+        .command   = OP_AUTHORIZE_AND_STASH,
+        .tx_buffer = (uint8_t*)req,
+        .tx_bytes  = sizeof(*req),
+        .rx_buffer = (uint8_t*)resp,
+        .rx_bytes  = sizeof(*resp),
+    };
+
+    return pack_and_execute_command(&p, async);
+}
@@ -26,6 +26,7 @@ enum mailbox_command {
     OP_GET_IDEV_INFO             = 0x49444549, // "IDEI"
     OP_GET_LDEV_CERT             = 0x4C444556, // "LDEV"
     OP_ECDSA384_VERIFY           = 0x53494756, // "SIGV"
+    OP_LMS_VERIFY                = 0x4C4D5356, // "LMSV"
     OP_STASH_MEASUREMENT         = 0x4D454153, // "MEAS"
     OP_DISABLE_ATTESTATION       = 0x4453424C, // "DSBL"
     OP_INVOKE_DPE_COMMAND        = 0x44504543, // "DPEC"
@@ -35,6 +36,22 @@ enum mailbox_command {
     OP_SELF_TEST_GET_RESULTS     = 0x46504C67, // "FPGR"
     OP_SHUTDOWN                  = 0x46505344, // "FPSD"
     OP_CAPABILITIES              = 0x43415053, // "CAPS"
+    OP_GET_PCR_QUOTE             = 0x50435251, // "PCRQ"
+    OP_EXTEND_PCR                = 0x50435245, // "PCRE"
+    OP_GET_PCR_LOG               = 0x504C4F47, // "PLOG"
+    OP_GET_FUSE_LOG              = 0x464C4F47, // "FLOG"
+    OP_GET_MEASUREMENT_LOG       = 0x4D4C4F47, // "MLOG"
+    OP_GET_EVENT_LOG             = 0x454C4F47, // "ELOG"
+    OP_GET_BOOT_TIMELINE         = 0x42544C4E, // "BTLN"
+    OP_GET_FMC_ALIAS_CERT        = 0x464D4341, // "FMCA"
+    OP_GET_RT_ALIAS_CERT         = 0x52544143, // "RTAC"
+    OP_GET_CERT_CHAIN            = 0x4343484E, // "CCHN"
+    OP_SHA_DIGEST                = 0x53484144, // "SHAD"
+    OP_SHA_INIT                  = 0x53484149, // "SHAI"
+    OP_SHA_UPDATE                = 0x53484155, // "SHAU"
+    OP_SHA_FINAL                 = 0x53484146, // "SHAF"
+    OP_SET_AUTH_MANIFEST         = 0x41544D4E, // "ATMN"
+    OP_AUTHORIZE_AND_STASH       = 0x41545348, // "ATSH"
 };
 
 struct parcel {
@@ -963,6 +963,23 @@ impl<TMmio: ureg::Mmio> RegisterBlock<TMmio> {
             )
         }
     }
+    /// Number of additional firmware images ROM accepts after a
+    /// cold boot image fails verification
+    /// [br]Caliptra Access: RO
+    /// [br]SOC Access:      RWL-S
+    ///
+    /// Read value: [`u32`]; Write value: [`u32`]
+    #[inline(always)]
+    pub fn fuse_fw_recovery_retry_count(
+        &self,
+    ) -> ureg::RegRef<crate::soc_ifc::meta::FuseFwRecoveryRetryCount, &TMmio> {
+        unsafe {
+            ureg::RegRef::new_with_mmio(
+                self.ptr.wrapping_add(0x34c / core::mem::size_of::<u32>()),
+                core::borrow::Borrow::borrow(&self.mmio),
+            )
+        }
+    }
     /// Stored De-Obfuscation key, not accessible by software.
     /// [br]Caliptra Access: -
     /// [br]SOC Access:      -
@@ -4244,6 +4261,7 @@ pub mod meta {
         crate::soc_ifc::regs::FuseSocSteppingIdReadVal,
         crate::soc_ifc::regs::FuseSocSteppingIdWriteVal,
     >;
+    pub type FuseFwRecoveryRetryCount = ureg::ReadWriteReg32<0, u32, u32>;
     pub type InternalObfKey = ureg::WriteOnlyReg32<0, u32>;
     pub type InternalIccmLock = ureg::ReadWriteReg32<
         0,
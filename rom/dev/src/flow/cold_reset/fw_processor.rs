@@ -22,8 +22,8 @@ use caliptra_cfi_lib::CfiCounter;
 use caliptra_common::capabilities::Capabilities;
 use caliptra_common::fips::FipsVersionCmd;
 use caliptra_common::mailbox_api::{
-    CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader, Response,
-    StashMeasurementReq, StashMeasurementResp,
+    CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader, Response, ResponseVarSize,
+    SelfTestGetResultsResp, StashMeasurementReq, StashMeasurementResp,
 };
 use caliptra_common::pcr::PCR_ID_STASH_MEASUREMENT;
 use caliptra_common::verifier::FirmwareImageVerificationEnv;
@@ -34,7 +34,7 @@ use caliptra_drivers::pcr_log::MeasurementLogEntry;
 use caliptra_drivers::*;
 use caliptra_image_types::{ImageManifest, IMAGE_BYTE_SIZE};
 use caliptra_image_verify::{ImageVerificationInfo, ImageVerificationLogInfo, ImageVerifier};
-use caliptra_kat::KatsEnv;
+use caliptra_kat::{KatReport, KatsEnv};
 use caliptra_x509::{NotAfter, NotBefore};
 use core::mem::ManuallyDrop;
 use zerocopy::{AsBytes, LayoutVerified};
@@ -55,46 +55,94 @@ pub struct FirmwareProcessor {}
 
 impl FirmwareProcessor {
     pub fn process(env: &mut RomEnv) -> CaliptraResult<FwProcInfo> {
-        let mut kats_env = caliptra_kat::KatsEnv {
-            // SHA1 Engine
-            sha1: &mut env.sha1,
-
-            // sha256
-            sha256: &mut env.sha256,
-
-            // SHA2-384 Engine
-            sha384: &mut env.sha384,
-
-            // SHA2-384 Accelerator
-            sha384_acc: &mut env.sha384_acc,
-
-            // Hmac384 Engine
-            hmac384: &mut env.hmac384,
-
-            /// Cryptographically Secure Random Number Generator
-            trng: &mut env.trng,
-
-            // LMS Engine
-            lms: &mut env.lms,
+        // Reset the PCR log size to zero. Rejected images are recorded in the
+        // log ahead of the measurements of the accepted image.
+        env.persistent_data.get_mut().fht.pcr_log_index = 0;
+
+        let retry_count = env.soc_ifc.fuse_bank().fw_recovery_retry_count();
+        let mut attempt = 0;
+        let (mut txn, manifest, info) = loop {
+            let mut kats_env = caliptra_kat::KatsEnv {
+                // SHA1 Engine
+                sha1: &mut env.sha1,
+
+                // sha256
+                sha256: &mut env.sha256,
+
+                // SHA2-384 Engine
+                sha384: &mut env.sha384,
+
+                // SHA2-384 Accelerator
+                sha384_acc: &mut env.sha384_acc,
+
+                // Hmac384 Engine
+                hmac384: &mut env.hmac384,
+
+                /// Cryptographically Secure Random Number Generator
+                trng: &mut env.trng,
+
+                // LMS Engine
+                lms: &mut env.lms,
+
+                /// Ecc384 Engine
+                ecc384: &mut env.ecc384,
+
+                /// SHA Acc lock state
+                sha_acc_lock_state: ShaAccLockState::NotAcquired,
+            };
+            // Process mailbox commands.
+            let mut txn = Self::process_mailbox_commands(
+                &mut env.soc_ifc,
+                &mut env.mbox,
+                &mut env.pcr_bank,
+                &mut kats_env,
+                env.persistent_data.get_mut(),
+            )?;
 
-            /// Ecc384 Engine
-            ecc384: &mut env.ecc384,
+            // Load the manifest
+            let manifest = Self::load_manifest(&mut env.persistent_data, &mut txn);
+
+            // Verify the image
+            let info = okref(&manifest).and_then(|manifest| {
+                let mut venv = FirmwareImageVerificationEnv {
+                    sha256: &mut env.sha256,
+                    sha384: &mut env.sha384,
+                    soc_ifc: &mut env.soc_ifc,
+                    ecc384: &mut env.ecc384,
+                    data_vault: &mut env.data_vault,
+                    pcr_bank: &mut env.pcr_bank,
+                    image: txn.raw_mailbox_contents(),
+                };
+                Self::verify_image(&mut venv, manifest, txn.dlen())
+            });
+
+            let err = match info {
+                Ok(info) => break (txn, manifest?, info),
+                Err(err) => err,
+            };
+            if attempt >= retry_count {
+                return Err(err);
+            }
 
-            /// SHA Acc lock state
-            sha_acc_lock_state: ShaAccLockState::NotAcquired,
+            // Reject the image and wait for the SoC to send another one.
+            cprintln!(
+                "[fwproc] Image rejected with error 0x{:08x}, waiting for recovery image",
+                u32::from(err)
+            );
+            report_boot_status(FwProcessorRecoveryImageRequested.into());
+            report_fw_error_non_fatal(err.into());
+            pcr::extend_fw_load_failure(
+                env.persistent_data.get_mut(),
+                &mut env.pcr_bank,
+                &mut env.sha384,
+                attempt,
+                err,
+            )?;
+            txn.complete(false)?;
+            attempt += 1;
         };
-        // Process mailbox commands.
-        let mut txn = Self::process_mailbox_commands(
-            &mut env.soc_ifc,
-            &mut env.mbox,
-            &mut env.pcr_bank,
-            &mut kats_env,
-            env.persistent_data.get_mut(),
-        )?;
-
-        // Load the manifest
-        let manifest = Self::load_manifest(&mut env.persistent_data, &mut txn);
-        let manifest = okref(&manifest)?;
+        let manifest = &manifest;
+        let info = &info;
 
         let mut venv = FirmwareImageVerificationEnv {
             sha256: &mut env.sha256,
@@ -106,17 +154,13 @@ impl FirmwareProcessor {
             image: txn.raw_mailbox_contents(),
         };
 
-        // Verify the image
-        let info = Self::verify_image(&mut venv, manifest, txn.dlen());
-        let info = okref(&info)?;
-
         Self::update_fuse_log(&mut env.persistent_data.get_mut().fuse_log, &info.log_info)?;
 
         // Populate data vault
         Self::populate_data_vault(venv.data_vault, info, &env.persistent_data);
 
         // Extend PCR0 and PCR1
-        pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
+        pcr::extend_pcrs(&mut venv, manifest, info, &mut env.persistent_data)?;
         report_boot_status(FwProcessorExtendPcrComplete.into());
 
         // Load the image
@@ -169,6 +213,7 @@ impl FirmwareProcessor {
         persistent_data: &mut PersistentData,
     ) -> CaliptraResult<ManuallyDrop<MailboxRecvTxn<'a>>> {
         let mut self_test_in_progress = false;
+        let mut kat_report = KatReport::default();
 
         cprintln!("[fwproc] Waiting for Commands...");
         loop {
@@ -220,7 +265,7 @@ impl FirmwareProcessor {
                             // TODO: set non-fatal error register?
                             txn.complete(false)?;
                         } else {
-                            run_fips_tests(env)?;
+                            run_fips_tests(env, &mut kat_report)?;
                             let mut resp = MailboxRespHeader::default();
                             resp.populate_chksum();
                             txn.send_response(resp.as_bytes())?;
@@ -235,9 +280,13 @@ impl FirmwareProcessor {
                             // TODO: set non-fatal error register?
                             txn.complete(false)?;
                         } else {
-                            let mut resp = MailboxRespHeader::default();
+                            let mut resp = SelfTestGetResultsResp::default();
+                            for (dst, src) in resp.results.iter_mut().zip(kat_report.results) {
+                                dst.as_bytes_mut().copy_from_slice(src.as_bytes());
+                            }
+                            resp.data_size = kat_report.results.as_bytes().len() as u32;
                             resp.populate_chksum();
-                            txn.send_response(resp.as_bytes())?;
+                            txn.send_response(resp.as_bytes_partial()?)?;
                             self_test_in_progress = false;
                         }
                     }
@@ -483,6 +532,17 @@ impl FirmwareProcessor {
 
         txn.copy_request(runtime_dest.as_bytes_mut())?;
 
+        for entry in manifest.aux_entries() {
+            cprintln!(
+                "[fwproc] Loading aux image {} at address 0x{:08x} len {}",
+                entry.id,
+                entry.load_addr,
+                entry.size
+            );
+
+            aux_image::load(entry, txn.raw_mailbox_contents())?;
+        }
+
         report_boot_status(FwProcessorLoadImageComplete.into());
         Ok(())
     }
@@ -27,14 +27,11 @@ use caliptra_drivers::*;
 use caliptra_x509::*;
 use zeroize::Zeroize;
 
-type InitDevIdCsr<'a> = Certificate<'a, { MAX_CSR_SIZE }>;
+type InitDevIdCsr<'a> = Certificate<'a, { IdevIdCsr::MAX_CSR_SIZE }>;
 
 /// Initialization Vector used by Deobfuscation Engine during UDS / field entropy decryption.
 const DOE_IV: Array4x4 = Array4xN::<4, 16>([0xfb10365b, 0xa1179741, 0xfba193a1, 0x0f406d7e]);
 
-/// Maximum Certificate Signing Request Size
-const MAX_CSR_SIZE: usize = 512;
-
 /// Dice Initial Device Identity (IDEVID) Layer
 pub enum InitDevIdLayer {}
 
@@ -196,7 +193,11 @@ impl InitDevIdLayer {
         result
     }
 
-    /// Generate Local Device ID CSR
+    /// Generate Initial Device ID CSR
+    ///
+    /// The CSR is only generated when requested via the Manufacturing Service
+    /// Register. It is then uploaded to the SOC and retained in persistent data
+    /// so runtime firmware can return it via GET_IDEV_CSR.
     ///
     /// # Arguments
     ///
@@ -216,7 +217,37 @@ impl InitDevIdLayer {
         cprintln!("[idev] CSR upload requested");
 
         // Generate the CSR
-        Self::make_csr(env, output)
+        let mut csr = [0u8; IdevIdCsr::MAX_CSR_SIZE];
+        let result = Self::make_csr(env, output, &mut csr);
+        let result = result.and_then(|csr_len| {
+            Self::retain_csr(env, &csr, csr_len)?;
+
+            // Execute Send CSR Flow
+            Self::send_csr(env, InitDevIdCsr::new(&csr, csr_len))
+        });
+        csr.zeroize();
+
+        result
+    }
+
+    /// Copy the CSR into persistent data
+    ///
+    /// # Arguments
+    ///
+    /// * `env`     - ROM Environment
+    /// * `csr`     - CSR buffer
+    /// * `csr_len` - Length of the CSR in `csr`
+    fn retain_csr(env: &mut RomEnv, csr: &[u8], csr_len: usize) -> CaliptraResult<()> {
+        let idevid_csr = &mut env.persistent_data.get_mut().idevid_csr;
+        let Some(dst) = idevid_csr.csr.get_mut(..csr_len) else {
+            return Err(CaliptraError::ROM_IDEVID_CSR_OVERFLOW);
+        };
+        let Some(src) = csr.get(..csr_len) else {
+            return Err(CaliptraError::ROM_IDEVID_CSR_OVERFLOW);
+        };
+        dst.copy_from_slice(src);
+        idevid_csr.csr_len = csr_len as u32;
+        Ok(())
     }
 
     /// Create Initial Device ID CSR
@@ -225,7 +256,12 @@ impl InitDevIdLayer {
     ///
     /// * `env`    - ROM Environment
     /// * `output` - DICE Output
-    fn make_csr(env: &mut RomEnv, output: &DiceOutput) -> CaliptraResult<()> {
+    /// * `csr`    - Buffer to build the CSR in
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Length of the CSR
+    fn make_csr(env: &mut RomEnv, output: &DiceOutput, csr: &mut [u8]) -> CaliptraResult<usize> {
         let key_pair = &output.subj_key_pair;
 
         // CSR `To Be Signed` Parameters
@@ -264,14 +300,13 @@ impl InitDevIdLayer {
         cprintln!("[idev] SIG.S = {}", HexBytes(&_sig_s));
 
         // Build the CSR with `To Be Signed` & `Signature`
-        let mut csr = [0u8; MAX_CSR_SIZE];
         let result = Ecdsa384CsrBuilder::new(tbs.tbs(), &sig.to_ecdsa())
             .ok_or(CaliptraError::ROM_IDEVID_CSR_BUILDER_INIT_FAILURE);
         sig.zeroize();
 
         let csr_bldr = result?;
         let csr_len = csr_bldr
-            .build(&mut csr)
+            .build(csr)
             .ok_or(CaliptraError::ROM_IDEVID_CSR_BUILDER_BUILD_FAILURE)?;
 
         if csr_len > csr.len() {
@@ -281,11 +316,7 @@ impl InitDevIdLayer {
         cprintln!("[idev] CSR = {}", HexBytes(&csr[..csr_len]));
         report_boot_status(IDevIdMakeCsrComplete.into());
 
-        // Execute Send CSR Flow
-        let result = Self::send_csr(env, InitDevIdCsr::new(&csr, csr_len));
-        csr.zeroize();
-
-        result
+        Ok(csr_len)
     }
 
     /// Send Initial Device ID CSR to SOC
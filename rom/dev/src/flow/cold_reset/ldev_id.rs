@@ -89,6 +89,64 @@ impl LocalDevIdLayer {
         Ok(output)
     }
 
+    /// Perform derivations for the DICE layer from a test seed instead of the
+    /// IDEVID CDI and field entropy. The certificate is signed with its own key.
+    ///
+    /// # Arguments
+    ///
+    /// * `env`  - ROM Environment
+    /// * `seed` - Test seed
+    ///
+    /// # Returns
+    ///
+    /// * `DiceOutput` - key pair, subject identifier serial number, subject key identifier
+    #[cfg(feature = "fake-rom")]
+    pub fn derive_from_seed(env: &mut RomEnv, seed: u32) -> CaliptraResult<DiceOutput> {
+        cprintln!("[ldev] ++");
+        cprintln!("[ldev] SEED = {}", seed);
+
+        // CDI = HMAC384(seed, "fake_ldevid_cdi")
+        let mut key = Array4x12::default();
+        key.0[0] = seed;
+        env.hmac384.hmac(
+            &(&key).into(),
+            &b"fake_ldevid_cdi".into(),
+            &mut env.trng,
+            KeyWriteArgs::new(
+                KEY_ID_ROM_FMC_CDI,
+                KeyUsage::default()
+                    .set_hmac_key_en()
+                    .set_ecc_key_gen_seed_en(),
+            )
+            .into(),
+        )?;
+        report_boot_status(LDevIdCdiDerivationComplete.into());
+
+        let key_pair = Self::derive_key_pair(env, KEY_ID_ROM_FMC_CDI, KEY_ID_LDEVID_PRIV_KEY)?;
+        let subj_sn = X509::subj_sn(env, &key_pair.pub_key)?;
+        report_boot_status(LDevIdSubjIdSnGenerationComplete.into());
+
+        let subj_key_id = X509::subj_key_id(env, &key_pair.pub_key)?;
+        report_boot_status(LDevIdSubjKeyIdGenerationComplete.into());
+
+        let output = DiceOutput {
+            subj_key_pair: key_pair,
+            subj_sn,
+            subj_key_id,
+        };
+        let input = DiceInput {
+            auth_key_pair: &output.subj_key_pair,
+            auth_sn: &output.subj_sn,
+            auth_key_id: &output.subj_key_id,
+        };
+        Self::generate_cert_sig(env, &input, &output)?;
+
+        cprintln!("[ldev] --");
+        report_boot_status(LDevIdDerivationComplete.into());
+
+        Ok(output)
+    }
+
     /// Derive Composite Device Identity (CDI) from field entropy
     ///
     /// # Arguments
@@ -177,11 +235,14 @@ impl LocalDevIdLayer {
         let mut sig = Crypto::ecdsa384_sign_and_verify(env, auth_priv_key, auth_pub_key, tbs.tbs());
         let sig = okmutref(&mut sig)?;
 
-        // Clear the authority private key
-        env.key_vault.erase_key(auth_priv_key).map_err(|err| {
-            sig.zeroize();
-            err
-        })?;
+        // Clear the authority private key, unless the certificate is signed
+        // with the subject key
+        if auth_priv_key != output.subj_key_pair.priv_key {
+            env.key_vault.erase_key(auth_priv_key).map_err(|err| {
+                sig.zeroize();
+                err
+            })?;
+        }
 
         let _pub_x: [u8; 48] = (&pub_key.x).into();
         let _pub_y: [u8; 48] = (&pub_key.y).into();
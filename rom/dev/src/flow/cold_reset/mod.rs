@@ -20,6 +20,9 @@ mod idev_id;
 mod ldev_id;
 mod x509;
 
+#[cfg(feature = "fake-rom")]
+pub(crate) use fw_processor::FirmwareProcessor;
+
 use crate::fht;
 use crate::flow::cold_reset::dice::*;
 use crate::flow::cold_reset::fmc_alias::FmcAliasLayer;
@@ -94,6 +97,30 @@ impl ColdResetFlow {
     }
 }
 
+/// Execute the LDEVID and FMCALIAS layers of the Cold Reset Flow around the
+/// firmware download, deriving the LDEVID layer from a test seed.
+///
+/// Used by the fake ROM to give each seed a distinct device identity while
+/// skipping the IDEVID layer.
+///
+/// # Arguments
+///
+/// * `env`  - ROM Environment
+/// * `seed` - Test seed
+#[cfg(feature = "fake-rom")]
+#[inline(never)]
+pub(crate) fn derive_fake_dice_layers(env: &mut RomEnv, seed: u32) -> CaliptraResult<()> {
+    let mut ldevid_layer_output = LocalDevIdLayer::derive_from_seed(env, seed)?;
+    let fmc_layer_input = dice_input_from_output(&ldevid_layer_output);
+
+    let mut fw_proc_info = FirmwareProcessor::process(env)?;
+
+    let result = FmcAliasLayer::derive(env, &fmc_layer_input, &fw_proc_info);
+    ldevid_layer_output.zeroize();
+    fw_proc_info.zeroize();
+    result
+}
+
 /// Copies the TBS to DCCM
 ///
 /// # Arguments
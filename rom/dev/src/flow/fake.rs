@@ -15,11 +15,9 @@ Abstract:
 #[cfg(not(feature = "fake-rom"))]
 compile_error!("This file should NEVER be included except for the fake-rom feature");
 
-#[allow(dead_code)]
-#[path = "cold_reset/fw_processor.rs"]
-mod fw_processor;
-
 use crate::fht;
+use crate::flow::cold_reset;
+use crate::flow::cold_reset::FirmwareProcessor;
 use crate::flow::update_reset;
 use crate::flow::warm_reset;
 use crate::print::HexBytes;
@@ -38,7 +36,6 @@ use caliptra_error::CaliptraError;
 use caliptra_image_types::*;
 use caliptra_image_verify::ImageVerificationEnv;
 use core::ops::Range;
-use fw_processor::FirmwareProcessor;
 
 const FAKE_LDEV_TBS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/ldev_tbs.der"));
 const FAKE_LDEV_PUB_KEY: Ecc384PubKey = Ecc384PubKey {
@@ -147,14 +144,8 @@ impl FakeRomFlow {
                 // Zeroize the key vault in the fake ROM flow
                 unsafe { KeyVault::zeroize() };
 
-                env.soc_ifc.flow_status_set_ready_for_firmware();
-
                 fht::initialize_fht(env);
 
-                // SKIP Execute IDEVID layer
-                // LDEVID cert
-                copy_canned_ldev_cert(env)?;
-
                 // Unlock the SHA Acc by creating a SHA Acc operation and dropping it.
                 // In real ROM, this is done as part of executing the SHA-ACC KAT.
                 let sha_op = env
@@ -163,11 +154,24 @@ impl FakeRomFlow {
                     .unwrap();
                 drop(sha_op);
 
-                // Download and validate firmware.
-                _ = FirmwareProcessor::process(env)?;
+                if env.soc_ifc.derive_identity_in_fake_mode() {
+                    // Derive the LDEVID and FMC Alias identities from the test
+                    // seed.
+                    let seed = env.soc_ifc.fake_rom_identity_seed();
+                    cold_reset::derive_fake_dice_layers(env, seed)?;
+                } else {
+                    env.soc_ifc.flow_status_set_ready_for_firmware();
+
+                    // SKIP Execute IDEVID layer
+                    // LDEVID cert
+                    copy_canned_ldev_cert(env)?;
 
-                // FMC Alias Cert
-                copy_canned_fmc_alias_cert(env)?;
+                    // Download and validate firmware.
+                    _ = FirmwareProcessor::process(env)?;
+
+                    // FMC Alias Cert
+                    copy_canned_fmc_alias_cert(env)?;
+                }
 
                 cprintln!("[fake-rom-cold-reset] --");
                 report_boot_status(ColdResetComplete.into());
@@ -354,6 +358,10 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         caliptra_common::memory_layout::ICCM_RANGE
     }
 
+    fn aux_image_range(&self) -> Range<u32> {
+        caliptra_common::memory_layout::AUX_IMAGE_RANGE
+    }
+
     fn lms_verify_enabled(&self) -> bool {
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
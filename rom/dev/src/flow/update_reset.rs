@@ -19,7 +19,7 @@ use caliptra_common::verifier::FirmwareImageVerificationEnv;
 use caliptra_cfi_derive::cfi_impl_fn;
 use caliptra_common::mailbox_api::CommandId;
 use caliptra_common::RomBootStatus::*;
-use caliptra_drivers::report_fw_error_non_fatal;
+use caliptra_drivers::{aux_image, report_fw_error_non_fatal};
 use caliptra_drivers::{
     okref, report_boot_status, MailboxRecvTxn, ResetReason, WarmResetEntry4, WarmResetEntry48,
 };
@@ -53,9 +53,16 @@ impl UpdateResetFlow {
 
         let Some(mut recv_txn) = env.mbox.try_start_recv_txn() else {
             cprintln!("Failed To Get Mailbox Transaction");
-            return Err(CaliptraError::ROM_UPDATE_RESET_FLOW_MAILBOX_ACCESS_FAILURE);
+            report_fw_error_non_fatal(
+                CaliptraError::ROM_UPDATE_RESET_FLOW_MAILBOX_ACCESS_FAILURE.into(),
+            );
+            return Self::rollback(env);
         };
 
+        // Set once the data vault, PCRs or ICCM have been modified for the new
+        // image. Until then the previous runtime can still be resumed.
+        let mut committed = false;
+
         let mut process_txn = || -> CaliptraResult<()> {
             if recv_txn.cmd() != CommandId::FIRMWARE_LOAD.into() {
                 cprintln!("Invalid command 0x{:08x} received", recv_txn.cmd());
@@ -78,12 +85,16 @@ impl UpdateResetFlow {
             let info = Self::verify_image(&mut venv, &manifest, recv_txn.dlen());
             let info = okref(&info)?;
             report_boot_status(UpdateResetImageVerificationComplete.into());
+            committed = true;
 
             // Populate data vault
             Self::populate_data_vault(venv.data_vault, info);
 
+            // Reset the PCR log size to zero.
+            env.persistent_data.get_mut().fht.pcr_log_index = 0;
+
             // Extend PCR0 and PCR1
-            pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
+            pcr::extend_pcrs(&mut venv, &manifest, info, &mut env.persistent_data)?;
             report_boot_status(UpdateResetExtendPcrComplete.into());
 
             cprintln!(
@@ -99,7 +110,11 @@ impl UpdateResetFlow {
             // transaction fail and reads the non-fatal error register before it
             // gets populated, report the non-fatal error code now.
             report_fw_error_non_fatal(e.into());
-            return Err(e);
+            if committed {
+                return Err(e);
+            }
+            drop(recv_txn);
+            return Self::rollback(env);
         }
 
         // Drop the transaction and release the Mailbox lock after the image
@@ -127,6 +142,29 @@ impl UpdateResetFlow {
         Ok(())
     }
 
+    /// Resume the previous runtime after the new image was rejected
+    ///
+    /// The previous runtime is still loaded in ICCM and described by MAN_1,
+    /// and the data vault and PCRs have not been modified, so the FMC can
+    /// boot it again as if the update had not been requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - ROM Environment
+    fn rollback(env: &mut RomEnv) -> CaliptraResult<()> {
+        cprintln!("[update-reset] Image rejected, resuming previous runtime");
+
+        env.data_vault.write_lock_warm_reset_entry4(
+            WarmResetEntry4::RomUpdateResetStatus,
+            UpdateResetRollbackComplete.into(),
+        );
+
+        cprintln!("[update-reset Rollback] --");
+        report_boot_status(UpdateResetRollbackComplete.into());
+
+        Ok(())
+    }
+
     /// Verify the image
     ///
     /// # Arguments
@@ -182,6 +220,17 @@ impl UpdateResetFlow {
 
         txn.copy_request(runtime_dest.as_bytes_mut())?;
 
+        for entry in manifest.aux_entries() {
+            cprintln!(
+                "[update-reset] Loading aux image {} at address 0x{:08x} len {}",
+                entry.id,
+                entry.load_addr,
+                entry.size
+            );
+
+            aux_image::load(entry, txn.raw_mailbox_contents())?;
+        }
+
         //Call the complete here to reset the execute bit
         txn.complete(true)?;
 
@@ -16,15 +16,17 @@ use caliptra_drivers::{report_boot_status, CaliptraResult};
 
 use crate::KatsEnv;
 use caliptra_common::RomBootStatus::{KatComplete, KatStarted};
+use caliptra_kat::{KatReport, KatSet};
 
 /// Execute Known Answer Tests
 ///
 /// # Arguments
 ///
 /// * `env` - ROM Environment
-pub fn execute_kat(kats_env: &mut KatsEnv) -> CaliptraResult<()> {
+/// * `report` - Per-KAT results
+pub fn execute_kat(kats_env: &mut KatsEnv, report: &mut KatReport) -> CaliptraResult<()> {
     report_boot_status(KatStarted.into());
-    caliptra_kat::execute_kat(kats_env)?;
+    caliptra_kat::execute_kats(kats_env, KatSet::all(), report)?;
     report_boot_status(KatComplete.into());
     Ok(())
 }
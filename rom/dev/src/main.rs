@@ -28,7 +28,7 @@ use caliptra_drivers::{
 };
 use caliptra_error::CaliptraResult;
 use caliptra_image_types::RomInfo;
-use caliptra_kat::KatsEnv;
+use caliptra_kat::{KatReport, KatsEnv};
 use rom_env::RomEnv;
 
 #[cfg(not(feature = "std"))]
@@ -149,7 +149,7 @@ pub extern "C" fn rom_entry() -> ! {
                 ShaAccLockState::AssumedLocked
             },
         };
-        let result = run_fips_tests(&mut kats_env);
+        let result = run_fips_tests(&mut kats_env, &mut KatReport::default());
         if let Err(err) = result {
             handle_fatal_error(err.into());
         }
@@ -157,15 +157,11 @@ pub extern "C" fn rom_entry() -> ! {
 
     if let Err(err) = flow::run(&mut env) {
         //
-        // For the update reset case, when we fail the image validation
-        // we will need to continue to jump to the FMC after
-        // reporting the error in the registers.
+        // The update reset flow rolls back to the previous runtime itself
+        // when the new image is rejected. Errors it returns happen after the
+        // new image was committed, so the previous runtime can't be resumed.
         //
-        if reset_reason == ResetReason::UpdateReset {
-            handle_non_fatal_error(err.into());
-        } else {
-            handle_fatal_error(err.into());
-        }
+        handle_fatal_error(err.into());
     }
 
     // Lock the datavault registers.
@@ -183,10 +179,10 @@ pub extern "C" fn rom_entry() -> ! {
     caliptra_drivers::ExitCtrl::exit(0);
 }
 
-fn run_fips_tests(env: &mut KatsEnv) -> CaliptraResult<()> {
+fn run_fips_tests(env: &mut KatsEnv, report: &mut KatReport) -> CaliptraResult<()> {
     let rom_info = unsafe { &CALIPTRA_ROM_INFO };
     rom_integrity_test(env, &rom_info.sha256_digest)?;
-    kat::execute_kat(env)
+    kat::execute_kat(env, report)
 }
 
 fn rom_integrity_test(env: &mut KatsEnv, expected_digest: &[u32; 8]) -> CaliptraResult<()> {
@@ -302,11 +298,6 @@ fn rom_panic(_: &core::panic::PanicInfo) -> ! {
     handle_fatal_error(CaliptraError::ROM_GLOBAL_PANIC.into());
 }
 
-fn handle_non_fatal_error(code: u32) {
-    cprintln!("ROM Non-Fatal Error: 0x{:08X}", code);
-    report_fw_error_non_fatal(code);
-}
-
 #[no_mangle]
 extern "C" fn cfi_panic_handler(code: u32) -> ! {
     cprintln!("[ROM] CFI Panic code=0x{:08X}", code);
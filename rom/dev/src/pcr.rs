@@ -28,8 +28,10 @@ use caliptra_common::{
     PcrLogEntry, PcrLogEntryId,
 };
 use caliptra_drivers::{
-    CaliptraError, CaliptraResult, PcrBank, PersistentData, PersistentDataAccessor, Sha384,
+    Array4x12, CaliptraError, CaliptraResult, PcrBank, PersistentData, PersistentDataAccessor,
+    Sha384,
 };
+use caliptra_image_types::ImageManifest;
 use caliptra_image_verify::ImageVerificationInfo;
 
 use zerocopy::AsBytes;
@@ -57,17 +59,18 @@ impl PcrExtender<'_> {
 ///
 /// # Arguments
 ///
-/// * `env` - ROM Environment
+/// * `env`      - ROM Environment
+/// * `manifest` - Manifest of the verified image
+/// * `info`     - Image verification info
+/// * `persistent_data` - Persistent data
 #[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
 #[inline(never)]
 pub(crate) fn extend_pcrs(
     env: &mut FirmwareImageVerificationEnv,
+    manifest: &ImageManifest,
     info: &ImageVerificationInfo,
     persistent_data: &mut PersistentDataAccessor,
 ) -> CaliptraResult<()> {
-    // Reset the PCR log size to zero.
-    persistent_data.get_mut().fht.pcr_log_index = 0;
-
     // Clear the Current PCR, but do not clear the Journey PCR
     env.pcr_bank.erase_pcr(PCR_ID_FMC_CURRENT)?;
 
@@ -104,9 +107,51 @@ pub(crate) fn extend_pcrs(
         PcrLogEntryId::FmcTci,
     )?;
 
+    // Measure the auxiliary images in TOC order.
+    for entry in manifest.aux_entries() {
+        pcr.extend(
+            &<[u8; 48]>::from(&Array4x12::from(entry.digest)),
+            PcrLogEntryId::AuxImage,
+        )?;
+    }
+
     Ok(())
 }
 
+/// Extend the Journey PCR with a firmware image that was rejected during
+/// cold boot
+///
+/// # Arguments
+///
+/// * `persistent_data` - Persistent data
+/// * `pcr_bank` - PCR bank
+/// * `sha384` - SHA384
+/// * `attempt` - Number of the rejected attempt, starting from zero
+/// * `err` - Error the image was rejected with
+#[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
+#[inline(never)]
+pub(crate) fn extend_fw_load_failure(
+    persistent_data: &mut PersistentData,
+    pcr_bank: &mut PcrBank,
+    sha384: &mut Sha384,
+    attempt: u32,
+    err: CaliptraError,
+) -> CaliptraResult<()> {
+    let mut data = [0u8; 8];
+    data[..4].copy_from_slice(attempt.as_bytes());
+    data[4..].copy_from_slice(u32::from(err).as_bytes());
+
+    pcr_bank.extend_pcr(PCR_ID_FMC_JOURNEY, sha384, &data)?;
+
+    let pcr_ids: u32 = 1 << PCR_ID_FMC_JOURNEY as u8;
+    log_pcr(
+        persistent_data,
+        PcrLogEntryId::FwLoadFailure,
+        pcr_ids,
+        &data,
+    )
+}
+
 /// Log PCR data
 ///
 /// # Arguments
@@ -9,6 +9,7 @@ mod test_cpu_fault;
 mod test_dice_derivations;
 mod test_fake_rom;
 mod test_fmcalias_derivation;
+mod test_fw_recovery;
 mod test_idevid_derivation;
 mod test_image_validation;
 mod test_mailbox_errors;
@@ -42,4 +42,5 @@ fn test_capabilities() {
     // Verify Capabilities
     let caps = Capabilities::try_from(capabilities_resp.capabilities.as_bytes()).unwrap();
     assert!(caps.contains(Capabilities::ROM_BASE));
+    assert_eq!(hw.capabilities().unwrap(), caps);
 }
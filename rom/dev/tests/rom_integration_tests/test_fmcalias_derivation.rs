@@ -19,9 +19,9 @@ use caliptra_drivers::{ColdResetEntry4, PcrId, RomVerifyConfig};
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{BootParams, Fuses, HwModel, InitParams, ModelError, SecurityState};
 use caliptra_image_fake_keys::{OWNER_CONFIG, VENDOR_CONFIG_KEY_1};
-use caliptra_image_gen::ImageGenerator;
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorAuxImage};
 use caliptra_image_openssl::OsslCrypto;
-use caliptra_image_types::IMAGE_BYTE_SIZE;
+use caliptra_image_types::{ImageTocEntryId, ImageTocEntryType, IMAGE_BYTE_SIZE};
 use caliptra_test::swap_word_bytes;
 use openssl::hash::{Hasher, MessageDigest};
 use zerocopy::{AsBytes, FromBytes};
@@ -562,6 +562,73 @@ fn test_pcr_log_across_update_reset() {
     assert_eq!(pcrs_after_clear, pcrs_after_reset);
 }
 
+#[test]
+fn test_pcr_log_aux_images() {
+    let aux_image = |id, load_addr, content| ImageGeneratorAuxImage {
+        id,
+        r#type: ImageTocEntryType::Data,
+        version: 1,
+        svn: 0,
+        min_svn: 0,
+        rev: Default::default(),
+        load_addr,
+        content,
+    };
+    let image_options = ImageOptions {
+        aux_images: vec![
+            aux_image(ImageTocEntryId::DpeConfig, AUX_IMAGE_ORG, vec![0xa5; 256]),
+            aux_image(
+                ImageTocEntryId::SocPolicy,
+                AUX_IMAGE_ORG + 256,
+                vec![0x5a; 128],
+            ),
+        ],
+        ..Default::default()
+    };
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &TEST_FMC_INTERACTIVE,
+        &APP_WITH_UART,
+        image_options,
+    )
+    .unwrap();
+
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    let mut hw = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap();
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+
+    let pcr_entry_arr = hw.mailbox_execute(0x1000_0000, &[]).unwrap().unwrap();
+
+    // The auxiliary images are measured after the FMC, in TOC order.
+    for (i, entry) in image_bundle.manifest.aux_entries().iter().enumerate() {
+        check_pcr_log_entry(
+            &pcr_entry_arr,
+            4 + i,
+            PcrLogEntryId::AuxImage,
+            PCR0_AND_PCR1_EXTENDED_ID,
+            swap_word_bytes(&entry.digest).as_bytes(),
+        );
+    }
+
+    let pcrs = hw.mailbox_execute(0x1000_0006, &[]).unwrap().unwrap();
+    let mut pcr0_from_hw: [u8; 48] = pcrs[0..48].try_into().unwrap();
+    helpers::change_dword_endianess(&mut pcr0_from_hw);
+    assert_eq!(
+        hash_pcr_log_entries(&[0; 48], &pcr_entry_arr, PcrId::PcrId0),
+        pcr0_from_hw
+    );
+}
+
 #[test]
 fn test_fuse_log() {
     const FMC_SVN: u32 = 4;
@@ -596,6 +663,7 @@ fn test_fuse_log() {
         app_svn: FMC_SVN,
         app_min_svn: FMC_MIN_SVN,
         app_version: 0,
+        aux_images: Vec::new(),
     };
     let image_bundle =
         caliptra_builder::build_and_sign_image(&TEST_FMC_WITH_UART, &APP_WITH_UART, image_options)
@@ -0,0 +1,108 @@
+// Licensed under the Apache-2.0 license
+
+use caliptra_builder::{
+    firmware::{rom_tests::TEST_FMC_INTERACTIVE, APP_WITH_UART},
+    ImageOptions,
+};
+use caliptra_common::RomBootStatus::*;
+use caliptra_common::{PcrLogEntry, PcrLogEntryId};
+use caliptra_drivers::PcrId;
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, Fuses, HwModel, ModelError};
+use zerocopy::{AsBytes, FromBytes};
+
+use crate::helpers;
+
+const PCR_ENTRY_SIZE: usize = core::mem::size_of::<PcrLogEntry>();
+
+fn build_hw_model_with_retries(retry_count: u32) -> DefaultHwModel {
+    helpers::build_hw_model(Fuses {
+        fw_recovery_retry_count: retry_count,
+        ..Default::default()
+    })
+}
+
+fn build_images() -> (Vec<u8>, Vec<u8>) {
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &TEST_FMC_INTERACTIVE,
+        &APP_WITH_UART,
+        ImageOptions::default(),
+    )
+    .unwrap();
+    let image = image_bundle.to_bytes().unwrap();
+
+    // Corrupt the last byte of the runtime
+    let mut bad_image = image.clone();
+    *bad_image.last_mut().unwrap() ^= 1;
+
+    (image, bad_image)
+}
+
+#[test]
+fn test_fw_recovery_accepts_fallback_image() {
+    let mut hw = build_hw_model_with_retries(1);
+    let (image, bad_image) = build_images();
+    let err = CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH;
+
+    assert_eq!(
+        hw.upload_firmware(&bad_image),
+        Err(ModelError::MailboxCmdFailed(u32::from(err)))
+    );
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorRecoveryImageRequested)
+    );
+    assert_eq!(hw.soc_ifc().cptra_fw_error_fatal().read(), 0);
+    assert_eq!(
+        hw.soc_ifc().cptra_fw_error_non_fatal().read(),
+        u32::from(err)
+    );
+
+    hw.upload_firmware(&image).unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+    assert_eq!(hw.soc_ifc().cptra_fw_error_non_fatal().read(), 0);
+
+    // The rejected image is logged ahead of the measurements of the
+    // accepted image.
+    let pcr_entry_arr = hw.mailbox_execute(0x1000_0000, &[]).unwrap().unwrap();
+    let entry = PcrLogEntry::read_from_prefix(pcr_entry_arr.as_bytes()).unwrap();
+    assert_eq!(entry.id, PcrLogEntryId::FwLoadFailure as u16);
+    assert_eq!(entry.pcr_ids, 1 << PcrId::PcrId1 as u8);
+    assert_eq!(entry.measured_data(), [0, u32::from(err)].as_bytes());
+
+    let entry = PcrLogEntry::read_from_prefix(pcr_entry_arr[PCR_ENTRY_SIZE..].as_bytes()).unwrap();
+    assert_eq!(entry.id, PcrLogEntryId::DeviceStatus as u16);
+}
+
+#[test]
+fn test_fw_recovery_retries_exhausted() {
+    let mut hw = build_hw_model_with_retries(1);
+    let (_, bad_image) = build_images();
+    let err = CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH;
+
+    assert_eq!(
+        hw.upload_firmware(&bad_image),
+        Err(ModelError::MailboxCmdFailed(u32::from(err)))
+    );
+    assert_eq!(hw.soc_ifc().cptra_fw_error_fatal().read(), 0);
+
+    // The second rejected image is fatal
+    assert_eq!(
+        hw.upload_firmware(&bad_image),
+        Err(ModelError::MailboxCmdFailed(u32::from(err)))
+    );
+    assert_eq!(hw.soc_ifc().cptra_fw_error_fatal().read(), u32::from(err));
+}
+
+#[test]
+fn test_fw_recovery_disabled() {
+    let mut hw = build_hw_model_with_retries(0);
+    let (_, bad_image) = build_images();
+    let err = CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH;
+
+    assert_eq!(
+        hw.upload_firmware(&bad_image),
+        Err(ModelError::MailboxCmdFailed(u32::from(err)))
+    );
+    assert_eq!(hw.soc_ifc().cptra_fw_error_fatal().read(), u32::from(err));
+}
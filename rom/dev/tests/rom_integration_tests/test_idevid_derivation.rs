@@ -108,7 +108,7 @@ fn test_generate_csr_stress() {
 
         let ldev_cert = verify_key(
             &mut hw,
-            u32::from(CommandId::TEST_ONLY_GET_LDEV_CERT),
+            u32::from(CommandId::GET_LDEV_CERT),
             &idevid_pubkey,
             &fuses.uds_seed,
         );
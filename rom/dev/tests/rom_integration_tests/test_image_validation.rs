@@ -4,7 +4,7 @@ use caliptra_builder::{
     firmware::{self, rom_tests::TEST_FMC_WITH_UART, APP_WITH_UART, FMC_WITH_UART},
     ImageOptions,
 };
-use caliptra_common::memory_layout::{ICCM_ORG, ICCM_SIZE};
+use caliptra_common::memory_layout::{AUX_IMAGE_ORG, ICCM_ORG, ICCM_SIZE};
 use caliptra_common::RomBootStatus::*;
 use caliptra_drivers::MfgFlags;
 use caliptra_drivers::{Array4x12, IdevidCertAttr};
@@ -15,11 +15,17 @@ use caliptra_hw_model::{
 use caliptra_image_elf::ElfExecutable;
 use caliptra_image_fake_keys::{
     VENDOR_CONFIG_KEY_0, VENDOR_CONFIG_KEY_1, VENDOR_CONFIG_KEY_2, VENDOR_CONFIG_KEY_3,
+    VENDOR_ECC_KEY_1_PRIVATE, VENDOR_ECC_KEY_1_PUBLIC, VENDOR_LMS_KEY_1_PRIVATE,
+    VENDOR_LMS_KEY_1_PUBLIC,
+};
+use caliptra_image_gen::{
+    ImageGenerator, ImageGeneratorAuxImage, ImageGeneratorConfig, ImageGeneratorOwnerConfig,
+    ImageGeneratorVendorConfig,
 };
-use caliptra_image_gen::{ImageGenerator, ImageGeneratorConfig, ImageGeneratorVendorConfig};
 use caliptra_image_openssl::OsslCrypto;
 use caliptra_image_types::{
-    ImageBundle, ImageManifest, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT,
+    ImageBundle, ImageManifest, ImageOwnerPrivKeys, ImageOwnerPubKeys, ImageTocEntryId,
+    ImageTocEntryType, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT,
 };
 use openssl::asn1::Asn1Integer;
 use openssl::asn1::Asn1Time;
@@ -172,6 +178,51 @@ fn test_preamble_owner_pubkey_digest_mismatch() {
     );
 }
 
+#[test]
+fn test_preamble_owner_resign() {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let mut image_bundle = helpers::build_image_bundle(ImageOptions::default());
+    let vendor_sigs = image_bundle.manifest.preamble.vendor_sigs;
+
+    // Any valid key pair will do for the new owner.
+    let owner_config = ImageGeneratorOwnerConfig {
+        pub_keys: ImageOwnerPubKeys {
+            ecc_pub_key: VENDOR_ECC_KEY_1_PUBLIC,
+            lms_pub_key: VENDOR_LMS_KEY_1_PUBLIC,
+        },
+        priv_keys: Some(ImageOwnerPrivKeys {
+            ecc_priv_key: VENDOR_ECC_KEY_1_PRIVATE,
+            lms_priv_key: VENDOR_LMS_KEY_1_PRIVATE,
+        }),
+        not_before: *b"20250101000000Z",
+        not_after: *b"20351231235959Z",
+    };
+    image_bundle.manifest = gen
+        .resign_owner(&image_bundle.manifest, &owner_config)
+        .unwrap();
+
+    assert_eq!(
+        image_bundle.manifest.preamble.vendor_sigs.as_bytes(),
+        vendor_sigs.as_bytes()
+    );
+
+    let fuses = caliptra_hw_model::Fuses {
+        key_manifest_pk_hash: gen
+            .vendor_pubkey_digest(&image_bundle.manifest.preamble)
+            .unwrap(),
+        owner_pk_hash: gen
+            .owner_pubkey_digest(&image_bundle.manifest.preamble)
+            .unwrap(),
+        lms_verify: true,
+        ..Default::default()
+    };
+    let mut hw = helpers::build_hw_model(fuses);
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
 #[test]
 fn test_preamble_vendor_ecc_pubkey_revocation() {
     let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
@@ -1342,6 +1393,68 @@ fn test_fmc_digest_mismatch() {
     );
 }
 
+fn aux_image_options() -> ImageOptions {
+    ImageOptions {
+        aux_images: vec![
+            ImageGeneratorAuxImage {
+                id: ImageTocEntryId::DpeConfig,
+                r#type: ImageTocEntryType::Data,
+                version: 1,
+                svn: 0,
+                min_svn: 0,
+                rev: Default::default(),
+                load_addr: AUX_IMAGE_ORG,
+                content: vec![0xa5; 256],
+            },
+            ImageGeneratorAuxImage {
+                id: ImageTocEntryId::SocPolicy,
+                r#type: ImageTocEntryType::Data,
+                version: 1,
+                svn: 0,
+                min_svn: 0,
+                rev: Default::default(),
+                load_addr: AUX_IMAGE_ORG + 256,
+                content: vec![0x5a; 128],
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_aux_images() {
+    let (mut hw, image_bundle) =
+        helpers::build_hw_model_and_image_bundle(Fuses::default(), aux_image_options());
+    assert_eq!(
+        image_bundle.manifest.header.toc_len,
+        caliptra_image_types::MIN_TOC_ENTRY_COUNT + 2
+    );
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+#[test]
+fn test_aux_digest_mismatch() {
+    let (mut hw, mut image_bundle) =
+        helpers::build_hw_model_and_image_bundle(Fuses::default(), aux_image_options());
+
+    // Change the SoC policy image.
+    image_bundle.aux[1][0..4].copy_from_slice(0xDEADBEEFu32.as_bytes());
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(CaliptraError::IMAGE_VERIFIER_ERR_AUX_DIGEST_MISMATCH.into()),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
 #[test]
 fn test_fmc_invalid_load_addr_before_iccm() {
     let (mut hw, mut image_bundle) =
@@ -2057,6 +2170,7 @@ fn update_header(image_bundle: &mut ImageBundle) {
         runtime: ElfExecutable::default(),
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        aux: opts.aux_images,
     };
 
     let gen = ImageGenerator::new(OsslCrypto::default());
@@ -2094,7 +2208,11 @@ fn update_fmc_runtime_ranges(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            image_bundle.manifest.aux_entries(),
+        )
         .unwrap();
 
     // Update Header.
@@ -2115,7 +2233,11 @@ fn update_load_addr(image_bundle: &mut ImageBundle, is_fmc: bool, new_load_addr:
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            image_bundle.manifest.aux_entries(),
+        )
         .unwrap();
 
     // Update Header.
@@ -2140,7 +2262,11 @@ fn update_entry_point(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            image_bundle.manifest.aux_entries(),
+        )
         .unwrap();
 
     // Update Header.
@@ -2155,6 +2281,9 @@ fn generate_image_bytes(image_bundle: &mut ImageBundle) -> Vec<u8> {
     image.extend_from_slice(image_bundle.manifest.as_bytes());
     image.extend_from_slice(&image_bundle.fmc);
     image.extend_from_slice(&image_bundle.runtime);
+    for aux in &image_bundle.aux {
+        image.extend_from_slice(aux);
+    }
     image
 }
 
@@ -107,7 +107,7 @@ fn test_update_reset_no_mailbox_cmd() {
 
     assert_eq!(
         hw.soc_ifc().cptra_boot_status().read(),
-        u32::from(UpdateResetStarted)
+        u32::from(UpdateResetRollbackComplete)
     );
 }
 
@@ -150,7 +150,7 @@ fn test_update_reset_non_fw_load_cmd() {
 
     assert_eq!(
         hw.soc_ifc().cptra_boot_status().read(),
-        u32::from(UpdateResetStarted)
+        u32::from(UpdateResetRollbackComplete)
     );
 }
 
@@ -199,7 +199,7 @@ fn test_update_reset_verify_image_failure() {
 
     assert_eq!(
         hw.soc_ifc().cptra_boot_status().read(),
-        u32::from(UpdateResetLoadManifestComplete)
+        u32::from(UpdateResetRollbackComplete)
     );
 }
 
@@ -314,6 +314,13 @@ fn test_update_reset_vendor_ecc_pub_key_idx_dv_mismatch() {
         ))
     );
 
+    // ROM rolled back to the previously loaded firmware
+    hw.step_until_boot_status(UpdateResetRollbackComplete.into(), true);
+    assert_eq!(
+        read_rom_update_reset_status(&mut hw),
+        u32::from(UpdateResetRollbackComplete)
+    );
+
     // Exit test-fmc with success
     hw.mailbox_execute(0x1000_000C, &[]).unwrap();
 
@@ -432,6 +439,15 @@ fn test_check_rom_update_reset_status_reg() {
 
     hw.step_until_boot_status(UpdateResetComplete.into(), true);
 
+    assert_eq!(
+        read_rom_update_reset_status(&mut hw),
+        u32::from(UpdateResetComplete)
+    );
+}
+
+/// Reads the RomUpdateResetStatus data vault entry through the interactive
+/// test FMC.
+fn read_rom_update_reset_status(hw: &mut impl HwModel) -> u32 {
     let warmresetentry4_array = hw.mailbox_execute(0x1000_000D, &[]).unwrap().unwrap();
     let mut warmresetentry4_offset = core::mem::size_of::<u32>() * 8; // Skip first four entries
 
@@ -443,7 +459,5 @@ fn test_check_rom_update_reset_status_reg() {
         WarmResetEntry4::RomUpdateResetStatus as u32
     );
     warmresetentry4_offset += core::mem::size_of::<u32>();
-    let warmresetentry4_value =
-        u32::read_from_prefix(warmresetentry4_array[warmresetentry4_offset..].as_bytes()).unwrap();
-    assert_eq!(warmresetentry4_value, u32::from(UpdateResetComplete));
+    u32::read_from_prefix(warmresetentry4_array[warmresetentry4_offset..].as_bytes()).unwrap()
 }
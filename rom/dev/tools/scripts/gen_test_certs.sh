@@ -4,14 +4,22 @@
 
 set -e
 
+# Keys are never overwritten, as the LMS keys are only usable with the leaf
+# index state created along with them.
+for key in "$1"/vnd-*.pem "$1"/own-*.pem "$1"/*.pem.state; do
+  if [ -e "$key" ]; then
+    echo "$key already exists" >&2
+    exit 1
+  fi
+done
+
 cargo --config="target.'cfg(all())'.rustflags = [\"-Dwarnings\"]" test -p caliptra-image-fake-keys test_write_lms_keys -- --ignored >/dev/null
 
 for value in {0..3}
 do
-  openssl ecparam -name secp384r1 -genkey -noout -out $1/vnd-priv-key-$value.pem
-  openssl ec -in  $1/vnd-priv-key-$value.pem -pubout -out $1/vnd-pub-key-$value.pem
+  openssl ecparam -name secp384r1 -genkey -noout -out "$1/vnd-priv-key-$value.pem"
+  openssl ec -in "$1/vnd-priv-key-$value.pem" -pubout -out "$1/vnd-pub-key-$value.pem"
 done
 
-openssl ecparam -name secp384r1 -genkey -noout -out $1/own-priv-key.pem
-openssl ec -in  $1/own-priv-key.pem -pubout -out $1/own-pub-key.pem
-
+openssl ecparam -name secp384r1 -genkey -noout -out "$1/own-priv-key.pem"
+openssl ec -in "$1/own-priv-key.pem" -pubout -out "$1/own-pub-key.pem"
@@ -0,0 +1,194 @@
+// Licensed under the Apache-2.0 license
+
+use crate::{Drivers, StashMeasurementCmd};
+use caliptra_common::{
+    mailbox_api::{
+        AuthorizeAndStashReq, AuthorizeAndStashResp, CommandId, MailboxReqHeader, MailboxResp,
+        MailboxRespHeader, SetAuthManifestReq,
+    },
+    verifier::FirmwareImageVerificationEnv,
+};
+use caliptra_drivers::{Array4xN, CaliptraError, CaliptraResult, HashValue};
+use caliptra_image_types::{
+    AuthManifest, ImageDigest, ImageEccPubKey, ImageLmsPublicKey, ImageSignatures,
+    AUTH_MANIFEST_BYTE_SIZE, AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT, AUTH_MANIFEST_MARKER,
+};
+use caliptra_image_verify::ImageVerificationEnv;
+use core::mem::size_of;
+use dpe::response::DpeErrorCode;
+use zerocopy::{FromBytes, LayoutVerified};
+
+const _: () = assert!(SetAuthManifestReq::MANIFEST_SIZE == AUTH_MANIFEST_BYTE_SIZE);
+
+pub struct SetAuthManifestCmd;
+impl SetAuthManifestCmd {
+    /// Verify the authorization manifest in the mailbox and, if both the
+    /// vendor and owner signatures are valid, replace the stored image
+    /// metadata with its contents.
+    ///
+    /// The manifest is larger than the runtime's request buffer, so it is
+    /// verified in place in the mailbox SRAM.
+    pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
+        let dlen = drivers.mbox.dlen() as usize;
+        if dlen != size_of::<SetAuthManifestReq>() {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+        let req_bytes = drivers
+            .mbox
+            .raw_mailbox_contents()
+            .get(..dlen)
+            .ok_or(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)?;
+
+        // Verify incoming checksum
+        let hdr_size = size_of::<MailboxReqHeader>();
+        let hdr = MailboxReqHeader::read_from(&req_bytes[..hdr_size])
+            .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+        if !caliptra_common::checksum::verify_checksum(
+            hdr.chksum,
+            CommandId::SET_AUTH_MANIFEST.into(),
+            &req_bytes[size_of::<u32>()..],
+        ) {
+            return Err(CaliptraError::RUNTIME_INVALID_CHECKSUM);
+        }
+
+        let manifest_bytes = &req_bytes[hdr_size..];
+        let manifest = LayoutVerified::<&[u8], AuthManifest>::new(manifest_bytes)
+            .ok_or(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)?
+            .into_ref();
+        if manifest.preamble.marker != AUTH_MANIFEST_MARKER
+            || manifest.preamble.size as usize != AUTH_MANIFEST_BYTE_SIZE
+            || manifest.image_metadata.entry_count as usize > AUTH_MANIFEST_IMAGE_METADATA_MAX_COUNT
+        {
+            return Err(CaliptraError::RUNTIME_AUTH_MANIFEST_INVALID);
+        }
+        let entries = manifest.image_metadata.valid_entries();
+        for (i, entry) in entries.iter().enumerate() {
+            if entries.iter().skip(i + 1).any(|e| e.fw_id == entry.fw_id) {
+                return Err(CaliptraError::RUNTIME_AUTH_MANIFEST_DUPLICATE_FW_ID);
+            }
+        }
+
+        // The manifest is signed with the keys that authorized the running
+        // firmware image.
+        let preamble = &drivers.persistent_data.get().manifest1.preamble;
+        let vendor_ecc_pub_key = preamble
+            .vendor_pub_keys
+            .ecc_pub_keys
+            .get(drivers.data_vault.ecc_vendor_pk_index() as usize)
+            .ok_or(CaliptraError::RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID)?;
+        let vendor_lms_pub_key = preamble
+            .vendor_pub_keys
+            .lms_pub_keys
+            .get(drivers.data_vault.lms_vendor_pk_index() as usize)
+            .ok_or(CaliptraError::RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID)?;
+
+        let mut venv = FirmwareImageVerificationEnv {
+            sha256: &mut drivers.sha256,
+            sha384: &mut drivers.sha384,
+            soc_ifc: &mut drivers.soc_ifc,
+            ecc384: &mut drivers.ecc384,
+            data_vault: &mut drivers.data_vault,
+            pcr_bank: &mut drivers.pcr_bank,
+            image: manifest_bytes,
+        };
+        let mut env = &mut venv;
+
+        let range = AuthManifest::image_metadata_range();
+        let digest = env.sha384_digest(range.start, range.len() as u32)?;
+
+        Self::verify_signatures(
+            &mut env,
+            &digest,
+            vendor_ecc_pub_key,
+            vendor_lms_pub_key,
+            &manifest.preamble.vendor_sigs,
+        )
+        .map_err(|_| CaliptraError::RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID)?;
+        Self::verify_signatures(
+            &mut env,
+            &digest,
+            &preamble.owner_pub_keys.ecc_pub_key,
+            &preamble.owner_pub_keys.lms_pub_key,
+            &manifest.preamble.owner_sigs,
+        )
+        .map_err(|_| CaliptraError::RUNTIME_AUTH_MANIFEST_OWNER_SIGNATURE_INVALID)?;
+
+        // Kept in persistent data so that images stay authorized across
+        // update resets.
+        drivers
+            .persistent_data
+            .get_mut()
+            .auth_manifest_image_metadata = manifest.image_metadata;
+
+        Ok(MailboxResp::Header(MailboxRespHeader::default()))
+    }
+
+    /// Verify the ECC signature, and the LMS signature when LMS verification
+    /// is enabled in the fuses, over `digest`.
+    fn verify_signatures(
+        env: &mut impl ImageVerificationEnv,
+        digest: &ImageDigest,
+        ecc_pub_key: &ImageEccPubKey,
+        lms_pub_key: &ImageLmsPublicKey,
+        sigs: &ImageSignatures,
+    ) -> CaliptraResult<()> {
+        let verify_r = env.ecc384_verify(digest, ecc_pub_key, &sigs.ecc_sig)?;
+        if verify_r != Array4xN(sigs.ecc_sig.r) {
+            return Err(CaliptraError::RUNTIME_INTERNAL);
+        }
+
+        if env.lms_verify_enabled() {
+            let candidate_key = env.lms_verify(digest, lms_pub_key, &sigs.lms_sig)?;
+            if candidate_key != HashValue::from(lms_pub_key.digest) {
+                return Err(CaliptraError::RUNTIME_INTERNAL);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct AuthorizeAndStashCmd;
+impl AuthorizeAndStashCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        if let Some(cmd) = AuthorizeAndStashReq::read_from(cmd_args) {
+            // Images are only authorized if their digest is listed in the
+            // manifest for their firmware ID. The SVN comes from the signed
+            // manifest entry rather than from the caller.
+            let Some(svn) = drivers
+                .persistent_data
+                .get()
+                .auth_manifest_image_metadata
+                .valid_entries()
+                .iter()
+                .find(|entry| entry.fw_id == cmd.fw_id && entry.digest == cmd.measurement)
+                .map(|entry| entry.svn)
+            else {
+                return Ok(MailboxResp::AuthorizeAndStash(AuthorizeAndStashResp {
+                    hdr: MailboxRespHeader::default(),
+                    auth_req_result: AuthorizeAndStashResp::DENIED,
+                }));
+            };
+
+            if cmd.flags & AuthorizeAndStashReq::FLAG_SKIP_STASH == 0 {
+                let dpe_result = StashMeasurementCmd::stash_measurement(
+                    drivers,
+                    &cmd.fw_id,
+                    &cmd.measurement,
+                    &cmd.context,
+                    svn,
+                )?;
+                if !matches!(dpe_result, DpeErrorCode::NoError) {
+                    return Err(CaliptraError::RUNTIME_AUTH_AND_STASH_MEASUREMENT_DPE_ERROR);
+                }
+            }
+
+            Ok(MailboxResp::AuthorizeAndStash(AuthorizeAndStashResp {
+                hdr: MailboxRespHeader::default(),
+                auth_req_result: AuthorizeAndStashResp::AUTHORIZED,
+            }))
+        } else {
+            Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)
+        }
+    }
+}
@@ -1,22 +1,41 @@
 // Licensed under the Apache-2.0 license
 
 #[cfg(feature = "test_only_commands")]
+use caliptra_common::mailbox_api::TestGetFmcAliasCertResp;
 use caliptra_common::mailbox_api::{
-    GetLdevCertResp, MailboxResp, MailboxRespHeader, TestGetFmcAliasCertResp,
+    ChunkedResp, GetCertChainReq, GetCertChainResp, GetFmcAliasCertResp, GetIdevCsrResp,
+    GetLdevCertResp, GetRtAliasCertResp, MailboxResp,
 };
 
-#[cfg(feature = "test_only_commands")]
-use crate::Drivers;
+use crate::{Drivers, MAX_CERT_CHAIN_SIZE};
 
 use caliptra_drivers::{
     hand_off::DataStore, CaliptraError, CaliptraResult, DataVault, Ecc384Scalar, Ecc384Signature,
     PersistentData,
 };
 use caliptra_x509::{Ecdsa384CertBuilder, Ecdsa384Signature};
+use zerocopy::FromBytes;
+
+pub struct GetIdevCsrCmd;
+impl GetIdevCsrCmd {
+    pub(crate) fn execute(drivers: &Drivers) -> CaliptraResult<MailboxResp> {
+        let Some(csr) = drivers.persistent_data.get().idevid_csr.get() else {
+            return Err(CaliptraError::RUNTIME_IDEV_CSR_NOT_AVAILABLE);
+        };
+
+        let mut resp = GetIdevCsrResp::default();
+        let Some(data) = resp.data.get_mut(..csr.len()) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+        data.copy_from_slice(csr);
+        resp.data_size = csr.len() as u32;
+
+        Ok(MailboxResp::GetIdevCsr(resp))
+    }
+}
 
 pub struct GetLdevCertCmd;
 impl GetLdevCertCmd {
-    #[cfg(feature = "test_only_commands")]
     pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
         let mut resp = GetLdevCertResp::default();
 
@@ -30,6 +49,52 @@ impl GetLdevCertCmd {
     }
 }
 
+pub struct GetFmcAliasCertCmd;
+impl GetFmcAliasCertCmd {
+    pub(crate) fn execute(drivers: &Drivers) -> CaliptraResult<MailboxResp> {
+        let mut resp = GetFmcAliasCertResp::default();
+
+        resp.data_size = copy_fmc_alias_cert(
+            &drivers.data_vault,
+            drivers.persistent_data.get(),
+            &mut resp.data,
+        )? as u32;
+
+        Ok(MailboxResp::GetFmcAliasCert(resp))
+    }
+}
+
+pub struct GetRtAliasCertCmd;
+impl GetRtAliasCertCmd {
+    pub(crate) fn execute(drivers: &Drivers) -> CaliptraResult<MailboxResp> {
+        let mut resp = GetRtAliasCertResp::default();
+
+        resp.data_size = copy_rt_alias_cert(drivers.persistent_data.get(), &mut resp.data)? as u32;
+
+        Ok(MailboxResp::GetRtAliasCert(resp))
+    }
+}
+
+// Hosts reassemble the chunks into a `GetCertChainResp`.
+const _: () = assert!(GetCertChainResp::DATA_MAX_SIZE >= MAX_CERT_CHAIN_SIZE);
+
+pub struct GetCertChainCmd;
+impl GetCertChainCmd {
+    /// Returns the chunk of the cert chain (LDevID, FMC alias, and RT alias
+    /// certs, preceded by the IDevID cert if one was populated) that starts
+    /// at the requested offset.
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetCertChainReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        Ok(MailboxResp::Chunked(ChunkedResp::from_payload(
+            &drivers.cert_chain,
+            cmd.offset,
+        )?))
+    }
+}
+
 pub struct TestGetFmcAliasCertCmd;
 impl TestGetFmcAliasCertCmd {
     #[cfg(feature = "test_only_commands")]
@@ -0,0 +1,276 @@
+// Licensed under the Apache-2.0 license
+
+use crate::{Drivers, ExtendPcrCmd, PauserPolicy};
+use caliptra_common::mailbox_api::{
+    MailboxResp, ShaDigestReq, ShaDigestResp, ShaInitReq, ShaUpdateReq,
+};
+use caliptra_common::permissions::CommandPermissions;
+use caliptra_drivers::{
+    Array4x12, Array4x16, CaliptraError, CaliptraResult, PcrId, Sha384AccOp, ShaAccLockState,
+};
+use core::mem::size_of;
+use zerocopy::{AsBytes, FromBytes};
+
+/// Offset of `ShaDigestReq::data` in the mailbox SRAM.
+const DATA_OFFSET: usize = size_of::<ShaDigestReq>() - ShaDigestReq::DATA_MAX_SIZE;
+
+/// Offset of `ShaUpdateReq::data` in the request.
+const UPDATE_DATA_OFFSET: usize = size_of::<ShaUpdateReq>() - ShaUpdateReq::DATA_MAX_SIZE;
+
+pub struct ShaDigestCmd;
+impl ShaDigestCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        if cmd_args.len() > size_of::<ShaDigestReq>() {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        }
+        let mut cmd = ShaDigestReq::default();
+        cmd.as_bytes_mut()[..cmd_args.len()].copy_from_slice(cmd_args);
+
+        // The data is hashed in place in the mailbox, so it must be exactly
+        // what the SoC sent.
+        if DATA_OFFSET + cmd.data_size as usize != cmd_args.len() {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+
+        let pcr_id = check_params(drivers, cmd.hash_algorithm, cmd.flags, cmd.pcr_idx)?;
+
+        let mut resp = ShaDigestResp::default();
+        match cmd.hash_algorithm {
+            ShaDigestReq::HASH_ALGORITHM_SHA384 => {
+                let mut digest = Array4x12::default();
+                sha_acc_op(drivers, ShaAccLockState::NotAcquired)?.digest(
+                    cmd.data_size,
+                    DATA_OFFSET as u32,
+                    false,
+                    &mut digest,
+                )?;
+                finish_sha384(drivers, digest, pcr_id, &mut resp)?;
+            }
+            ShaDigestReq::HASH_ALGORITHM_SHA512 => {
+                let mut digest = Array4x16::default();
+                sha_acc_op(drivers, ShaAccLockState::NotAcquired)?.digest_512(
+                    cmd.data_size,
+                    DATA_OFFSET as u32,
+                    false,
+                    &mut digest,
+                )?;
+                finish_sha512(digest, &mut resp);
+            }
+            _ => return Err(CaliptraError::RUNTIME_SHA_DIGEST_INVALID_ALGORITHM),
+        }
+
+        Ok(MailboxResp::ShaDigest(resp))
+    }
+}
+
+/// A streaming digest started by SHA_INIT. The runtime holds the SHA
+/// accelerator lock from SHA_INIT until SHA_FINAL.
+pub struct ShaStream {
+    /// PAUSER that started the stream. Only it can update, finalize or
+    /// restart the stream.
+    pauser: u32,
+
+    hash_algorithm: u32,
+    pcr_id: Option<PcrId>,
+
+    /// Total length of the data announced by SHA_INIT
+    data_len: u32,
+
+    /// Length of the data received so far
+    received: u32,
+}
+
+impl ShaStream {
+    /// Abandon the streaming digest in progress, if any, and release the SHA
+    /// accelerator.
+    pub(crate) fn abort(drivers: &mut Drivers) -> CaliptraResult<()> {
+        if drivers.sha_stream.take().is_some() {
+            // Dropping the operation releases the lock.
+            drop(sha_acc_op(drivers, ShaAccLockState::AssumedLocked)?);
+        }
+        Ok(())
+    }
+
+    /// Check that the PAUSER holding the mailbox lock owns the streaming
+    /// digest in progress, if any.
+    fn check_pauser(drivers: &Drivers) -> CaliptraResult<()> {
+        match &drivers.sha_stream {
+            Some(stream) if stream.pauser != drivers.mbox.user() => {
+                Err(CaliptraError::RUNTIME_SHA_STREAM_PAUSER_MISMATCH)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub struct ShaInitCmd;
+impl ShaInitCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = ShaInitReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let pcr_id = check_params(drivers, cmd.hash_algorithm, cmd.flags, cmd.pcr_idx)?;
+
+        // A new SHA_INIT restarts the stream of the same PAUSER.
+        ShaStream::check_pauser(drivers)?;
+        ShaStream::abort(drivers)?;
+
+        let mut op = sha_acc_op(drivers, ShaAccLockState::NotAcquired)?;
+        op.stream_init(
+            cmd.data_len,
+            cmd.hash_algorithm == ShaDigestReq::HASH_ALGORITHM_SHA512,
+        );
+        op.retain_lock();
+
+        drivers.sha_stream = Some(ShaStream {
+            pauser: drivers.mbox.user(),
+            hash_algorithm: cmd.hash_algorithm,
+            pcr_id,
+            data_len: cmd.data_len,
+            received: 0,
+        });
+
+        Ok(MailboxResp::default())
+    }
+}
+
+pub struct ShaUpdateCmd;
+impl ShaUpdateCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        if cmd_args.len() > size_of::<ShaUpdateReq>() {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        }
+        let mut cmd = ShaUpdateReq::default();
+        cmd.as_bytes_mut()[..cmd_args.len()].copy_from_slice(cmd_args);
+
+        if UPDATE_DATA_OFFSET + cmd.data_size as usize != cmd_args.len() {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+        let data = cmd
+            .data
+            .get(..cmd.data_size as usize)
+            .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+        ShaStream::check_pauser(drivers)?;
+        let Some(stream) = drivers.sha_stream.as_mut() else {
+            return Err(CaliptraError::RUNTIME_SHA_STREAM_NOT_STARTED);
+        };
+        let received = stream
+            .received
+            .checked_add(cmd.data_size)
+            .filter(|received| *received <= stream.data_len)
+            .ok_or(CaliptraError::RUNTIME_SHA_STREAM_LENGTH_MISMATCH)?;
+
+        // The accelerator takes whole DWORDs, so only the last update may be
+        // unaligned.
+        if received != stream.data_len && cmd.data_size % 4 != 0 {
+            return Err(CaliptraError::RUNTIME_SHA_STREAM_UNALIGNED_UPDATE);
+        }
+        stream.received = received;
+
+        let mut op = sha_acc_op(drivers, ShaAccLockState::AssumedLocked)?;
+        op.stream_update(data);
+        op.retain_lock();
+
+        Ok(MailboxResp::default())
+    }
+}
+
+pub struct ShaFinalCmd;
+impl ShaFinalCmd {
+    pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
+        ShaStream::check_pauser(drivers)?;
+        let Some(stream) = drivers.sha_stream.take() else {
+            return Err(CaliptraError::RUNTIME_SHA_STREAM_NOT_STARTED);
+        };
+
+        // The lock is released when `op` is dropped, even if the stream is
+        // incomplete.
+        let mut op = sha_acc_op(drivers, ShaAccLockState::AssumedLocked)?;
+        if stream.received != stream.data_len {
+            return Err(CaliptraError::RUNTIME_SHA_STREAM_LENGTH_MISMATCH);
+        }
+
+        let mut resp = ShaDigestResp::default();
+        match stream.hash_algorithm {
+            ShaDigestReq::HASH_ALGORITHM_SHA384 => {
+                let mut digest = Array4x12::default();
+                op.stream_finalize(&mut digest)?;
+                drop(op);
+                finish_sha384(drivers, digest, stream.pcr_id, &mut resp)?;
+            }
+            ShaDigestReq::HASH_ALGORITHM_SHA512 => {
+                let mut digest = Array4x16::default();
+                op.stream_finalize_512(&mut digest)?;
+                finish_sha512(digest, &mut resp);
+            }
+            _ => return Err(CaliptraError::RUNTIME_SHA_DIGEST_INVALID_ALGORITHM),
+        }
+
+        Ok(MailboxResp::ShaDigest(resp))
+    }
+}
+
+/// Check the algorithm and flags of a SHA_DIGEST or SHA_INIT request and
+/// return the SoC PCR to extend, if requested.
+fn check_params(
+    drivers: &Drivers,
+    hash_algorithm: u32,
+    flags: u32,
+    pcr_idx: u32,
+) -> CaliptraResult<Option<PcrId>> {
+    if !matches!(
+        hash_algorithm,
+        ShaDigestReq::HASH_ALGORITHM_SHA384 | ShaDigestReq::HASH_ALGORITHM_SHA512
+    ) {
+        return Err(CaliptraError::RUNTIME_SHA_DIGEST_INVALID_ALGORITHM);
+    }
+
+    if flags & ShaDigestReq::FLAG_EXTEND_PCR == 0 {
+        return Ok(None);
+    }
+
+    // The extend is subject to the same policy as EXTEND_PCR.
+    PauserPolicy::check_permission(drivers, CommandPermissions::EXTEND_PCR)?;
+
+    // PCR extends (and the PCR log) are SHA-384 sized.
+    if hash_algorithm != ShaDigestReq::HASH_ALGORITHM_SHA384 {
+        return Err(CaliptraError::RUNTIME_SHA_DIGEST_EXTEND_REQUIRES_SHA384);
+    }
+    Ok(Some(ExtendPcrCmd::soc_pcr_id(pcr_idx)?))
+}
+
+/// Copy a SHA384 digest to `resp`, extending it into `pcr_id` if set.
+fn finish_sha384(
+    drivers: &mut Drivers,
+    digest: Array4x12,
+    pcr_id: Option<PcrId>,
+    resp: &mut ShaDigestResp,
+) -> CaliptraResult<()> {
+    let digest: [u8; 48] = digest.into();
+    resp.digest[..digest.len()].copy_from_slice(&digest);
+    resp.data_size = digest.len() as u32;
+
+    if let Some(pcr_id) = pcr_id {
+        ExtendPcrCmd::extend(drivers, pcr_id, &digest)?;
+    }
+    Ok(())
+}
+
+/// Copy a SHA512 digest to `resp`.
+fn finish_sha512(digest: Array4x16, resp: &mut ShaDigestResp) {
+    let digest: [u8; 64] = digest.into();
+    resp.digest.copy_from_slice(&digest);
+    resp.data_size = digest.len() as u32;
+}
+
+/// Acquire the SHA accelerator, or resume it if the runtime already holds it
+/// for a streaming digest. Unless `retain_lock` is called, the lock is released
+/// when the returned operation is dropped.
+fn sha_acc_op(drivers: &mut Drivers, lock_state: ShaAccLockState) -> CaliptraResult<Sha384AccOp> {
+    drivers
+        .sha384_acc
+        .try_start_operation(lock_state)?
+        .ok_or(CaliptraError::RUNTIME_SHA_ACC_LOCK_UNAVAILABLE)
+}
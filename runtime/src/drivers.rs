@@ -4,10 +4,12 @@
 
 #[cfg(feature = "fips_self_test")]
 pub use crate::fips::{fips_self_test_cmd, fips_self_test_cmd::SelfTestStatus};
+#[cfg(feature = "fips_self_test")]
+use caliptra_kat::{KatReport, KatSet};
 
 use crate::{
-    dice, CptraDpeTypes, DisableAttestationCmd, DpeCrypto, DpePlatform, Mailbox, DPE_SUPPORT,
-    MAX_CERT_CHAIN_SIZE,
+    dice, CptraDpeTypes, DisableAttestationCmd, DpeCrypto, DpePlatform, Mailbox, ShaStream,
+    DPE_SUPPORT, MAX_CERT_CHAIN_SIZE,
 };
 
 use arrayvec::ArrayVec;
@@ -70,9 +72,20 @@ pub struct Drivers {
 
     pub attestation_disabled: bool,
 
+    /// Streaming digest started by SHA_INIT, if any
+    pub sha_stream: Option<ShaStream>,
+
     #[cfg(feature = "fips_self_test")]
     pub self_test_status: SelfTestStatus,
 
+    /// KATs run by the pending self test
+    #[cfg(feature = "fips_self_test")]
+    pub self_test_kats: KatSet,
+
+    /// Per-KAT results of the last self test
+    #[cfg(feature = "fips_self_test")]
+    pub self_test_report: KatReport,
+
     pub is_shutdown: bool,
 }
 
@@ -135,8 +148,13 @@ impl Drivers {
             pcr_bank: PcrBank::new(PvReg::new()),
             #[cfg(feature = "fips_self_test")]
             self_test_status: SelfTestStatus::Idle,
+            #[cfg(feature = "fips_self_test")]
+            self_test_kats: KatSet::all(),
+            #[cfg(feature = "fips_self_test")]
+            self_test_report: KatReport::default(),
             cert_chain: ArrayVec::new(),
             attestation_disabled: false,
+            sha_stream: None,
             is_shutdown: false,
         })
     }
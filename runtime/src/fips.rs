@@ -43,14 +43,22 @@ impl FipsModule {
 pub mod fips_self_test_cmd {
     use super::*;
     use crate::RtBootStatus::{RtFipSelfTestComplete, RtFipSelfTestStarted};
-    use caliptra_common::HexBytes;
-    use caliptra_common::{
-        verifier::FirmwareImageVerificationEnv, FMC_ORG, FMC_SIZE, RUNTIME_ORG, RUNTIME_SIZE,
+    use caliptra_common::mailbox_api::{
+        MailboxReqHeader, SelfTestGetResultsResp, SelfTestResult, SelfTestStartReq,
     };
-    use caliptra_drivers::{ResetReason, ShaAccLockState};
+    use caliptra_common::HexBytes;
+    use caliptra_common::{FMC_ORG, FMC_SIZE, RUNTIME_ORG, RUNTIME_SIZE};
+    use caliptra_drivers::ShaAccLockState;
     use caliptra_image_types::RomInfo;
-    use caliptra_image_verify::ImageVerifier;
-    use zerocopy::AsBytes;
+    use caliptra_kat::{KatReport, KatSet, KAT_COUNT};
+    use core::mem::size_of;
+    use zerocopy::{AsBytes, FromBytes};
+
+    // SELF_TEST_GET_RESULTS returns the KAT report verbatim.
+    const _: () = {
+        assert!(size_of::<SelfTestResult>() == size_of::<caliptra_kat::KatResult>());
+        assert!(KAT_COUNT <= SelfTestGetResultsResp::MAX_RESULT_COUNT);
+    };
 
     // Helper function to create a slice from a memory region
     unsafe fn create_slice(org: u32, size: usize) -> &'static [u8] {
@@ -87,24 +95,7 @@ pub mod fips_self_test_cmd {
         let rt = unsafe { create_slice(RUNTIME_ORG, runtime_size as usize) };
         env.mbox.copy_bytes_to_mbox(rt.as_bytes())?;
 
-        let mut venv = FirmwareImageVerificationEnv {
-            sha256: &mut env.sha256,
-            sha384: &mut env.sha384,
-            soc_ifc: &mut env.soc_ifc,
-            ecc384: &mut env.ecc384,
-            data_vault: &mut env.data_vault,
-            pcr_bank: &mut env.pcr_bank,
-            image: env.mbox.raw_mailbox_contents(),
-        };
-
-        let mut verifier = ImageVerifier::new(&mut venv);
-        let _info = verifier.verify(
-            &env.persistent_data.get().manifest1,
-            env.persistent_data.get().manifest1.size
-                + env.persistent_data.get().manifest1.fmc.size
-                + env.persistent_data.get().manifest1.runtime.size,
-            ResetReason::UpdateReset,
-        )?;
+        crate::update::verify_mailbox_image(env)?;
         env.mbox.unlock();
         cprintln!("[rt] Verify complete");
         Ok(())
@@ -120,8 +111,37 @@ pub mod fips_self_test_cmd {
         Ok(())
     }
 
+    /// Returns the KATs selected by a SELF_TEST_START request.
+    pub(crate) fn requested_kats(cmd_args: &[u8]) -> CaliptraResult<KatSet> {
+        if cmd_args.len() == size_of::<MailboxReqHeader>() {
+            return Ok(KatSet::all());
+        }
+        let Some(cmd) = SelfTestStartReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+        match cmd.kats {
+            0 => Ok(KatSet::all()),
+            kats => KatSet::from_bits(kats).ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS),
+        }
+    }
+
+    /// Returns the results of the last self test.
+    pub(crate) fn get_results(env: &Drivers) -> CaliptraResult<MailboxResp> {
+        let report = &env.self_test_report;
+
+        let mut resp = SelfTestGetResultsResp::default();
+        for (dst, src) in resp.results.iter_mut().zip(report.results) {
+            dst.as_bytes_mut().copy_from_slice(src.as_bytes());
+        }
+        resp.data_size = report.results.as_bytes().len() as u32;
+
+        Ok(MailboxResp::SelfTestGetResults(resp))
+    }
+
     /// Execute KAT for cryptographic algorithms implemented in H/W.
     fn execute_kats(env: &mut Drivers) -> CaliptraResult<()> {
+        env.self_test_report = KatReport::default();
+
         let mut kats_env = caliptra_kat::KatsEnv {
             // SHA1 Engine
             sha1: &mut env.sha1,
@@ -151,8 +171,7 @@ pub mod fips_self_test_cmd {
             sha_acc_lock_state: ShaAccLockState::NotAcquired,
         };
 
-        caliptra_kat::execute_kat(&mut kats_env)?;
-        Ok(())
+        caliptra_kat::execute_kats(&mut kats_env, env.self_test_kats, &mut env.self_test_report)
     }
 
     fn rom_integrity_test(env: &mut Drivers) -> CaliptraResult<()> {
@@ -2,12 +2,14 @@
 
 use crate::{handoff::RtHandoff, Drivers, MAX_CERT_CHAIN_SIZE, PL0_PAUSER_FLAG};
 use arrayvec::ArrayVec;
+use caliptra_common::capabilities::Capabilities;
 use caliptra_common::mailbox_api::{
-    FwInfoResp, GetIdevCertReq, GetIdevCertResp, GetIdevInfoResp, MailboxResp, MailboxRespHeader,
-    PopulateIdevCertReq,
+    CapabilitiesResp, FwInfoResp, GetIdevCertReq, GetIdevCertResp, GetIdevInfoResp, MailboxResp,
+    MailboxRespHeader, PopulateIdevCertReq,
 };
 use caliptra_drivers::{CaliptraError, CaliptraResult};
 use caliptra_x509::{Ecdsa384CertBuilder, Ecdsa384Signature};
+use dpe::{DpeProfile, DPE_PROFILE};
 use zerocopy::{AsBytes, FromBytes};
 
 pub struct FwInfoCmd;
@@ -35,6 +37,39 @@ impl FwInfoCmd {
     }
 }
 
+pub struct CapabilitiesCmd;
+impl CapabilitiesCmd {
+    pub(crate) fn execute() -> CaliptraResult<MailboxResp> {
+        let mut capabilities = Capabilities::FMC_BASE
+            | Capabilities::RT_BASE
+            | Capabilities::RT_PCR_QUOTE
+            | Capabilities::RT_LMS_VERIFY
+            | Capabilities::RT_SHA_DIGEST
+            | Capabilities::RT_SHA_STREAM
+            | Capabilities::RT_PAUSER_POLICY
+            | Capabilities::RT_AUTH_MANIFEST
+            | Capabilities::RT_EVENT_LOG
+            | Capabilities::RT_BOOT_TIMELINE;
+        capabilities |= match DPE_PROFILE {
+            DpeProfile::P256Sha256 => Capabilities::RT_DPE_P256_SHA256,
+            DpeProfile::P384Sha384 => Capabilities::RT_DPE_P384_SHA384,
+        };
+        #[cfg(feature = "fips_self_test")]
+        {
+            capabilities |= Capabilities::RT_FIPS_SELF_TEST;
+        }
+        #[cfg(feature = "test_only_commands")]
+        {
+            capabilities |= Capabilities::RT_TEST_ONLY_COMMANDS;
+        }
+
+        Ok(MailboxResp::Capabilities(CapabilitiesResp {
+            hdr: MailboxRespHeader::default(),
+            capabilities: capabilities.to_bytes(),
+        }))
+    }
+}
+
 pub struct IDevIdInfoCmd;
 impl IDevIdInfoCmd {
     pub(crate) fn execute(drivers: &Drivers) -> CaliptraResult<MailboxResp> {
@@ -1,7 +1,9 @@
 // Licensed under the Apache-2.0 license
 #![cfg_attr(not(feature = "fip-self-test"), allow(unused))]
 #![no_std]
+mod auth_manifest;
 pub mod dice;
+mod digest;
 mod disable;
 mod dpe_crypto;
 mod dpe_platform;
@@ -10,6 +12,9 @@ pub mod fips;
 pub mod handoff;
 pub mod info;
 mod invoke_dpe;
+mod logs;
+mod pauser_policy;
+mod pcr;
 mod stash_measurement;
 mod update;
 mod verify;
@@ -19,9 +24,15 @@ pub mod mailbox;
 pub use drivers::Drivers;
 use mailbox::Mailbox;
 
+pub use auth_manifest::{AuthorizeAndStashCmd, SetAuthManifestCmd};
+pub use caliptra_common::boot_status::RtBootStatus;
 pub use caliptra_common::fips::FipsVersionCmd;
 #[cfg(feature = "test_only_commands")]
-pub use dice::{GetLdevCertCmd, TestGetFmcAliasCertCmd};
+pub use dice::TestGetFmcAliasCertCmd;
+pub use dice::{
+    GetCertChainCmd, GetFmcAliasCertCmd, GetIdevCsrCmd, GetLdevCertCmd, GetRtAliasCertCmd,
+};
+pub use digest::{ShaDigestCmd, ShaFinalCmd, ShaInitCmd, ShaStream, ShaUpdateCmd};
 pub use disable::DisableAttestationCmd;
 use dpe_crypto::DpeCrypto;
 pub use dpe_platform::{DpePlatform, VENDOR_ID, VENDOR_SKU};
@@ -29,10 +40,15 @@ pub use fips::FipsShutdownCmd;
 #[cfg(feature = "fips_self_test")]
 pub use fips::{fips_self_test_cmd, fips_self_test_cmd::SelfTestStatus};
 
-pub use info::{FwInfoCmd, IDevIdCertCmd, IDevIdInfoCmd, PopulateIDevIdCertCmd};
+pub use info::{CapabilitiesCmd, FwInfoCmd, IDevIdCertCmd, IDevIdInfoCmd, PopulateIDevIdCertCmd};
 pub use invoke_dpe::InvokeDpeCmd;
+pub use logs::{
+    GetBootTimelineCmd, GetEventLogCmd, GetFuseLogCmd, GetMeasurementLogCmd, GetPcrLogCmd,
+};
+use pauser_policy::PauserPolicy;
+pub use pcr::{ExtendPcrCmd, GetPcrQuoteCmd};
 pub use stash_measurement::StashMeasurementCmd;
-pub use verify::EcdsaVerifyCmd;
+pub use verify::{EcdsaVerifyCmd, LmsVerifyCmd};
 pub mod packet;
 use caliptra_common::mailbox_api::{CommandId, MailboxResp};
 use packet::Packet;
@@ -51,29 +67,11 @@ use dpe::{
 #[cfg(feature = "test_only_commands")]
 use crate::verify::HmacVerifyCmd;
 
-const RUNTIME_BOOT_STATUS_BASE: u32 = 0x600;
-
-/// Statuses used by ROM to log dice derivation progress.
-#[repr(u32)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum RtBootStatus {
-    // RtAlias Statuses
-    RtReadyForCommands = RUNTIME_BOOT_STATUS_BASE,
-    RtFipSelfTestStarted = RUNTIME_BOOT_STATUS_BASE + 1,
-    RtFipSelfTestComplete = RUNTIME_BOOT_STATUS_BASE + 2,
-}
-
-impl From<RtBootStatus> for u32 {
-    /// Converts to this type from the input type.
-    fn from(status: RtBootStatus) -> u32 {
-        status as u32
-    }
-}
-
 pub const DPE_SUPPORT: Support = Support::all();
 pub const MAX_CERT_CHAIN_SIZE: usize = 4096;
 
 pub const PL0_PAUSER_FLAG: u32 = 1;
+pub const PAUSER_POLICY_FLAG: u32 = 1 << 1;
 
 pub struct CptraDpeTypes;
 
@@ -88,10 +86,12 @@ fn enter_idle(drivers: &mut Drivers) {
     #[cfg(feature = "fips_self_test")]
     if let SelfTestStatus::InProgress(execute) = drivers.self_test_status {
         if drivers.mbox.lock() == false {
-            match execute(drivers) {
-                Ok(_) => drivers.self_test_status = SelfTestStatus::Done,
-                Err(e) => caliptra_drivers::report_fw_error_non_fatal(e.into()),
+            // Failed KATs are recorded in the report returned by
+            // SELF_TEST_GET_RESULTS, so the test completes either way.
+            if let Err(e) = execute(drivers) {
+                caliptra_drivers::report_fw_error_non_fatal(e.into());
             }
+            drivers.self_test_status = SelfTestStatus::Done;
         }
     }
 
@@ -106,6 +106,9 @@ fn enter_idle(drivers: &mut Drivers) {
 ///
 /// Returns the mailbox status (DataReady when we send a response) or an error
 fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
+    // Reject commands the manifest doesn't allow for the requesting PAUSER
+    PauserPolicy::check_command(drivers, drivers.mbox.cmd())?;
+
     // For firmware update, don't read data from the mailbox
     if drivers.mbox.cmd() == CommandId::FIRMWARE_LOAD {
         update::handle_impactless_update(drivers)?;
@@ -115,6 +118,14 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         return Err(CaliptraError::RUNTIME_UNEXPECTED_UPDATE_RETURN);
     }
 
+    // The authorization manifest doesn't fit in a packet, so it is verified
+    // directly in the mailbox
+    if drivers.mbox.cmd() == CommandId::SET_AUTH_MANIFEST {
+        let mut resp = SetAuthManifestCmd::execute(drivers)?;
+        Packet::copy_to_mbox(drivers, &mut resp)?;
+        return Ok(MboxStatusE::DataReady);
+    }
+
     // Get the command bytes
     let req_packet = Packet::copy_from_mbox(drivers)?;
     let cmd_bytes = req_packet.as_bytes()?;
@@ -129,17 +140,31 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
     let mut resp = match CommandId::from(req_packet.cmd) {
         CommandId::FIRMWARE_LOAD => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
         CommandId::GET_IDEV_CERT => IDevIdCertCmd::execute(cmd_bytes),
-        CommandId::GET_IDEV_CSR => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
+        CommandId::GET_IDEV_CSR => GetIdevCsrCmd::execute(drivers),
         CommandId::GET_IDEV_INFO => IDevIdInfoCmd::execute(drivers),
-        CommandId::GET_LDEV_CERT => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
+        CommandId::GET_LDEV_CERT => GetLdevCertCmd::execute(drivers),
         CommandId::INVOKE_DPE => InvokeDpeCmd::execute(drivers, cmd_bytes),
         CommandId::ECDSA384_VERIFY => EcdsaVerifyCmd::execute(drivers, cmd_bytes),
+        CommandId::LMS_VERIFY => LmsVerifyCmd::execute(drivers, cmd_bytes),
         CommandId::STASH_MEASUREMENT => StashMeasurementCmd::execute(drivers, cmd_bytes),
         CommandId::DISABLE_ATTESTATION => DisableAttestationCmd::execute(drivers),
         CommandId::FW_INFO => FwInfoCmd::execute(drivers),
         CommandId::POPULATE_IDEV_CERT => PopulateIDevIdCertCmd::execute(drivers, cmd_bytes),
-        #[cfg(feature = "test_only_commands")]
-        CommandId::TEST_ONLY_GET_LDEV_CERT => GetLdevCertCmd::execute(drivers),
+        CommandId::GET_PCR_QUOTE => GetPcrQuoteCmd::execute(drivers, cmd_bytes),
+        CommandId::EXTEND_PCR => ExtendPcrCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_PCR_LOG => GetPcrLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_FUSE_LOG => GetFuseLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_MEASUREMENT_LOG => GetMeasurementLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_EVENT_LOG => GetEventLogCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_BOOT_TIMELINE => GetBootTimelineCmd::execute(cmd_bytes),
+        CommandId::GET_FMC_ALIAS_CERT => GetFmcAliasCertCmd::execute(drivers),
+        CommandId::GET_RT_ALIAS_CERT => GetRtAliasCertCmd::execute(drivers),
+        CommandId::GET_CERT_CHAIN => GetCertChainCmd::execute(drivers, cmd_bytes),
+        CommandId::SHA_DIGEST => ShaDigestCmd::execute(drivers, cmd_bytes),
+        CommandId::SHA_INIT => ShaInitCmd::execute(drivers, cmd_bytes),
+        CommandId::SHA_UPDATE => ShaUpdateCmd::execute(drivers, cmd_bytes),
+        CommandId::SHA_FINAL => ShaFinalCmd::execute(drivers),
+        CommandId::AUTHORIZE_AND_STASH => AuthorizeAndStashCmd::execute(drivers, cmd_bytes),
         #[cfg(feature = "test_only_commands")]
         CommandId::TEST_ONLY_GET_FMC_ALIAS_CERT => TestGetFmcAliasCertCmd::execute(drivers),
         #[cfg(feature = "test_only_commands")]
@@ -150,6 +175,7 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         #[cfg(feature = "fips_self_test")]
         CommandId::SELF_TEST_START => match drivers.self_test_status {
             SelfTestStatus::Idle => {
+                drivers.self_test_kats = fips_self_test_cmd::requested_kats(cmd_bytes)?;
                 drivers.self_test_status = SelfTestStatus::InProgress(fips_self_test_cmd::execute);
                 Ok(MailboxResp::default())
             }
@@ -159,11 +185,12 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         CommandId::SELF_TEST_GET_RESULTS => match drivers.self_test_status {
             SelfTestStatus::Done => {
                 drivers.self_test_status = SelfTestStatus::Idle;
-                Ok(MailboxResp::default())
+                fips_self_test_cmd::get_results(drivers)
             }
             _ => Err(CaliptraError::RUNTIME_SELF_TEST_NOT_STARTED),
         },
         CommandId::SHUTDOWN => FipsShutdownCmd::execute(drivers),
+        CommandId::CAPABILITIES => CapabilitiesCmd::execute(),
         _ => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
     }?;
 
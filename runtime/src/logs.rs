@@ -0,0 +1,234 @@
+// Licensed under the Apache-2.0 license
+
+use crate::Drivers;
+use caliptra_common::{
+    event_log::{EventLogRecord, EV_EVENT_TAG, EV_NO_ACTION},
+    mailbox_api::{
+        ChunkedResp, GetBootTimelineReq, GetBootTimelineResp, GetEventLogReq, GetFuseLogReq,
+        GetFuseLogResp, GetMeasurementLogReq, GetMeasurementLogResp, GetPcrLogReq, GetPcrLogResp,
+        MailboxResp,
+    },
+};
+use caliptra_drivers::{
+    boot_timeline::{BootTimeline, BOOT_TIMELINE_MAX_COUNT},
+    fuse_log::FuseLogEntryId,
+    pcr_log::{PcrLogEntry, PcrLogEntryId, PCR_ID_STASH_MEASUREMENT},
+    CaliptraError, CaliptraResult, Sha384, FUSE_LOG_MAX_COUNT, MEASUREMENT_MAX_COUNT,
+    PCR_LOG_MAX_COUNT,
+};
+use core::mem::size_of;
+use zerocopy::{AsBytes, FromBytes};
+
+// The mailbox API mirrors the persistent data log layouts so that entries can
+// be copied out verbatim, and its reassembled responses hold every entry.
+const _: () = {
+    assert!(
+        size_of::<caliptra_common::mailbox_api::PcrLogEntry>()
+            == size_of::<caliptra_drivers::pcr_log::PcrLogEntry>()
+    );
+    assert!(
+        size_of::<caliptra_common::mailbox_api::FuseLogEntry>()
+            == size_of::<caliptra_drivers::fuse_log::FuseLogEntry>()
+    );
+    assert!(
+        size_of::<caliptra_common::mailbox_api::MeasurementLogEntry>()
+            == size_of::<caliptra_drivers::pcr_log::MeasurementLogEntry>()
+    );
+    assert!(
+        size_of::<caliptra_common::mailbox_api::BootTimelineEntry>()
+            == size_of::<caliptra_drivers::boot_timeline::BootTimelineEntry>()
+    );
+    assert!(GetPcrLogResp::MAX_ENTRY_COUNT == PCR_LOG_MAX_COUNT);
+    assert!(GetFuseLogResp::MAX_ENTRY_COUNT == FUSE_LOG_MAX_COUNT);
+    assert!(GetMeasurementLogResp::MAX_ENTRY_COUNT == MEASUREMENT_MAX_COUNT);
+    assert!(GetBootTimelineResp::MAX_ENTRY_COUNT == BOOT_TIMELINE_MAX_COUNT);
+};
+
+pub struct GetPcrLogCmd;
+impl GetPcrLogCmd {
+    /// Returns the chunk of the PCR log entries that starts at the requested
+    /// offset.
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetPcrLogReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let pdata = drivers.persistent_data.get();
+        let count = (pdata.fht.pcr_log_index as usize).min(PCR_LOG_MAX_COUNT);
+
+        Ok(MailboxResp::Chunked(ChunkedResp::from_payload(
+            pdata.pcr_log[..count].as_bytes(),
+            cmd.offset,
+        )?))
+    }
+}
+
+pub struct GetFuseLogCmd;
+impl GetFuseLogCmd {
+    /// Returns the chunk of the populated fuse log entries that starts at the
+    /// requested offset.
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetFuseLogReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        // The fuse log is indexed by entry ID rather than filled in order, so
+        // only return the slots that ROM actually populated.
+        let mut resp = ChunkedResp::new_streamed(cmd.offset);
+        for entry in drivers
+            .persistent_data
+            .get()
+            .fuse_log
+            .iter()
+            .filter(|e| FuseLogEntryId::from(e.entry_id) != FuseLogEntryId::Invalid)
+        {
+            resp.append_payload(entry.as_bytes());
+        }
+
+        Ok(MailboxResp::Chunked(resp.finish_payload()?))
+    }
+}
+
+pub struct GetMeasurementLogCmd;
+impl GetMeasurementLogCmd {
+    /// Returns the chunk of the measurement log entries that starts at the
+    /// requested offset.
+    pub(crate) fn execute(drivers: &Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetMeasurementLogReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let pdata = drivers.persistent_data.get();
+        let count = (pdata.fht.meas_log_index as usize).min(MEASUREMENT_MAX_COUNT);
+
+        Ok(MailboxResp::Chunked(ChunkedResp::from_payload(
+            pdata.measurement_log[..count].as_bytes(),
+            cmd.offset,
+        )?))
+    }
+}
+
+pub struct GetBootTimelineCmd;
+impl GetBootTimelineCmd {
+    /// Returns the chunk of the boot timeline entries, oldest first, that
+    /// starts at the requested offset.
+    pub(crate) fn execute(cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetBootTimelineReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let timeline = BootTimeline::read();
+        let mut resp = ChunkedResp::new_streamed(cmd.offset);
+        for entry in timeline.entries() {
+            resp.append_payload(entry.as_bytes());
+        }
+
+        Ok(MailboxResp::Chunked(resp.finish_payload()?))
+    }
+}
+
+pub struct GetEventLogCmd;
+impl GetEventLogCmd {
+    /// Returns the chunk that starts at the requested offset of the CEL
+    /// encoding of the measurement log followed by the PCR log.
+    ///
+    /// If measurements were stashed after the measurement log filled up, the
+    /// measurement log is followed by an `EV_NO_ACTION` record that marks
+    /// PCR31 as incomplete.
+    ///
+    /// The log is encoded again for every chunk rather than being kept in
+    /// memory.
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetEventLogReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let pdata = drivers.persistent_data.get();
+        let meas_count = (pdata.fht.meas_log_index as usize).min(MEASUREMENT_MAX_COUNT);
+        let pcr_count = (pdata.fht.pcr_log_index as usize).min(PCR_LOG_MAX_COUNT);
+
+        let mut resp = ChunkedResp::new_streamed(cmd.offset);
+        let mut recnum = 0;
+        for entry in pdata.measurement_log[..meas_count].iter() {
+            Self::append_entry(
+                &mut resp,
+                &mut drivers.sha384,
+                &mut recnum,
+                &entry.pcr_entry,
+            )?;
+        }
+        let dropped = pdata.fht.meas_log_index - meas_count as u32;
+        if dropped > 0 {
+            Self::append_record(
+                &mut resp,
+                &mut recnum,
+                EventLogRecord {
+                    recnum: 0,
+                    pcr: PCR_ID_STASH_MEASUREMENT as u8,
+                    digest: [0u8; 48],
+                    event_type: EV_NO_ACTION,
+                    event_id: PcrLogEntryId::StashMeasurement as u32,
+                    event_data: &dropped.to_le_bytes(),
+                },
+            )?;
+        }
+        for entry in pdata.pcr_log[..pcr_count].iter() {
+            Self::append_entry(&mut resp, &mut drivers.sha384, &mut recnum, entry)?;
+        }
+
+        Ok(MailboxResp::Chunked(resp.finish_payload()?))
+    }
+
+    /// Append a record to `resp` for every PCR `entry` was extended into.
+    fn append_entry(
+        resp: &mut ChunkedResp,
+        sha384: &mut Sha384,
+        recnum: &mut u64,
+        entry: &PcrLogEntry,
+    ) -> CaliptraResult<()> {
+        let event_data = entry.measured_data();
+        // Digest sized data is extended as is, which a standard replay of the
+        // digest reproduces. Shorter data can't be replayed that way.
+        let digest = match event_data.try_into() {
+            Ok(digest) => digest,
+            Err(_) => sha384.digest(event_data)?.into(),
+        };
+
+        for pcr in 0..32 {
+            if entry.pcr_ids & (1 << pcr) == 0 {
+                continue;
+            }
+            Self::append_record(
+                resp,
+                recnum,
+                EventLogRecord {
+                    recnum: 0,
+                    pcr,
+                    digest,
+                    event_type: EV_EVENT_TAG,
+                    event_id: entry.id.into(),
+                    event_data,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Append `record` to `resp`, numbered as the next record.
+    fn append_record(
+        resp: &mut ChunkedResp,
+        recnum: &mut u64,
+        record: EventLogRecord,
+    ) -> CaliptraResult<()> {
+        let mut buf = [0u8; EventLogRecord::MAX_SIZE];
+        let len = EventLogRecord {
+            recnum: *recnum,
+            ..record
+        }
+        .encode(&mut buf)
+        .ok_or(CaliptraError::RUNTIME_INTERNAL)?;
+        resp.append_payload(&buf[..len]);
+        *recnum += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+// Licensed under the Apache-2.0 license
+
+use crate::{Drivers, PAUSER_POLICY_FLAG};
+use caliptra_common::{mailbox_api::CommandId, permissions::CommandPermissions};
+use caliptra_drivers::{CaliptraError, CaliptraResult};
+
+pub struct PauserPolicy;
+impl PauserPolicy {
+    /// Check that the PAUSER holding the mailbox lock is allowed to issue
+    /// `cmd` by the policy signed in the image manifest.
+    pub(crate) fn check_command(drivers: &Drivers, cmd: CommandId) -> CaliptraResult<()> {
+        // Commands without a permission bit can't be granted by the policy.
+        Self::check(drivers, CommandPermissions::from_command(cmd))
+    }
+
+    /// Check that the PAUSER holding the mailbox lock is granted `perm` by
+    /// the policy, for operations a command performs on behalf of another
+    /// command, such as the PCR extend of SHA_DIGEST.
+    pub(crate) fn check_permission(
+        drivers: &Drivers,
+        perm: CommandPermissions,
+    ) -> CaliptraResult<()> {
+        Self::check(drivers, Some(perm))
+    }
+
+    fn check(drivers: &Drivers, perm: Option<CommandPermissions>) -> CaliptraResult<()> {
+        let header = &drivers.persistent_data.get().manifest1.header;
+        if header.flags & PAUSER_POLICY_FLAG == 0 {
+            return Ok(());
+        }
+
+        let pauser = drivers.mbox.user();
+        let allowed = header
+            .pauser_policy
+            .entry(pauser)
+            .map(|entry| CommandPermissions::from_words(entry.allowed_cmds))
+            .unwrap_or_default();
+
+        match perm {
+            Some(perm) if allowed.contains(perm) => Ok(()),
+            _ => Err(CaliptraError::RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// Licensed under the Apache-2.0 license
+
+use crate::Drivers;
+use caliptra_common::keyids::KEY_ID_RT_PRIV_KEY;
+use caliptra_common::mailbox_api::{ExtendPcrReq, GetPcrQuoteReq, GetPcrQuoteResp, MailboxResp};
+use caliptra_drivers::{
+    pcr_log::{
+        PcrLogEntry, PcrLogEntryId, PCR_ID_FMC_CURRENT, PCR_ID_FMC_JOURNEY,
+        PCR_ID_STASH_MEASUREMENT, RT_FW_CURRENT_PCR, RT_FW_JOURNEY_PCR,
+    },
+    Array4x12, CaliptraError, CaliptraResult, Ecc384PrivKeyIn, KeyReadArgs, PcrId,
+};
+use zerocopy::{AsBytes, FromBytes};
+
+pub struct GetPcrQuoteCmd;
+impl GetPcrQuoteCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = GetPcrQuoteReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let mut resp = GetPcrQuoteResp {
+            nonce: cmd.nonce,
+            ..Default::default()
+        };
+        for (i, pcr) in resp.pcrs.iter_mut().enumerate() {
+            let id = PcrId::try_from(i as u8).map_err(|_| CaliptraError::RUNTIME_INTERNAL)?;
+            *pcr = drivers.pcr_bank.read_pcr(id).into();
+        }
+
+        // Quote digest = SHA384(nonce || PCR0 || ... || PCR31)
+        let mut digest = Array4x12::default();
+        let mut op = drivers.sha384.digest_init()?;
+        op.update(&resp.nonce)?;
+        op.update(resp.pcrs.as_bytes())?;
+        op.finalize(&mut digest)?;
+
+        let pub_key = drivers.persistent_data.get().fht.rt_dice_pub_key;
+        let priv_key = Ecc384PrivKeyIn::Key(KeyReadArgs::new(KEY_ID_RT_PRIV_KEY));
+        let sig = drivers
+            .ecc384
+            .sign(&priv_key, &pub_key, &digest, &mut drivers.trng)
+            .map_err(|_| CaliptraError::RUNTIME_GET_PCR_QUOTE_FAILED)?;
+
+        resp.signature_r = sig.r.into();
+        resp.signature_s = sig.s.into();
+
+        Ok(MailboxResp::GetPcrQuote(resp))
+    }
+}
+
+pub struct ExtendPcrCmd;
+impl ExtendPcrCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = ExtendPcrReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        let pcr_id = Self::soc_pcr_id(cmd.pcr_idx)?;
+        Self::extend(drivers, pcr_id, &cmd.data)?;
+
+        Ok(MailboxResp::default())
+    }
+
+    /// Extend `data` into the SoC PCR `pcr_id` and record it in the PCR log.
+    pub(crate) fn extend(
+        drivers: &mut Drivers,
+        pcr_id: PcrId,
+        data: &[u8; 48],
+    ) -> CaliptraResult<()> {
+        // Make sure the extend can be logged before touching the PCR so the
+        // log can always be used to replay the PCR value.
+        let pdata = drivers.persistent_data.get_mut();
+        let log_index = pdata.fht.pcr_log_index as usize;
+        let Some(dst) = pdata.pcr_log.get_mut(log_index) else {
+            return Err(CaliptraError::RUNTIME_PCR_LOG_EXHAUSTED);
+        };
+
+        drivers
+            .pcr_bank
+            .extend_pcr(pcr_id, &mut drivers.sha384, data)?;
+
+        *dst = PcrLogEntry {
+            id: PcrLogEntryId::SocPcrExtend as u16,
+            pcr_ids: 1 << pcr_id as u8,
+            ..Default::default()
+        };
+        dst.pcr_data.as_bytes_mut().copy_from_slice(data);
+        pdata.fht.pcr_log_index += 1;
+
+        Ok(())
+    }
+
+    /// Map `pcr_idx` to a PCR the SoC is allowed to extend (PCR4 - PCR30).
+    /// PCRs owned by ROM, FMC, and STASH_MEASUREMENT are rejected.
+    pub(crate) fn soc_pcr_id(pcr_idx: u32) -> CaliptraResult<PcrId> {
+        let pcr_id = u8::try_from(pcr_idx)
+            .ok()
+            .and_then(|idx| PcrId::try_from(idx).ok())
+            .ok_or(CaliptraError::RUNTIME_EXTEND_PCR_INVALID_INDEX)?;
+
+        match pcr_id {
+            PCR_ID_FMC_CURRENT | PCR_ID_FMC_JOURNEY => {
+                Err(CaliptraError::RUNTIME_EXTEND_PCR_FMC_PCR_RESERVED)
+            }
+            RT_FW_CURRENT_PCR | RT_FW_JOURNEY_PCR => {
+                Err(CaliptraError::RUNTIME_EXTEND_PCR_RT_PCR_RESERVED)
+            }
+            PCR_ID_STASH_MEASUREMENT => {
+                Err(CaliptraError::RUNTIME_EXTEND_PCR_STASH_MEASUREMENT_PCR_RESERVED)
+            }
+            _ => Ok(pcr_id),
+        }
+    }
+}
@@ -4,7 +4,10 @@ use crate::{dpe_crypto::DpeCrypto, CptraDpeTypes, DpePlatform, Drivers};
 use caliptra_common::mailbox_api::{
     MailboxResp, MailboxRespHeader, StashMeasurementReq, StashMeasurementResp,
 };
-use caliptra_drivers::{pcr_log::PCR_ID_STASH_MEASUREMENT, CaliptraError, CaliptraResult};
+use caliptra_drivers::{
+    pcr_log::{MeasurementLogEntry, PcrLogEntry, PcrLogEntryId, PCR_ID_STASH_MEASUREMENT},
+    CaliptraError, CaliptraResult,
+};
 use crypto::{AlgLen, Crypto};
 use dpe::{
     commands::{CommandExecution, DeriveChildCmd, DeriveChildFlags},
@@ -18,59 +21,13 @@ pub struct StashMeasurementCmd;
 impl StashMeasurementCmd {
     pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
         if let Some(cmd) = StashMeasurementReq::read_from(cmd_args) {
-            let dpe_result = {
-                let hashed_rt_pub_key = drivers.compute_rt_alias_sn()?;
-                let pdata = drivers.persistent_data.get();
-                let rt_pub_key = pdata.fht.rt_dice_pub_key;
-                let mut crypto = DpeCrypto::new(
-                    &mut drivers.sha384,
-                    &mut drivers.trng,
-                    &mut drivers.ecc384,
-                    &mut drivers.hmac384,
-                    &mut drivers.key_vault,
-                    rt_pub_key,
-                );
-                let mut env = DpeEnv::<CptraDpeTypes> {
-                    crypto,
-                    platform: DpePlatform::new(
-                        pdata.manifest1.header.pl0_pauser,
-                        hashed_rt_pub_key,
-                        &mut drivers.cert_chain,
-                    ),
-                };
-
-                let locality = drivers.mbox.user();
-                // Call DeriveChild to add the measurement to DPE
-                let derive_child_resp = DeriveChildCmd {
-                    handle: ContextHandle::default(),
-                    data: cmd.measurement,
-                    flags: DeriveChildFlags::MAKE_DEFAULT
-                        | DeriveChildFlags::CHANGE_LOCALITY
-                        | DeriveChildFlags::INPUT_ALLOW_CA
-                        | DeriveChildFlags::INPUT_ALLOW_X509,
-                    tci_type: u32::from_be_bytes(cmd.metadata),
-                    target_locality: locality,
-                }
-                .execute(
-                    &mut drivers.persistent_data.get_mut().dpe,
-                    &mut env,
-                    locality,
-                );
-
-                match derive_child_resp {
-                    Ok(_) => DpeErrorCode::NoError,
-                    Err(e) => e,
-                }
-            };
-
-            if let DpeErrorCode::NoError = dpe_result {
-                // Extend the measurement into PCR31
-                drivers.pcr_bank.extend_pcr(
-                    PCR_ID_STASH_MEASUREMENT,
-                    &mut drivers.sha384,
-                    cmd.measurement.as_bytes(),
-                )?;
-            }
+            let dpe_result = Self::stash_measurement(
+                drivers,
+                &cmd.metadata,
+                &cmd.measurement,
+                &cmd.context,
+                cmd.svn,
+            )?;
 
             Ok(MailboxResp::StashMeasurement(StashMeasurementResp {
                 hdr: MailboxRespHeader::default(),
@@ -80,4 +37,104 @@ impl StashMeasurementCmd {
             Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)
         }
     }
+
+    /// Add `measurement` to DPE as a child of the default context and, on
+    /// success, extend it into PCR31 and record it in the measurement log
+    /// along with `context` and `svn`.
+    pub(crate) fn stash_measurement(
+        drivers: &mut Drivers,
+        metadata: &[u8; 4],
+        measurement: &[u8; 48],
+        context: &[u8; 48],
+        svn: u32,
+    ) -> CaliptraResult<DpeErrorCode> {
+        let dpe_result = {
+            let hashed_rt_pub_key = drivers.compute_rt_alias_sn()?;
+            let pdata = drivers.persistent_data.get();
+            let rt_pub_key = pdata.fht.rt_dice_pub_key;
+            let mut crypto = DpeCrypto::new(
+                &mut drivers.sha384,
+                &mut drivers.trng,
+                &mut drivers.ecc384,
+                &mut drivers.hmac384,
+                &mut drivers.key_vault,
+                rt_pub_key,
+            );
+            let mut env = DpeEnv::<CptraDpeTypes> {
+                crypto,
+                platform: DpePlatform::new(
+                    pdata.manifest1.header.pl0_pauser,
+                    hashed_rt_pub_key,
+                    &mut drivers.cert_chain,
+                ),
+            };
+
+            let locality = drivers.mbox.user();
+            // Call DeriveChild to add the measurement to DPE
+            let derive_child_resp = DeriveChildCmd {
+                handle: ContextHandle::default(),
+                data: *measurement,
+                flags: DeriveChildFlags::MAKE_DEFAULT
+                    | DeriveChildFlags::CHANGE_LOCALITY
+                    | DeriveChildFlags::INPUT_ALLOW_CA
+                    | DeriveChildFlags::INPUT_ALLOW_X509,
+                tci_type: u32::from_be_bytes(*metadata),
+                target_locality: locality,
+            }
+            .execute(
+                &mut drivers.persistent_data.get_mut().dpe,
+                &mut env,
+                locality,
+            );
+
+            match derive_child_resp {
+                Ok(_) => DpeErrorCode::NoError,
+                Err(e) => e,
+            }
+        };
+
+        if let DpeErrorCode::NoError = dpe_result {
+            // Extend the measurement into PCR31
+            drivers.pcr_bank.extend_pcr(
+                PCR_ID_STASH_MEASUREMENT,
+                &mut drivers.sha384,
+                measurement.as_bytes(),
+            )?;
+            Self::log_measurement(drivers, metadata, measurement, context, svn);
+        }
+
+        Ok(dpe_result)
+    }
+
+    /// Record a measurement stashed at runtime in the measurement log.
+    ///
+    /// Once the log is full, measurements are only counted, so that
+    /// GET_EVENT_LOG can report that PCR31 can't be replayed.
+    fn log_measurement(
+        drivers: &mut Drivers,
+        metadata: &[u8; 4],
+        measurement: &[u8; 48],
+        context: &[u8; 48],
+        svn: u32,
+    ) {
+        let pdata = drivers.persistent_data.get_mut();
+        let index = pdata.fht.meas_log_index;
+        pdata.fht.meas_log_index = index.saturating_add(1);
+        let Some(dst) = pdata.measurement_log.get_mut(index as usize) else {
+            return;
+        };
+
+        *dst = MeasurementLogEntry {
+            pcr_entry: PcrLogEntry {
+                id: PcrLogEntryId::StashMeasurement as u16,
+                reserved0: [0u8; 2],
+                pcr_ids: 1 << (PCR_ID_STASH_MEASUREMENT as u8),
+                pcr_data: zerocopy::transmute!(*measurement),
+            },
+            metadata: *metadata,
+            context: zerocopy::transmute!(*context),
+            svn,
+            reserved0: [0u8; 4],
+        };
+    }
 }
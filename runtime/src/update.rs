@@ -1,9 +1,22 @@
 // Licensed under the Apache-2.0 license
 
-use crate::Drivers;
-use caliptra_drivers::{CaliptraError, CaliptraResult};
+use crate::{Drivers, ShaStream};
+use caliptra_common::{cprintln, verifier::FirmwareImageVerificationEnv};
+use caliptra_drivers::{CaliptraError, CaliptraResult, ResetReason};
+use caliptra_image_types::ImageManifest;
+use caliptra_image_verify::ImageVerifier;
+use zerocopy::LayoutVerified;
 
 pub(crate) fn handle_impactless_update(drivers: &mut Drivers) -> CaliptraResult<()> {
+    // The image is hashed with the SHA accelerator, so release it if a
+    // streaming digest is in progress.
+    ShaStream::abort(drivers)?;
+
+    // Reject bad images while the current runtime is still running, rather
+    // than after the reset when ROM verifies the image again.
+    verify_mailbox_image(drivers)?;
+    cprintln!("[rt] Update image verified");
+
     let cycles = drivers.soc_ifc.internal_fw_update_reset_wait_cycles();
     for _ in 0..cycles {
         drivers.soc_ifc.assert_fw_update_reset();
@@ -11,3 +24,31 @@ pub(crate) fn handle_impactless_update(drivers: &mut Drivers) -> CaliptraResult<
 
     Err(CaliptraError::RUNTIME_UNEXPECTED_UPDATE_RETURN)
 }
+
+/// Verify the image bundle in the mailbox with the same checks ROM applies
+/// during an update reset. The mailbox contents are left untouched, so ROM
+/// can load them after the reset.
+pub(crate) fn verify_mailbox_image(drivers: &mut Drivers) -> CaliptraResult<()> {
+    let dlen = drivers.mbox.dlen();
+    let image = drivers
+        .mbox
+        .raw_mailbox_contents()
+        .get(..dlen as usize)
+        .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+    let (manifest, _) = LayoutVerified::<&[u8], ImageManifest>::new_from_prefix(image)
+        .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+
+    let mut venv = FirmwareImageVerificationEnv {
+        sha256: &mut drivers.sha256,
+        sha384: &mut drivers.sha384,
+        soc_ifc: &mut drivers.soc_ifc,
+        ecc384: &mut drivers.ecc384,
+        data_vault: &mut drivers.data_vault,
+        pcr_bank: &mut drivers.pcr_bank,
+        image,
+    };
+
+    let mut verifier = ImageVerifier::new(&mut venv);
+    verifier.verify(manifest.into_ref(), dlen, ResetReason::UpdateReset)?;
+    Ok(())
+}
@@ -3,11 +3,12 @@
 use crate::Drivers;
 #[cfg(feature = "test_only_commands")]
 use caliptra_common::mailbox_api::HmacVerifyReq;
-use caliptra_common::mailbox_api::{EcdsaVerifyReq, MailboxResp};
+use caliptra_common::mailbox_api::{EcdsaVerifyReq, LmsVerifyReq, MailboxResp};
 use caliptra_drivers::{
     Array4x12, CaliptraError, CaliptraResult, Ecc384PubKey, Ecc384Result, Ecc384Scalar,
-    Ecc384Signature,
+    Ecc384Signature, LmsResult,
 };
+use caliptra_lms_types::{LmotsAlgorithmType, LmsAlgorithmType, LmsPublicKey, LmsSignature};
 
 #[cfg(feature = "test_only_commands")]
 use caliptra_drivers::{Hmac384Data, Hmac384Key, Trng};
@@ -15,7 +16,7 @@ use caliptra_drivers::{Hmac384Data, Hmac384Key, Trng};
 use caliptra_registers::{
     csrng::CsrngReg, entropy_src::EntropySrcReg, soc_ifc::SocIfcReg, soc_ifc_trng::SocIfcTrngReg,
 };
-use zerocopy::FromBytes;
+use zerocopy::{FromBytes, LayoutVerified};
 
 pub struct EcdsaVerifyCmd;
 impl EcdsaVerifyCmd {
@@ -50,6 +51,97 @@ impl EcdsaVerifyCmd {
     }
 }
 
+pub struct LmsVerifyCmd;
+impl LmsVerifyCmd {
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = LayoutVerified::<_, LmsVerifyReq>::new(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+        let Some(signature) = cmd.signature.get(..cmd.signature_size as usize) else {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        };
+        let Some(message) = cmd.message.get(..cmd.message_size as usize) else {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        };
+
+        // The algorithm types lead the public key regardless of N, so peek at
+        // them to pick the parameter set to verify with.
+        let Some(key_hdr) = LmsPublicKey::<0>::read_from_prefix(&cmd.pub_key[..]) else {
+            return Err(CaliptraError::RUNTIME_INTERNAL);
+        };
+
+        let result = match (key_hdr.tree_type, key_hdr.otstype) {
+            (LmsAlgorithmType::LmsSha256N24H5, LmotsAlgorithmType::LmotsSha256N24W4) => {
+                Self::verify::<6, 51, 5>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N24H10, LmotsAlgorithmType::LmotsSha256N24W4) => {
+                Self::verify::<6, 51, 10>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N24H15, LmotsAlgorithmType::LmotsSha256N24W4) => {
+                // Use the non-generic entry point so this parameter set runs the
+                // same machine code as the image verifier and its KAT.
+                let (pub_key, sig) = Self::parse::<6, 51, 15>(&cmd.pub_key, signature)?;
+                drivers
+                    .lms
+                    .verify_lms_signature(&mut drivers.sha256, message, &pub_key, sig)
+            }
+            (LmsAlgorithmType::LmsSha256N24H5, LmotsAlgorithmType::LmotsSha256N24W8) => {
+                Self::verify::<6, 26, 5>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N24H10, LmotsAlgorithmType::LmotsSha256N24W8) => {
+                Self::verify::<6, 26, 10>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N24H15, LmotsAlgorithmType::LmotsSha256N24W8) => {
+                Self::verify::<6, 26, 15>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N32H5, LmotsAlgorithmType::LmotsSha256N32W8) => {
+                Self::verify::<8, 34, 5>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N32H10, LmotsAlgorithmType::LmotsSha256N32W8) => {
+                Self::verify::<8, 34, 10>(drivers, &cmd.pub_key, signature, message)
+            }
+            (LmsAlgorithmType::LmsSha256N32H15, LmotsAlgorithmType::LmotsSha256N32W8) => {
+                Self::verify::<8, 34, 15>(drivers, &cmd.pub_key, signature, message)
+            }
+            _ => Err(CaliptraError::RUNTIME_LMS_VERIFY_UNSUPPORTED_ALGORITHM),
+        }?;
+
+        if result != LmsResult::Success {
+            return Err(CaliptraError::RUNTIME_LMS_VERIFY_FAILED);
+        }
+
+        Ok(MailboxResp::default())
+    }
+
+    fn verify<const N: usize, const P: usize, const H: usize>(
+        drivers: &mut Drivers,
+        pub_key: &[u8],
+        signature: &[u8],
+        message: &[u8],
+    ) -> CaliptraResult<LmsResult> {
+        let (pub_key, sig) = Self::parse::<N, P, H>(pub_key, signature)?;
+        drivers
+            .lms
+            .verify_lms_signature_generic(&mut drivers.sha256, message, &pub_key, sig)
+    }
+
+    fn parse<'a, const N: usize, const P: usize, const H: usize>(
+        pub_key: &[u8],
+        signature: &'a [u8],
+    ) -> CaliptraResult<(LmsPublicKey<N>, &'a LmsSignature<N, P, H>)> {
+        let Some(pub_key) = LmsPublicKey::<N>::read_from_prefix(pub_key) else {
+            return Err(CaliptraError::RUNTIME_INTERNAL);
+        };
+        let Some(sig) = LayoutVerified::<_, LmsSignature<N, P, H>>::new(signature) else {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        };
+        if sig.tree_type != pub_key.tree_type {
+            return Err(CaliptraError::RUNTIME_LMS_VERIFY_FAILED);
+        }
+        Ok((pub_key, sig.into_ref()))
+    }
+}
+
 /// Handle the `TEST_ONLY_HMAC_SHA384_VERIFY` mailbox command
 #[cfg(feature = "test_only_commands")]
 pub struct HmacVerifyCmd;
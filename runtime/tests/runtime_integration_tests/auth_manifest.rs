@@ -0,0 +1,203 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::run_rt_test;
+use caliptra_builder::{
+    firmware::{APP_WITH_UART, FMC_WITH_UART},
+    ImageOptions,
+};
+use caliptra_common::mailbox_api::{AuthorizeAndStashReq, CommandId};
+use caliptra_drivers::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use caliptra_image_fake_keys::{OWNER_CONFIG, VENDOR_CONFIG_KEY_0};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::{
+    AuthManifest, AuthManifestImageMetadata, AuthManifestImageMetadataCollection,
+};
+use caliptra_runtime::RtBootStatus;
+use zerocopy::AsBytes;
+
+const SOC_FW_ID: [u8; 4] = *b"SOC0";
+const SOC_FW_DIGEST: [u8; 48] = [0x5a; 48];
+const SOC_FW_SVN: u32 = 2;
+const SOC_FW_CONTEXT: [u8; 48] = [0x3c; 48];
+
+fn run_rt_test_ready() -> DefaultHwModel {
+    let mut model = run_rt_test(None, None, None);
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+    model
+}
+
+fn sign_auth_manifest(entries: &[AuthManifestImageMetadata]) -> AuthManifest {
+    let mut image_metadata = AuthManifestImageMetadataCollection {
+        entry_count: entries.len() as u32,
+        ..Default::default()
+    };
+    image_metadata.entries[..entries.len()].copy_from_slice(entries);
+
+    ImageGenerator::new(OsslCrypto::default())
+        .gen_auth_manifest(&VENDOR_CONFIG_KEY_0, &OWNER_CONFIG, &image_metadata)
+        .unwrap()
+}
+
+fn gen_auth_manifest() -> AuthManifest {
+    sign_auth_manifest(&[AuthManifestImageMetadata {
+        fw_id: SOC_FW_ID,
+        svn: SOC_FW_SVN,
+        digest: SOC_FW_DIGEST,
+    }])
+}
+
+#[test]
+fn test_authorize_and_stash() {
+    let mut model = run_rt_test_ready();
+    model
+        .set_auth_manifest(gen_auth_manifest().as_bytes())
+        .unwrap();
+
+    assert!(model
+        .authorize_and_stash(SOC_FW_ID, SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+    assert!(model
+        .authorize_and_stash(
+            SOC_FW_ID,
+            SOC_FW_DIGEST,
+            SOC_FW_CONTEXT,
+            AuthorizeAndStashReq::FLAG_SKIP_STASH
+        )
+        .unwrap());
+
+    // Only the first request stashed the measurement, with the caller's
+    // context and the SVN from the manifest.
+    let measurement_log = model.get_measurement_log().unwrap();
+    assert_eq!(measurement_log.entries().len(), 1);
+    let entry = &measurement_log.entries()[0];
+    assert_eq!(entry.metadata, SOC_FW_ID);
+    assert_eq!(entry.pcr_entry.measured_data(), SOC_FW_DIGEST);
+    assert_eq!(entry.context.as_bytes(), SOC_FW_CONTEXT);
+    assert_eq!(entry.svn, SOC_FW_SVN);
+
+    // Unknown digest
+    assert!(!model
+        .authorize_and_stash(SOC_FW_ID, [0xa5; 48], SOC_FW_CONTEXT, 0)
+        .unwrap());
+
+    // Known digest listed for a different firmware ID
+    assert!(!model
+        .authorize_and_stash(*b"SOC1", SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+}
+
+#[test]
+fn test_authorize_and_stash_without_manifest() {
+    let mut model = run_rt_test_ready();
+
+    assert!(!model
+        .authorize_and_stash(SOC_FW_ID, SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+}
+
+#[test]
+fn test_auth_manifest_survives_update_reset() {
+    let image = caliptra_builder::build_and_sign_image(
+        &FMC_WITH_UART,
+        &APP_WITH_UART,
+        ImageOptions::default(),
+    )
+    .unwrap()
+    .to_bytes()
+    .unwrap();
+
+    let mut model = run_rt_test_ready();
+    model
+        .set_auth_manifest(gen_auth_manifest().as_bytes())
+        .unwrap();
+
+    model
+        .mailbox_execute(u32::from(CommandId::FIRMWARE_LOAD), &image)
+        .unwrap();
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    assert!(model
+        .authorize_and_stash(SOC_FW_ID, SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+}
+
+#[test]
+fn test_set_auth_manifest_duplicate_fw_id() {
+    let mut model = run_rt_test_ready();
+
+    let entry = AuthManifestImageMetadata {
+        fw_id: SOC_FW_ID,
+        svn: SOC_FW_SVN,
+        digest: SOC_FW_DIGEST,
+    };
+    let manifest = sign_auth_manifest(&[
+        entry,
+        AuthManifestImageMetadata {
+            digest: [0xa5; 48],
+            ..entry
+        },
+    ]);
+    assert_eq!(
+        model.set_auth_manifest(manifest.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_MANIFEST_DUPLICATE_FW_ID.into()
+        ))
+    );
+
+    assert!(!model
+        .authorize_and_stash(SOC_FW_ID, SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+}
+
+#[test]
+fn test_set_auth_manifest_invalid_signatures() {
+    let mut model = run_rt_test_ready();
+
+    let mut manifest = gen_auth_manifest();
+    manifest.preamble.vendor_sigs.ecc_sig.s[0] ^= 1;
+    assert_eq!(
+        model.set_auth_manifest(manifest.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID.into()
+        ))
+    );
+
+    let mut manifest = gen_auth_manifest();
+    manifest.preamble.owner_sigs.ecc_sig.s[0] ^= 1;
+    assert_eq!(
+        model.set_auth_manifest(manifest.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_MANIFEST_OWNER_SIGNATURE_INVALID.into()
+        ))
+    );
+
+    // Metadata modified after signing
+    let mut manifest = gen_auth_manifest();
+    manifest.image_metadata.entries[0].digest = [0xa5; 48];
+    assert_eq!(
+        model.set_auth_manifest(manifest.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_MANIFEST_VENDOR_SIGNATURE_INVALID.into()
+        ))
+    );
+
+    let mut manifest = gen_auth_manifest();
+    manifest.preamble.marker = 0;
+    assert_eq!(
+        model.set_auth_manifest(manifest.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_AUTH_MANIFEST_INVALID.into()
+        ))
+    );
+
+    // A rejected manifest doesn't authorize anything
+    assert!(!model
+        .authorize_and_stash(SOC_FW_ID, SOC_FW_DIGEST, SOC_FW_CONTEXT, 0)
+        .unwrap());
+}
@@ -0,0 +1,45 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::run_rt_test;
+use caliptra_common::RomBootStatus;
+use caliptra_hw_model::{BootPhases, HwModel};
+use caliptra_runtime::RtBootStatus;
+
+#[test]
+fn test_get_boot_timeline() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let timeline = model.get_boot_timeline().unwrap();
+    let entries = timeline.entries();
+    assert_eq!(
+        entries.first().unwrap().boot_status,
+        u32::from(RomBootStatus::CfiInitialized)
+    );
+    assert_eq!(
+        entries.last().unwrap().boot_status,
+        u32::from(RtBootStatus::RtReadyForCommands)
+    );
+    assert!(entries
+        .windows(2)
+        .all(|pair| pair[0].cycle_count() < pair[1].cycle_count()));
+
+    let phases = BootPhases::new(entries);
+    for name in [
+        "KATs",
+        "DOE",
+        "IDevID",
+        "LDevID",
+        "Image download",
+        "Image verify",
+        "Image load",
+        "FMC alias",
+        "RT alias",
+        "Runtime init",
+    ] {
+        assert!(phases.cycles(name).unwrap() > 0, "{name}");
+    }
+}
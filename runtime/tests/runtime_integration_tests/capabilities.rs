@@ -0,0 +1,59 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::run_rt_test;
+use caliptra_common::capabilities::Capabilities;
+use caliptra_common::mailbox_api::{
+    CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader,
+};
+use caliptra_hw_model::HwModel;
+use caliptra_runtime::RtBootStatus;
+use zerocopy::{AsBytes, FromBytes};
+
+#[test]
+fn test_rt_capabilities() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::CAPABILITIES), &[]),
+    };
+    let response = model
+        .mailbox_execute(CommandId::CAPABILITIES.into(), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    let capabilities_resp = CapabilitiesResp::read_from(response.as_bytes()).unwrap();
+    assert!(caliptra_common::checksum::verify_checksum(
+        capabilities_resp.hdr.chksum,
+        0x0,
+        &capabilities_resp.as_bytes()[core::mem::size_of_val(&capabilities_resp.hdr.chksum)..],
+    ));
+    assert_eq!(
+        capabilities_resp.hdr.fips_status,
+        MailboxRespHeader::FIPS_STATUS_APPROVED
+    );
+
+    let caps = Capabilities::try_from(capabilities_resp.capabilities.as_bytes()).unwrap();
+    assert!(caps.contains(
+        Capabilities::FMC_BASE
+            | Capabilities::RT_BASE
+            | Capabilities::RT_DPE_P384_SHA384
+            | Capabilities::RT_PCR_QUOTE
+            | Capabilities::RT_LMS_VERIFY
+            | Capabilities::RT_SHA_DIGEST
+            | Capabilities::RT_SHA_STREAM
+            | Capabilities::RT_PAUSER_POLICY
+            | Capabilities::RT_AUTH_MANIFEST
+            | Capabilities::RT_EVENT_LOG
+            | Capabilities::RT_BOOT_TIMELINE
+            // APP_WITH_UART is built with fips_self_test and test_only_commands
+            | Capabilities::RT_FIPS_SELF_TEST
+            | Capabilities::RT_TEST_ONLY_COMMANDS
+    ));
+    assert!(!caps.contains(Capabilities::ROM_BASE));
+    assert!(!caps.contains(Capabilities::RT_DPE_P256_SHA256));
+
+    assert_eq!(model.capabilities().unwrap(), caps);
+}
@@ -6,12 +6,13 @@ use caliptra_builder::{
     ImageOptions,
 };
 use caliptra_common::mailbox_api::{
-    CommandId, EcdsaVerifyReq, FipsVersionResp, FwInfoResp, GetIdevCertReq, GetIdevCertResp,
-    GetIdevInfoResp, InvokeDpeReq, InvokeDpeResp, MailboxReq, MailboxReqHeader, MailboxRespHeader,
-    PopulateIdevCertReq, StashMeasurementReq, StashMeasurementResp,
+    ChunkedResp, CommandId, EcdsaVerifyReq, FipsVersionResp, FwInfoResp, GetCertChainReq,
+    GetIdevCertReq, GetIdevCertResp, GetIdevInfoResp, InvokeDpeReq, InvokeDpeResp, MailboxReq,
+    MailboxReqHeader, MailboxRespHeader, PopulateIdevCertReq, ResponseVarSize, StashMeasurementReq,
+    StashMeasurementResp,
 };
 use caliptra_drivers::{CaliptraError, Ecc384PubKey};
-use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError, ShaAccMode};
+use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams, ModelError, ShaAccMode};
 use caliptra_runtime::{
     FipsVersionCmd, InvokeDpeCmd, RtBootStatus, DPE_SUPPORT, VENDOR_ID, VENDOR_SKU,
 };
@@ -37,10 +38,10 @@ use openssl::{
     ec::{EcGroup, EcKey},
     ecdsa::EcdsaSig,
     nid::Nid,
-    pkey::{PKey, Private},
+    pkey::{PKey, Private, Public},
     stack::Stack,
     x509::{
-        store::X509StoreBuilder, verify::X509VerifyFlags, X509Builder, X509StoreContext,
+        store::X509StoreBuilder, verify::X509VerifyFlags, X509Builder, X509Req, X509StoreContext,
         X509VerifyResult, X509,
     },
 };
@@ -93,6 +94,45 @@ fn test_update() {
     assert_eq!(fw_rev[1], 0xaabbccdd);
 }
 
+#[test]
+fn test_update_invalid_image() {
+    let image_options = ImageOptions {
+        app_version: 0xaabbccdd,
+        ..Default::default()
+    };
+    let mut image =
+        caliptra_builder::build_and_sign_image(&FMC_WITH_UART, &APP_WITH_UART, image_options)
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+    // Corrupt the runtime image
+    *image.last_mut().unwrap() ^= 0xff;
+
+    let mut model = run_rt_test(None, None, None);
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+    let fw_rev = model.soc_ifc().cptra_fw_rev_id().read();
+
+    // The image is rejected by runtime without resetting
+    assert_eq!(
+        model.mailbox_execute(u32::from(CommandId::FIRMWARE_LOAD), &image),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH.into()
+        ))
+    );
+
+    // The current runtime keeps handling commands
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::FW_INFO), &[]),
+    };
+    model
+        .mailbox_execute(u32::from(CommandId::FW_INFO), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    assert_eq!(model.soc_ifc().cptra_fw_rev_id().read(), fw_rev);
+}
+
 #[test]
 fn test_boot() {
     let mut model = run_rt_test(Some(&firmware::runtime_tests::BOOT), None, None);
@@ -450,71 +490,45 @@ fn test_invoke_dpe_get_certificate_chain_cmd() {
     assert_ne!([0u8; 2048], cert_chain.certificate_chain);
 }
 
-fn get_full_cert_chain(model: &mut DefaultHwModel, out: &mut [u8; 4096]) -> usize {
-    // first half
-    let mut data = [0u8; InvokeDpeReq::DATA_MAX_SIZE];
-    let get_cert_chain_cmd = GetCertificateChainCmd {
-        offset: 0,
-        size: 2048,
-    };
-    let cmd_hdr = CommandHdr::new_for_test(Command::GET_CERTIFICATE_CHAIN);
-    let cmd_hdr_buf = cmd_hdr.as_bytes();
-    data[..cmd_hdr_buf.len()].copy_from_slice(cmd_hdr_buf);
-    let dpe_cmd_buf = get_cert_chain_cmd.as_bytes();
-    data[cmd_hdr_buf.len()..cmd_hdr_buf.len() + dpe_cmd_buf.len()].copy_from_slice(dpe_cmd_buf);
-    let mut cmd = MailboxReq::InvokeDpeCommand(InvokeDpeReq {
-        hdr: MailboxReqHeader { chksum: 0 },
-        data,
-        data_size: (cmd_hdr_buf.len() + dpe_cmd_buf.len()) as u32,
-    });
-    cmd.populate_chksum().unwrap();
-
-    let resp = model
-        .mailbox_execute(u32::from(CommandId::INVOKE_DPE), cmd.as_bytes().unwrap())
-        .unwrap()
-        .expect("We should have received a response");
-
-    let mut resp_hdr = InvokeDpeResp::default();
-    resp_hdr.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
-
-    let cert_chunk_1 =
-        GetCertificateChainResp::read_from(&resp_hdr.data[..resp_hdr.data_size as usize]).unwrap();
-    out[..cert_chunk_1.certificate_size as usize]
-        .copy_from_slice(&cert_chunk_1.certificate_chain[..cert_chunk_1.certificate_size as usize]);
-
-    // second half
-    let mut data = [0u8; InvokeDpeReq::DATA_MAX_SIZE];
-    let get_cert_chain_cmd = GetCertificateChainCmd {
-        offset: cert_chunk_1.certificate_size,
-        size: 2048,
-    };
-    let cmd_hdr = CommandHdr::new_for_test(Command::GET_CERTIFICATE_CHAIN);
-    let cmd_hdr_buf = cmd_hdr.as_bytes();
-    data[..cmd_hdr_buf.len()].copy_from_slice(cmd_hdr_buf);
-    let dpe_cmd_buf = get_cert_chain_cmd.as_bytes();
-    data[cmd_hdr_buf.len()..cmd_hdr_buf.len() + dpe_cmd_buf.len()].copy_from_slice(dpe_cmd_buf);
-    let mut cmd = MailboxReq::InvokeDpeCommand(InvokeDpeReq {
-        hdr: MailboxReqHeader { chksum: 0 },
-        data,
-        data_size: (cmd_hdr_buf.len() + dpe_cmd_buf.len()) as u32,
-    });
-    cmd.populate_chksum().unwrap();
-
-    let resp = model
-        .mailbox_execute(u32::from(CommandId::INVOKE_DPE), cmd.as_bytes().unwrap())
-        .unwrap()
-        .expect("We should have received a response");
+/// Reads the cert chain through DPE, which returns it in pieces of up to
+/// 2048 bytes.
+fn get_dpe_cert_chain(model: &mut DefaultHwModel) -> Vec<u8> {
+    let mut cert_chain = vec![];
+    loop {
+        let mut data = [0u8; InvokeDpeReq::DATA_MAX_SIZE];
+        let get_cert_chain_cmd = GetCertificateChainCmd {
+            offset: cert_chain.len() as u32,
+            size: 2048,
+        };
+        let cmd_hdr = CommandHdr::new_for_test(Command::GET_CERTIFICATE_CHAIN);
+        let cmd_hdr_buf = cmd_hdr.as_bytes();
+        data[..cmd_hdr_buf.len()].copy_from_slice(cmd_hdr_buf);
+        let dpe_cmd_buf = get_cert_chain_cmd.as_bytes();
+        data[cmd_hdr_buf.len()..cmd_hdr_buf.len() + dpe_cmd_buf.len()].copy_from_slice(dpe_cmd_buf);
+        let mut cmd = MailboxReq::InvokeDpeCommand(InvokeDpeReq {
+            hdr: MailboxReqHeader { chksum: 0 },
+            data,
+            data_size: (cmd_hdr_buf.len() + dpe_cmd_buf.len()) as u32,
+        });
+        cmd.populate_chksum().unwrap();
 
-    let mut resp_hdr = InvokeDpeResp::default();
-    resp_hdr.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
+        let resp = model
+            .mailbox_execute(u32::from(CommandId::INVOKE_DPE), cmd.as_bytes().unwrap())
+            .unwrap()
+            .expect("We should have received a response");
 
-    let cert_chunk_2 =
-        GetCertificateChainResp::read_from(&resp_hdr.data[..resp_hdr.data_size as usize]).unwrap();
-    out[cert_chunk_1.certificate_size as usize
-        ..cert_chunk_1.certificate_size as usize + cert_chunk_2.certificate_size as usize]
-        .copy_from_slice(&cert_chunk_2.certificate_chain[..cert_chunk_2.certificate_size as usize]);
+        let mut resp_hdr = InvokeDpeResp::default();
+        resp_hdr.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
 
-    cert_chunk_1.certificate_size as usize + cert_chunk_2.certificate_size as usize
+        let chunk =
+            GetCertificateChainResp::read_from(&resp_hdr.data[..resp_hdr.data_size as usize])
+                .unwrap();
+        let size = chunk.certificate_size as usize;
+        cert_chain.extend_from_slice(&chunk.certificate_chain[..size]);
+        if size < get_cert_chain_cmd.size as usize {
+            return cert_chain;
+        }
+    }
 }
 
 // Will panic if any of the cert chain chunks is not a valid X.509 cert
@@ -537,9 +551,7 @@ fn test_populate_idev_cert_cmd() {
         m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
     });
 
-    let mut cert_chain_without_idev_cert = [0u8; 4096];
-    let cert_chain_len_without_idev_cert =
-        get_full_cert_chain(&mut model, &mut cert_chain_without_idev_cert);
+    let cert_chain_without_idev_cert = model.get_cert_chain().unwrap();
 
     // generate test idev cert
     let ec_group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
@@ -568,23 +580,21 @@ fn test_populate_idev_cert_cmd() {
         .unwrap()
         .expect("We should have received a response");
 
-    let mut cert_chain_with_idev_cert = [0u8; 4096];
-    let cert_chain_len_with_idev_cert =
-        get_full_cert_chain(&mut model, &mut cert_chain_with_idev_cert);
+    let cert_chain_with_idev_cert = model.get_cert_chain().unwrap();
 
     // read idev cert from prefix of cert chain and parse it as X509
-    let idev_len = cert_chain_len_with_idev_cert - cert_chain_len_without_idev_cert;
+    let idev_len = cert_chain_with_idev_cert.len() - cert_chain_without_idev_cert.len();
     let idev_cert = X509::from_der(&cert_chain_with_idev_cert[..idev_len]).unwrap();
     assert_eq!(idev_cert, cert);
 
     // ensure rest of cert chain is not corrupted
     assert_eq!(
-        cert_chain_without_idev_cert[..cert_chain_len_without_idev_cert],
-        cert_chain_with_idev_cert[idev_len..cert_chain_len_with_idev_cert]
+        cert_chain_without_idev_cert,
+        cert_chain_with_idev_cert[idev_len..]
     );
     parse_cert_chain(
         &cert_chain_with_idev_cert[idev_len..],
-        cert_chain_len_with_idev_cert - idev_len,
+        cert_chain_with_idev_cert.len() - idev_len,
         3,
     );
 }
@@ -1162,24 +1172,6 @@ fn test_unimplemented_cmds() {
 
     model.step_until(|m| m.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
 
-    let expected_err = Err(ModelError::MailboxCmdFailed(0xe0002));
-
-    // GET_IDEV_CSR
-    let payload = MailboxReqHeader {
-        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_IDEV_CSR), &[]),
-    };
-
-    let mut resp = model.mailbox_execute(u32::from(CommandId::GET_IDEV_CSR), payload.as_bytes());
-    assert_eq!(resp, expected_err);
-
-    // GET_LDEV_CERT
-    let payload = MailboxReqHeader {
-        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_LDEV_CERT), &[]),
-    };
-
-    resp = model.mailbox_execute(u32::from(CommandId::GET_LDEV_CERT), payload.as_bytes());
-    assert_eq!(resp, expected_err);
-
     // Send something that is not a valid RT command.
     let expected_err = Err(ModelError::MailboxCmdFailed(0xe0002));
     const INVALID_CMD: u32 = 0xAABBCCDD;
@@ -1207,6 +1199,195 @@ fn test_idev_id_info() {
     GetIdevInfoResp::read_from(resp.as_slice()).unwrap();
 }
 
+fn idev_pub_key(model: &mut DefaultHwModel) -> EcKey<Public> {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_IDEV_INFO), &[]),
+    };
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_IDEV_INFO), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    let info = GetIdevInfoResp::read_from(resp.as_slice()).unwrap();
+
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    EcKey::from_public_key_affine_coordinates(
+        &group,
+        &BigNum::from_slice(&info.idev_pub_x).unwrap(),
+        &BigNum::from_slice(&info.idev_pub_y).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_get_idev_csr() {
+    const GENERATE_IDEVID_CSR: u32 = 1;
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    let image = caliptra_builder::build_and_sign_image(
+        &FMC_WITH_UART,
+        &APP_WITH_UART,
+        ImageOptions::default(),
+    )
+    .unwrap()
+    .to_bytes()
+    .unwrap();
+
+    let mut model = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        initial_dbg_manuf_service_reg: GENERATE_IDEVID_CSR,
+        ..Default::default()
+    })
+    .unwrap();
+
+    // ROM uploads the CSR before asking for firmware.
+    let mut txn = model.wait_for_mailbox_receive().unwrap();
+    let uploaded_csr = std::mem::take(&mut txn.req.data);
+    txn.respond_success();
+
+    model.step_until(|m| m.ready_for_fw());
+    model.upload_firmware(&image).unwrap();
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let resp = model.get_idev_csr().unwrap();
+    assert_eq!(resp.data().unwrap(), uploaded_csr.as_slice());
+    let csr = X509Req::from_der(resp.data().unwrap()).unwrap();
+
+    // The CSR is self-signed by the IDevID key.
+    let idev_key = PKey::from_ec_key(idev_pub_key(&mut model)).unwrap();
+    assert!(csr.public_key().unwrap().public_eq(&idev_key));
+    assert!(csr.verify(&idev_key).unwrap());
+}
+
+#[test]
+fn test_get_idev_csr_not_requested() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // ROM only generates the CSR when requested.
+    assert_eq!(
+        model.get_idev_csr().err(),
+        Some(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_IDEV_CSR_NOT_AVAILABLE.into()
+        ))
+    );
+}
+
+#[test]
+fn test_get_ldev_cert() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let resp = model.get_ldev_cert().unwrap();
+    let ldev_cert = X509::from_der(resp.data().unwrap()).unwrap();
+
+    // The LDevID cert is issued by the IDevID key.
+    let idev_key = PKey::from_ec_key(idev_pub_key(&mut model)).unwrap();
+    assert!(ldev_cert.verify(&idev_key).unwrap());
+
+    // Same cert as the one at the start of the chain.
+    let cert_chain = model.get_cert_chain().unwrap();
+    let chain_ldev_cert = X509::from_der(&cert_chain).unwrap();
+    assert_eq!(
+        ldev_cert.to_der().unwrap(),
+        chain_ldev_cert.to_der().unwrap()
+    );
+}
+
+#[test]
+fn test_get_alias_certs() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let ldev_resp = model.get_ldev_cert().unwrap();
+    let ldev_cert = X509::from_der(ldev_resp.data().unwrap()).unwrap();
+
+    let fmc_resp = model.get_fmc_alias_cert().unwrap();
+    let fmc_alias_cert = X509::from_der(fmc_resp.data().unwrap()).unwrap();
+    assert!(fmc_alias_cert
+        .verify(&ldev_cert.public_key().unwrap())
+        .unwrap());
+
+    let rt_resp = model.get_rt_alias_cert().unwrap();
+    let rt_alias_cert = X509::from_der(rt_resp.data().unwrap()).unwrap();
+    assert!(rt_alias_cert
+        .verify(&fmc_alias_cert.public_key().unwrap())
+        .unwrap());
+}
+
+#[test]
+fn test_get_cert_chain() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // The chain is LDevID || FMC alias || RT alias.
+    let mut expected = vec![];
+    expected.extend_from_slice(model.get_ldev_cert().unwrap().data().unwrap());
+    expected.extend_from_slice(model.get_fmc_alias_cert().unwrap().data().unwrap());
+    expected.extend_from_slice(model.get_rt_alias_cert().unwrap().data().unwrap());
+
+    let cert_chain = model.get_cert_chain().unwrap();
+    assert_eq!(cert_chain, expected);
+
+    // Matches the chain served through DPE.
+    assert_eq!(cert_chain, get_dpe_cert_chain(&mut model));
+
+    // Every chunk describes the whole chain.
+    let mut cmd = MailboxReq::GetCertChain(GetCertChainReq::default());
+    cmd.populate_chksum().unwrap();
+    let resp = model
+        .mailbox_execute(
+            u32::from(CommandId::GET_CERT_CHAIN),
+            cmd.as_bytes().unwrap(),
+        )
+        .unwrap()
+        .expect("We should have received a response");
+    let mut first = ChunkedResp::default();
+    first.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
+    assert_eq!(first.total_size as usize, cert_chain.len());
+    assert_eq!(first.offset, 0);
+    assert_eq!(first.flags, ChunkedResp::FLAG_MORE);
+    assert_eq!(
+        first.payload_chksum,
+        caliptra_common::checksum::calc_checksum(0, &cert_chain)
+    );
+    assert_eq!(
+        first.data().unwrap(),
+        &cert_chain[..ChunkedResp::DATA_MAX_SIZE]
+    );
+
+    // Reading past the end of the chain fails.
+    let mut cmd = MailboxReq::GetCertChain(GetCertChainReq {
+        hdr: MailboxReqHeader::default(),
+        offset: cert_chain.len() as u32 + 1,
+    });
+    cmd.populate_chksum().unwrap();
+    assert_eq!(
+        model.mailbox_execute(
+            u32::from(CommandId::GET_CERT_CHAIN),
+            cmd.as_bytes().unwrap(),
+        ),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS.into()
+        ))
+    );
+}
+
 fn generate_test_x509_cert(ec_key: PKey<Private>) -> X509 {
     let mut cert_builder = X509Builder::new().unwrap();
     cert_builder.set_version(2).unwrap();
@@ -0,0 +1,207 @@
+// Licensed under the Apache-2.0 license.
+
+use crate::common::run_rt_test;
+use caliptra_drivers::CaliptraError;
+use caliptra_hw_model::{HwModel, ModelError};
+use caliptra_image_fake_keys::{VENDOR_LMS_KEY_0_PRIVATE, VENDOR_LMS_KEY_0_PUBLIC};
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_runtime::RtBootStatus;
+use zerocopy::AsBytes;
+
+// Second-level LMS_SHA256_M32_H5 / LMOTS_SHA256_N32_W8 signature from
+// RFC 8554 Appendix F, Test Case 1.
+const RFC8554_MESSAGE: &[u8] =
+    b"The powers not delegated to the United States by the Constitution, \
+nor prohibited by it to the States, are reserved to the States respectively, or to the people.\n";
+const RFC8554_PUB_KEY: [u8; 56] = [
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0xd2, 0xf1, 0x4f, 0xf6, 0x34, 0x6a, 0xf9, 0x64,
+    0x56, 0x9f, 0x7d, 0x6c, 0xb8, 0x80, 0xa1, 0xb6, 0x6c, 0x50, 0x04, 0x91, 0x7d, 0xa6, 0xea, 0xfe,
+    0x4d, 0x9e, 0xf6, 0xc6, 0x40, 0x7b, 0x3d, 0xb0, 0xe5, 0x48, 0x5b, 0x12, 0x2d, 0x9e, 0xbe, 0x15,
+    0xcd, 0xa9, 0x3c, 0xfe, 0xc5, 0x82, 0xd7, 0xab,
+];
+const RFC8554_SIGNATURE: [u8; 1292] = [
+    0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x04, 0x07, 0x03, 0xc4, 0x91, 0xe7, 0x55, 0x8b, 0x35,
+    0x01, 0x1e, 0xce, 0x35, 0x92, 0xea, 0xa5, 0xda, 0x4d, 0x91, 0x87, 0x86, 0x77, 0x12, 0x33, 0xe8,
+    0x35, 0x3b, 0xc4, 0xf6, 0x23, 0x23, 0x18, 0x5c, 0x95, 0xca, 0xe0, 0x5b, 0x89, 0x9e, 0x35, 0xdf,
+    0xfd, 0x71, 0x70, 0x54, 0x70, 0x62, 0x09, 0x98, 0x8e, 0xbf, 0xdf, 0x6e, 0x37, 0x96, 0x0b, 0xb5,
+    0xc3, 0x8d, 0x76, 0x57, 0xe8, 0xbf, 0xfe, 0xef, 0x9b, 0xc0, 0x42, 0xda, 0x4b, 0x45, 0x25, 0x65,
+    0x04, 0x85, 0xc6, 0x6d, 0x0c, 0xe1, 0x9b, 0x31, 0x75, 0x87, 0xc6, 0xba, 0x4b, 0xff, 0xcc, 0x42,
+    0x8e, 0x25, 0xd0, 0x89, 0x31, 0xe7, 0x2d, 0xfb, 0x6a, 0x12, 0x0c, 0x56, 0x12, 0x34, 0x42, 0x58,
+    0xb8, 0x5e, 0xfd, 0xb7, 0xdb, 0x1d, 0xb9, 0xe1, 0x86, 0x5a, 0x73, 0xca, 0xf9, 0x65, 0x57, 0xeb,
+    0x39, 0xed, 0x3e, 0x3f, 0x42, 0x69, 0x33, 0xac, 0x9e, 0xed, 0xdb, 0x03, 0xa1, 0xd2, 0x37, 0x4a,
+    0xf7, 0xbf, 0x77, 0x18, 0x55, 0x77, 0x45, 0x62, 0x37, 0xf9, 0xde, 0x2d, 0x60, 0x11, 0x3c, 0x23,
+    0xf8, 0x46, 0xdf, 0x26, 0xfa, 0x94, 0x20, 0x08, 0xa6, 0x98, 0x99, 0x4c, 0x08, 0x27, 0xd9, 0x0e,
+    0x86, 0xd4, 0x3e, 0x0d, 0xf7, 0xf4, 0xbf, 0xcd, 0xb0, 0x9b, 0x86, 0xa3, 0x73, 0xb9, 0x82, 0x88,
+    0xb7, 0x09, 0x4a, 0xd8, 0x1a, 0x01, 0x85, 0xac, 0x10, 0x0e, 0x4f, 0x2c, 0x5f, 0xc3, 0x8c, 0x00,
+    0x3c, 0x1a, 0xb6, 0xfe, 0xa4, 0x79, 0xeb, 0x2f, 0x5e, 0xbe, 0x48, 0xf5, 0x84, 0xd7, 0x15, 0x9b,
+    0x8a, 0xda, 0x03, 0x58, 0x6e, 0x65, 0xad, 0x9c, 0x96, 0x9f, 0x6a, 0xec, 0xbf, 0xe4, 0x4c, 0xf3,
+    0x56, 0x88, 0x8a, 0x7b, 0x15, 0xa3, 0xff, 0x07, 0x4f, 0x77, 0x17, 0x60, 0xb2, 0x6f, 0x9c, 0x04,
+    0x88, 0x4e, 0xe1, 0xfa, 0xa3, 0x29, 0xfb, 0xf4, 0xe6, 0x1a, 0xf2, 0x3a, 0xee, 0x7f, 0xa5, 0xd4,
+    0xd9, 0xa5, 0xdf, 0xcf, 0x43, 0xc4, 0xc2, 0x6c, 0xe8, 0xae, 0xa2, 0xce, 0x8a, 0x29, 0x90, 0xd7,
+    0xba, 0x7b, 0x57, 0x10, 0x8b, 0x47, 0xda, 0xbf, 0xbe, 0xad, 0xb2, 0xb2, 0x5b, 0x3c, 0xac, 0xc1,
+    0xac, 0x0c, 0xef, 0x34, 0x6c, 0xbb, 0x90, 0xfb, 0x04, 0x4b, 0xee, 0xe4, 0xfa, 0xc2, 0x60, 0x3a,
+    0x44, 0x2b, 0xdf, 0x7e, 0x50, 0x72, 0x43, 0xb7, 0x31, 0x9c, 0x99, 0x44, 0xb1, 0x58, 0x6e, 0x89,
+    0x9d, 0x43, 0x1c, 0x7f, 0x91, 0xbc, 0xcc, 0xc8, 0x69, 0x0d, 0xbf, 0x59, 0xb2, 0x83, 0x86, 0xb2,
+    0x31, 0x5f, 0x3d, 0x36, 0xef, 0x2e, 0xaa, 0x3c, 0xf3, 0x0b, 0x2b, 0x51, 0xf4, 0x8b, 0x71, 0xb0,
+    0x03, 0xdf, 0xb0, 0x82, 0x49, 0x48, 0x42, 0x01, 0x04, 0x3f, 0x65, 0xf5, 0xa3, 0xef, 0x6b, 0xbd,
+    0x61, 0xdd, 0xfe, 0xe8, 0x1a, 0xca, 0x9c, 0xe6, 0x00, 0x81, 0x26, 0x2a, 0x00, 0x00, 0x04, 0x80,
+    0xdc, 0xbc, 0x9a, 0x3d, 0xa6, 0xfb, 0xef, 0x5c, 0x1c, 0x0a, 0x55, 0xe4, 0x8a, 0x0e, 0x72, 0x9f,
+    0x91, 0x84, 0xfc, 0xb1, 0x40, 0x7c, 0x31, 0x52, 0x9d, 0xb2, 0x68, 0xf6, 0xfe, 0x50, 0x03, 0x2a,
+    0x36, 0x3c, 0x98, 0x01, 0x30, 0x68, 0x37, 0xfa, 0xfa, 0xbd, 0xf9, 0x57, 0xfd, 0x97, 0xea, 0xfc,
+    0x80, 0xdb, 0xd1, 0x65, 0xe4, 0x35, 0xd0, 0xe2, 0xdf, 0xd8, 0x36, 0xa2, 0x8b, 0x35, 0x40, 0x23,
+    0x92, 0x4b, 0x6f, 0xb7, 0xe4, 0x8b, 0xc0, 0xb3, 0xed, 0x95, 0xee, 0xa6, 0x4c, 0x2d, 0x40, 0x2f,
+    0x4d, 0x73, 0x4c, 0x8d, 0xc2, 0x6f, 0x3a, 0xc5, 0x91, 0x82, 0x5d, 0xae, 0xf0, 0x1e, 0xae, 0x3c,
+    0x38, 0xe3, 0x32, 0x8d, 0x00, 0xa7, 0x7d, 0xc6, 0x57, 0x03, 0x4f, 0x28, 0x7c, 0xcb, 0x0f, 0x0e,
+    0x1c, 0x9a, 0x7c, 0xbd, 0xc8, 0x28, 0xf6, 0x27, 0x20, 0x5e, 0x47, 0x37, 0xb8, 0x4b, 0x58, 0x37,
+    0x65, 0x51, 0xd4, 0x4c, 0x12, 0xc3, 0xc2, 0x15, 0xc8, 0x12, 0xa0, 0x97, 0x07, 0x89, 0xc8, 0x3d,
+    0xe5, 0x1d, 0x6a, 0xd7, 0x87, 0x27, 0x19, 0x63, 0x32, 0x7f, 0x0a, 0x5f, 0xbb, 0x6b, 0x59, 0x07,
+    0xde, 0xc0, 0x2c, 0x9a, 0x90, 0x93, 0x4a, 0xf5, 0xa1, 0xc6, 0x3b, 0x72, 0xc8, 0x26, 0x53, 0x60,
+    0x5d, 0x1d, 0xcc, 0xe5, 0x15, 0x96, 0xb3, 0xc2, 0xb4, 0x56, 0x96, 0x68, 0x9f, 0x2e, 0xb3, 0x82,
+    0x00, 0x74, 0x97, 0x55, 0x76, 0x92, 0xca, 0xac, 0x4d, 0x57, 0xb5, 0xde, 0x9f, 0x55, 0x69, 0xbc,
+    0x2a, 0xd0, 0x13, 0x7f, 0xd4, 0x7f, 0xb4, 0x7e, 0x66, 0x4f, 0xcb, 0x6d, 0xb4, 0x97, 0x1f, 0x5b,
+    0x3e, 0x07, 0xac, 0xed, 0xa9, 0xac, 0x13, 0x0e, 0x9f, 0x38, 0x18, 0x2d, 0xe9, 0x94, 0xcf, 0xf1,
+    0x92, 0xec, 0x0e, 0x82, 0xfd, 0x6d, 0x4c, 0xb7, 0xf3, 0xfe, 0x00, 0x81, 0x25, 0x89, 0xb7, 0xa7,
+    0xce, 0x51, 0x54, 0x40, 0x45, 0x64, 0x33, 0x01, 0x6b, 0x84, 0xa5, 0x9b, 0xec, 0x66, 0x19, 0xa1,
+    0xc6, 0xc0, 0xb3, 0x7d, 0xd1, 0x45, 0x0e, 0xd4, 0xf2, 0xd8, 0xb5, 0x84, 0x41, 0x0c, 0xed, 0xa8,
+    0x02, 0x5f, 0x5d, 0x2d, 0x8d, 0xd0, 0xd2, 0x17, 0x6f, 0xc1, 0xcf, 0x2c, 0xc0, 0x6f, 0xa8, 0xc8,
+    0x2b, 0xed, 0x4d, 0x94, 0x4e, 0x71, 0x33, 0x9e, 0xce, 0x78, 0x0f, 0xd0, 0x25, 0xbd, 0x41, 0xec,
+    0x34, 0xeb, 0xff, 0x9d, 0x42, 0x70, 0xa3, 0x22, 0x4e, 0x01, 0x9f, 0xcb, 0x44, 0x44, 0x74, 0xd4,
+    0x82, 0xfd, 0x2d, 0xbe, 0x75, 0xef, 0xb2, 0x03, 0x89, 0xcc, 0x10, 0xcd, 0x60, 0x0a, 0xbb, 0x54,
+    0xc4, 0x7e, 0xde, 0x93, 0xe0, 0x8c, 0x11, 0x4e, 0xdb, 0x04, 0x11, 0x7d, 0x71, 0x4d, 0xc1, 0xd5,
+    0x25, 0xe1, 0x1b, 0xed, 0x87, 0x56, 0x19, 0x2f, 0x92, 0x9d, 0x15, 0x46, 0x2b, 0x93, 0x9f, 0xf3,
+    0xf5, 0x2f, 0x22, 0x52, 0xda, 0x2e, 0xd6, 0x4d, 0x8f, 0xae, 0x88, 0x81, 0x8b, 0x1e, 0xfa, 0x2c,
+    0x7b, 0x08, 0xc8, 0x79, 0x4f, 0xb1, 0xb2, 0x14, 0xaa, 0x23, 0x3d, 0xb3, 0x16, 0x28, 0x33, 0x14,
+    0x1e, 0xa4, 0x38, 0x3f, 0x1a, 0x6f, 0x12, 0x0b, 0xe1, 0xdb, 0x82, 0xce, 0x36, 0x30, 0xb3, 0x42,
+    0x91, 0x14, 0x46, 0x31, 0x57, 0xa6, 0x4e, 0x91, 0x23, 0x4d, 0x47, 0x5e, 0x2f, 0x79, 0xcb, 0xf0,
+    0x5e, 0x4d, 0xb6, 0xa9, 0x40, 0x7d, 0x72, 0xc6, 0xbf, 0xf7, 0xd1, 0x19, 0x8b, 0x5c, 0x4d, 0x6a,
+    0xad, 0x28, 0x31, 0xdb, 0x61, 0x27, 0x49, 0x93, 0x71, 0x5a, 0x01, 0x82, 0xc7, 0xdc, 0x80, 0x89,
+    0xe3, 0x2c, 0x85, 0x31, 0xde, 0xed, 0x4f, 0x74, 0x31, 0xc0, 0x7c, 0x02, 0x19, 0x5e, 0xba, 0x2e,
+    0xf9, 0x1e, 0xfb, 0x56, 0x13, 0xc3, 0x7a, 0xf7, 0xae, 0x0c, 0x06, 0x6b, 0xab, 0xc6, 0x93, 0x69,
+    0x70, 0x0e, 0x1d, 0xd2, 0x6e, 0xdd, 0xc0, 0xd2, 0x16, 0xc7, 0x81, 0xd5, 0x6e, 0x4c, 0xe4, 0x7e,
+    0x33, 0x03, 0xfa, 0x73, 0x00, 0x7f, 0xf7, 0xb9, 0x49, 0xef, 0x23, 0xbe, 0x2a, 0xa4, 0xdb, 0xf2,
+    0x52, 0x06, 0xfe, 0x45, 0xc2, 0x0d, 0xd8, 0x88, 0x39, 0x5b, 0x25, 0x26, 0x39, 0x1a, 0x72, 0x49,
+    0x96, 0xa4, 0x41, 0x56, 0xbe, 0xac, 0x80, 0x82, 0x12, 0x85, 0x87, 0x92, 0xbf, 0x8e, 0x74, 0xcb,
+    0xa4, 0x9d, 0xee, 0x5e, 0x88, 0x12, 0xe0, 0x19, 0xda, 0x87, 0x45, 0x4b, 0xff, 0x9e, 0x84, 0x7e,
+    0xd8, 0x3d, 0xb0, 0x7a, 0xf3, 0x13, 0x74, 0x30, 0x82, 0xf8, 0x80, 0xa2, 0x78, 0xf6, 0x82, 0xc2,
+    0xbd, 0x0a, 0xd6, 0x88, 0x7c, 0xb5, 0x9f, 0x65, 0x2e, 0x15, 0x59, 0x87, 0xd6, 0x1b, 0xbf, 0x6a,
+    0x88, 0xd3, 0x6e, 0xe9, 0x3b, 0x60, 0x72, 0xe6, 0x65, 0x6d, 0x9c, 0xcb, 0xaa, 0xe3, 0xd6, 0x55,
+    0x85, 0x2e, 0x38, 0xde, 0xb3, 0xa2, 0xdc, 0xf8, 0x05, 0x8d, 0xc9, 0xfb, 0x6f, 0x2a, 0xb3, 0xd3,
+    0xb3, 0x53, 0x9e, 0xb7, 0x7b, 0x24, 0x8a, 0x66, 0x10, 0x91, 0xd0, 0x5e, 0xb6, 0xe2, 0xf2, 0x97,
+    0x77, 0x4f, 0xe6, 0x05, 0x35, 0x98, 0x45, 0x7c, 0xc6, 0x19, 0x08, 0x31, 0x8d, 0xe4, 0xb8, 0x26,
+    0xf0, 0xfc, 0x86, 0xd4, 0xbb, 0x11, 0x7d, 0x33, 0xe8, 0x65, 0xaa, 0x80, 0x50, 0x09, 0xcc, 0x29,
+    0x18, 0xd9, 0xc2, 0xf8, 0x40, 0xc4, 0xda, 0x43, 0xa7, 0x03, 0xad, 0x9f, 0x5b, 0x58, 0x06, 0x16,
+    0x3d, 0x71, 0x61, 0x69, 0x6b, 0x5a, 0x0a, 0xdc, 0x00, 0x00, 0x00, 0x05, 0xd5, 0xc0, 0xd1, 0xbe,
+    0xbb, 0x06, 0x04, 0x8e, 0xd6, 0xfe, 0x2e, 0xf2, 0xc6, 0xce, 0xf3, 0x05, 0xb3, 0xed, 0x63, 0x39,
+    0x41, 0xeb, 0xc8, 0xb3, 0xbe, 0xc9, 0x73, 0x87, 0x54, 0xcd, 0xdd, 0x60, 0xe1, 0x92, 0x0a, 0xda,
+    0x52, 0xf4, 0x3d, 0x05, 0x5b, 0x50, 0x31, 0xce, 0xe6, 0x19, 0x25, 0x20, 0xd6, 0xa5, 0x11, 0x55,
+    0x14, 0x85, 0x1c, 0xe7, 0xfd, 0x44, 0x8d, 0x4a, 0x39, 0xfa, 0xe2, 0xab, 0x23, 0x35, 0xb5, 0x25,
+    0xf4, 0x84, 0xe9, 0xb4, 0x0d, 0x6a, 0x4a, 0x96, 0x93, 0x94, 0x84, 0x3b, 0xdc, 0xf6, 0xd1, 0x4c,
+    0x48, 0xe8, 0x01, 0x5e, 0x08, 0xab, 0x92, 0x66, 0x2c, 0x05, 0xc6, 0xe9, 0xf9, 0x0b, 0x65, 0xa7,
+    0xa6, 0x20, 0x16, 0x89, 0x99, 0x9f, 0x32, 0xbf, 0xd3, 0x68, 0xe5, 0xe3, 0xec, 0x9c, 0xb7, 0x0a,
+    0xc7, 0xb8, 0x39, 0x90, 0x03, 0xf1, 0x75, 0xc4, 0x08, 0x85, 0x08, 0x1a, 0x09, 0xab, 0x30, 0x34,
+    0x91, 0x1f, 0xe1, 0x25, 0x63, 0x10, 0x51, 0xdf, 0x04, 0x08, 0xb3, 0x94, 0x6b, 0x0b, 0xde, 0x79,
+    0x09, 0x11, 0xe8, 0x97, 0x8b, 0xa0, 0x7d, 0xd5, 0x6c, 0x73, 0xe7, 0xee,
+];
+
+#[test]
+fn test_lms_verify_n24() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let digest = [0x5a5a_a5a5u32; 12];
+    let sig = OsslCrypto::default()
+        .lms_sign(&digest, &VENDOR_LMS_KEY_0_PRIVATE)
+        .unwrap();
+    let message: Vec<u8> = digest.iter().flat_map(|w| w.to_be_bytes()).collect();
+
+    model
+        .lms_verify(VENDOR_LMS_KEY_0_PUBLIC.as_bytes(), sig.as_bytes(), &message)
+        .unwrap();
+
+    // A different message must not verify.
+    let mut bad_message = message.clone();
+    bad_message[0] ^= 1;
+    assert_eq!(
+        model.lms_verify(
+            VENDOR_LMS_KEY_0_PUBLIC.as_bytes(),
+            sig.as_bytes(),
+            &bad_message
+        ),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_LMS_VERIFY_FAILED.into()
+        ))
+    );
+
+    // Neither must a tampered signature.
+    let mut bad_sig = sig;
+    bad_sig.ots.y[0][0] = (bad_sig.ots.y[0][0].get() ^ 1).into();
+    assert_eq!(
+        model.lms_verify(
+            VENDOR_LMS_KEY_0_PUBLIC.as_bytes(),
+            bad_sig.as_bytes(),
+            &message
+        ),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_LMS_VERIFY_FAILED.into()
+        ))
+    );
+
+    // The signature size must match the parameter set of the public key.
+    assert_eq!(
+        model.lms_verify(
+            VENDOR_LMS_KEY_0_PUBLIC.as_bytes(),
+            &sig.as_bytes()[..sig.as_bytes().len() - 1],
+            &message
+        ),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS.into()
+        ))
+    );
+}
+
+#[test]
+fn test_lms_verify_n32() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    model
+        .lms_verify(&RFC8554_PUB_KEY, &RFC8554_SIGNATURE, RFC8554_MESSAGE)
+        .unwrap();
+
+    assert_eq!(
+        model.lms_verify(
+            &RFC8554_PUB_KEY,
+            &RFC8554_SIGNATURE,
+            &RFC8554_MESSAGE[..RFC8554_MESSAGE.len() - 1]
+        ),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_LMS_VERIFY_FAILED.into()
+        ))
+    );
+}
+
+#[test]
+fn test_lms_verify_unsupported_algorithm() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // LMOTS_SHA256_N32_W4 signatures don't fit in the mailbox request.
+    let mut pub_key = RFC8554_PUB_KEY;
+    pub_key[4..8].copy_from_slice(&3u32.to_be_bytes());
+    assert_eq!(
+        model.lms_verify(&pub_key, &RFC8554_SIGNATURE, RFC8554_MESSAGE),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_LMS_VERIFY_UNSUPPORTED_ALGORITHM.into()
+        ))
+    );
+}
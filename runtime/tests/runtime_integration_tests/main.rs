@@ -1,7 +1,14 @@
 // Licensed under the Apache-2.0 license
 
+mod auth_manifest;
+mod boot_timeline;
+mod capabilities;
 mod common;
 mod ecdsa;
 mod hmac;
 mod integration_tests;
+mod lms;
+mod pauser_policy;
+mod pcr;
+mod sha;
 mod test_panic_missing;
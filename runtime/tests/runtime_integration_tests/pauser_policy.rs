@@ -0,0 +1,136 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::run_rt_test;
+use caliptra_builder::ImageOptions;
+use caliptra_common::{
+    mailbox_api::{CommandId, MailboxReqHeader, ShaDigestReq},
+    permissions::CommandPermissions,
+};
+use caliptra_drivers::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use caliptra_image_types::{ImagePauserPolicy, ImagePauserPolicyEntry};
+use caliptra_runtime::RtBootStatus;
+use zerocopy::AsBytes;
+
+// PAUSER used by the SoC for mailbox commands in the test models
+const SOC_PAUSER: u32 = 0x1;
+
+fn run_rt_test_with_policy(policy: ImagePauserPolicy) -> DefaultHwModel {
+    let mut opts = ImageOptions::default();
+    opts.vendor_config.pl0_pauser = Some(SOC_PAUSER);
+    opts.vendor_config.pauser_policy = Some(policy);
+
+    let mut model = run_rt_test(None, Some(opts), None);
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+    model
+}
+
+fn execute_no_payload_cmd(model: &mut DefaultHwModel, cmd: CommandId) -> Result<(), ModelError> {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(cmd), &[]),
+    };
+    model
+        .mailbox_execute(u32::from(cmd), payload.as_bytes())
+        .map(|_| ())
+}
+
+#[test]
+fn test_pauser_policy_restricts_commands() {
+    let allowed = CommandPermissions::all()
+        - CommandPermissions::DISABLE_ATTESTATION
+        - CommandPermissions::SHUTDOWN;
+    let mut policy = ImagePauserPolicy {
+        entry_count: 1,
+        ..Default::default()
+    };
+    policy.entries[0] = ImagePauserPolicyEntry {
+        pauser: SOC_PAUSER,
+        allowed_cmds: allowed.to_words(),
+    };
+    let mut model = run_rt_test_with_policy(policy);
+
+    for cmd in [CommandId::DISABLE_ATTESTATION, CommandId::SHUTDOWN] {
+        assert_eq!(
+            execute_no_payload_cmd(&mut model, cmd),
+            Err(ModelError::MailboxCmdFailed(
+                CaliptraError::RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER.into()
+            ))
+        );
+    }
+
+    // Commands without a permission bit are never allowed under a policy.
+    assert_eq!(
+        execute_no_payload_cmd(&mut model, CommandId(0xdead_beef)),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER.into()
+        ))
+    );
+
+    execute_no_payload_cmd(&mut model, CommandId::FW_INFO).unwrap();
+    model.get_pcr_log().unwrap();
+}
+
+#[test]
+fn test_pauser_policy_pauser_without_entry() {
+    let mut policy = ImagePauserPolicy {
+        entry_count: 1,
+        ..Default::default()
+    };
+    policy.entries[0] = ImagePauserPolicyEntry {
+        pauser: SOC_PAUSER + 1,
+        allowed_cmds: CommandPermissions::all().to_words(),
+    };
+    let mut model = run_rt_test_with_policy(policy);
+
+    assert_eq!(
+        execute_no_payload_cmd(&mut model, CommandId::FW_INFO),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER.into()
+        ))
+    );
+}
+
+#[test]
+fn test_no_pauser_policy() {
+    let mut model = run_rt_test(None, None, None);
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    execute_no_payload_cmd(&mut model, CommandId::DISABLE_ATTESTATION).unwrap();
+}
+
+#[test]
+fn test_pauser_policy_sha_extend_requires_extend_pcr() {
+    let allowed = CommandPermissions::all() - CommandPermissions::EXTEND_PCR;
+    let mut policy = ImagePauserPolicy {
+        entry_count: 1,
+        ..Default::default()
+    };
+    policy.entries[0] = ImagePauserPolicyEntry {
+        pauser: SOC_PAUSER,
+        allowed_cmds: allowed.to_words(),
+    };
+    let mut model = run_rt_test_with_policy(policy);
+
+    let not_allowed = Err(ModelError::MailboxCmdFailed(
+        CaliptraError::RUNTIME_CMD_NOT_ALLOWED_FOR_PAUSER.into(),
+    ));
+    assert_eq!(
+        model
+            .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"data", Some(5))
+            .map(|_| ()),
+        not_allowed
+    );
+    assert_eq!(
+        model.sha_init(ShaDigestReq::HASH_ALGORITHM_SHA384, 4, Some(5)),
+        not_allowed
+    );
+
+    // Digests without an extend only need SHA_DIGEST.
+    model
+        .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"data", None)
+        .unwrap();
+}
@@ -0,0 +1,235 @@
+// Licensed under the Apache-2.0 license.
+
+use crate::common::run_rt_test;
+use caliptra_common::{
+    event_log::{EventLogError, EventLogIter, ReplayedPcrs},
+    mailbox_api::{ExtendPcrReq, MailboxReqHeader, StashMeasurementReq},
+};
+use caliptra_drivers::{CaliptraError, MEASUREMENT_MAX_COUNT};
+use caliptra_hw_model::{HwModel, ModelError};
+use caliptra_runtime::RtBootStatus;
+use openssl::{
+    bn::BigNum,
+    ecdsa::EcdsaSig,
+    sha::{sha384, Sha384},
+    x509::X509,
+};
+use zerocopy::AsBytes;
+
+#[test]
+fn test_get_pcr_quote() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let nonce = [0xa5u8; 32];
+    let quote = model.get_pcr_quote(nonce).unwrap();
+    assert_eq!(quote.nonce, nonce);
+
+    // ROM and FMC extend PCR0..PCR3 during boot.
+    for pcr in &quote.pcrs[..4] {
+        assert_ne!(pcr, &[0u8; 48]);
+    }
+
+    // The RT alias cert is the last cert in the chain.
+    let cert_chain = model.get_cert_chain().unwrap();
+    let mut rt_alias_cert = None;
+    let mut i = 0;
+    while i < cert_chain.len() {
+        let cert = X509::from_der(&cert_chain[i..]).unwrap();
+        i += cert.to_der().unwrap().len();
+        rt_alias_cert = Some(cert);
+    }
+    let rt_alias_key = rt_alias_cert.unwrap().public_key().unwrap();
+
+    let mut hasher = Sha384::new();
+    hasher.update(&quote.nonce);
+    hasher.update(quote.pcrs.as_bytes());
+    let digest = hasher.finish();
+
+    let sig = EcdsaSig::from_private_components(
+        BigNum::from_slice(&quote.signature_r).unwrap(),
+        BigNum::from_slice(&quote.signature_s).unwrap(),
+    )
+    .unwrap();
+    assert!(sig
+        .verify(&digest, &rt_alias_key.ec_key().unwrap())
+        .unwrap());
+}
+
+#[test]
+fn test_extend_pcr() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let data = [0x5au8; 48];
+    model
+        .mailbox_execute_req(ExtendPcrReq {
+            hdr: MailboxReqHeader::default(),
+            pcr_idx: 4,
+            data,
+        })
+        .unwrap();
+
+    let mut hasher = Sha384::new();
+    hasher.update(&[0u8; 48]);
+    hasher.update(&data);
+    let expected_pcr = hasher.finish();
+
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    assert_eq!(quote.pcrs[4], expected_pcr);
+
+    for (pcr_idx, expected_err) in [
+        (0, CaliptraError::RUNTIME_EXTEND_PCR_FMC_PCR_RESERVED),
+        (1, CaliptraError::RUNTIME_EXTEND_PCR_FMC_PCR_RESERVED),
+        (2, CaliptraError::RUNTIME_EXTEND_PCR_RT_PCR_RESERVED),
+        (3, CaliptraError::RUNTIME_EXTEND_PCR_RT_PCR_RESERVED),
+        (
+            31,
+            CaliptraError::RUNTIME_EXTEND_PCR_STASH_MEASUREMENT_PCR_RESERVED,
+        ),
+        (32, CaliptraError::RUNTIME_EXTEND_PCR_INVALID_INDEX),
+    ] {
+        assert_eq!(
+            model.mailbox_execute_req(ExtendPcrReq {
+                hdr: MailboxReqHeader::default(),
+                pcr_idx,
+                data,
+            }),
+            Err(ModelError::MailboxCmdFailed(expected_err.into()))
+        );
+    }
+}
+
+#[test]
+fn test_get_pcr_log_replays_pcr0_pcr1() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let log = model.get_pcr_log().unwrap();
+    assert!(!log.entries().is_empty());
+
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    for pcr_idx in [0, 1] {
+        let mut pcr = [0u8; 48];
+        for entry in log
+            .entries()
+            .iter()
+            .filter(|e| e.pcr_ids & (1 << pcr_idx) != 0)
+        {
+            let mut hasher = Sha384::new();
+            hasher.update(&pcr);
+            let data = entry.measured_data();
+            if data.len() == 48 {
+                hasher.update(data);
+            } else {
+                hasher.update(&sha384(data));
+            }
+            pcr = hasher.finish();
+        }
+        assert_eq!(quote.pcrs[pcr_idx], pcr);
+    }
+}
+
+#[test]
+fn test_get_event_log_matches_pcr_quote() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    model
+        .mailbox_execute_req(ExtendPcrReq {
+            hdr: MailboxReqHeader::default(),
+            pcr_idx: 4,
+            data: [0x5au8; 48],
+        })
+        .unwrap();
+    let resp = model
+        .mailbox_execute_req(StashMeasurementReq {
+            measurement: [0xa5u8; 48],
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(resp.dpe_result, 0);
+
+    let log = model.get_event_log().unwrap();
+    let records: Vec<_> = EventLogIter::new(&log).map(Result::unwrap).collect();
+    assert_eq!(
+        records.iter().map(|r| r.recnum).collect::<Vec<_>>(),
+        (0..records.len() as u64).collect::<Vec<_>>()
+    );
+    assert_eq!(records[0].pcr, 31);
+    assert_eq!(records[0].event_data, [0xa5u8; 48]);
+    assert_eq!(records[0].digest, [0xa5u8; 48]);
+    assert_eq!(records.last().unwrap().pcr, 4);
+
+    let replayed = ReplayedPcrs::replay(&log, sha384).unwrap();
+    assert_eq!(replayed.logged_pcrs, (1 << 31) | 0b11111);
+
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    replayed.check_quote(&quote.pcrs).unwrap();
+}
+
+#[test]
+fn test_get_fuse_and_measurement_logs() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let fuse_log = model.get_fuse_log().unwrap();
+    assert!(!fuse_log.entries().is_empty());
+    let mut prev_id = 0;
+    for entry in fuse_log.entries() {
+        assert!(entry.entry_id > prev_id);
+        prev_id = entry.entry_id;
+    }
+
+    // Nothing was stashed during this boot.
+    let measurement_log = model.get_measurement_log().unwrap();
+    assert!(measurement_log.entries().is_empty());
+
+    // Measurements stashed at runtime are logged until the log is full, and
+    // still stashed after that.
+    for i in 0..MEASUREMENT_MAX_COUNT + 2 {
+        let resp = model
+            .mailbox_execute_req(StashMeasurementReq {
+                metadata: [i as u8; 4],
+                measurement: [i as u8; 48],
+                context: [!(i as u8); 48],
+                svn: i as u32,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(resp.dpe_result, 0);
+    }
+    let measurement_log = model.get_measurement_log().unwrap();
+    assert_eq!(measurement_log.entries().len(), MEASUREMENT_MAX_COUNT);
+    for (i, entry) in measurement_log.entries().iter().enumerate() {
+        assert_eq!(entry.metadata, [i as u8; 4]);
+        assert_eq!(entry.pcr_entry.measured_data(), [i as u8; 48]);
+        assert_eq!(entry.context.as_bytes(), [!(i as u8); 48]);
+        assert_eq!(entry.svn, i as u32);
+    }
+
+    // The event log marks PCR31 as incomplete.
+    let log = model.get_event_log().unwrap();
+    let replayed = ReplayedPcrs::replay(&log, sha384).unwrap();
+    assert_eq!(replayed.incomplete_pcrs, 1 << 31);
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    assert_eq!(
+        replayed.check_quote(&quote.pcrs),
+        Err(EventLogError::IncompletePcr(31))
+    );
+}
@@ -0,0 +1,260 @@
+// Licensed under the Apache-2.0 license.
+
+use crate::common::run_rt_test;
+use caliptra_common::mailbox_api::{ShaDigestReq, ShaUpdateReq};
+use caliptra_drivers::CaliptraError;
+use caliptra_hw_model::{HwModel, ModelError};
+use caliptra_runtime::RtBootStatus;
+use openssl::sha::{sha384, sha512, Sha384};
+
+#[test]
+fn test_sha_digest() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // Cover empty, unaligned and maximum length data.
+    let data: Vec<u8> = (0..ShaDigestReq::DATA_MAX_SIZE).map(|i| i as u8).collect();
+    for len in [0, 3, 130, ShaDigestReq::DATA_MAX_SIZE] {
+        let data = &data[..len];
+        assert_eq!(
+            model
+                .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, data, None)
+                .unwrap(),
+            sha384(data)
+        );
+        assert_eq!(
+            model
+                .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA512, data, None)
+                .unwrap(),
+            sha512(data)
+        );
+    }
+
+    assert_eq!(
+        model.sha_digest(0, &data[..4], None),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_DIGEST_INVALID_ALGORITHM.into()
+        ))
+    );
+}
+
+#[test]
+fn test_sha_digest_extend_pcr() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let data = b"SoC firmware blob";
+    let digest = model
+        .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, data, Some(5))
+        .unwrap();
+    assert_eq!(digest, sha384(data));
+
+    let mut hasher = Sha384::new();
+    hasher.update(&[0u8; 48]);
+    hasher.update(&digest);
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    assert_eq!(quote.pcrs[5], hasher.finish());
+
+    let log = model.get_pcr_log().unwrap();
+    let entry = log.entries().last().unwrap();
+    assert_eq!(entry.pcr_ids, 1 << 5);
+    assert_eq!(entry.measured_data(), &digest[..]);
+
+    assert_eq!(
+        model.sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA512, data, Some(5)),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_DIGEST_EXTEND_REQUIRES_SHA384.into()
+        ))
+    );
+    assert_eq!(
+        model.sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, data, Some(31)),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_EXTEND_PCR_STASH_MEASUREMENT_PCR_RESERVED.into()
+        ))
+    );
+}
+
+#[test]
+fn test_sha_digest_soc_holds_lock() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // Reading the lock register acquires the lock for the SoC.
+    assert!(!model.soc_sha512_acc().lock().read().lock());
+    assert_eq!(
+        model.sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"abc", None),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_ACC_LOCK_UNAVAILABLE.into()
+        ))
+    );
+
+    model.soc_sha512_acc().lock().write(|w| w.lock(true));
+    assert_eq!(
+        model
+            .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"abc", None)
+            .unwrap(),
+        sha384(b"abc")
+    );
+}
+
+#[test]
+fn test_sha_stream() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // More data than fits in a single SHA_DIGEST, with an unaligned tail.
+    let data: Vec<u8> = (0..4 * ShaUpdateReq::DATA_MAX_SIZE + 3)
+        .map(|i| i as u8)
+        .collect();
+    for (hash_algorithm, expected) in [
+        (ShaDigestReq::HASH_ALGORITHM_SHA384, sha384(&data).to_vec()),
+        (ShaDigestReq::HASH_ALGORITHM_SHA512, sha512(&data).to_vec()),
+    ] {
+        model
+            .sha_init(hash_algorithm, data.len() as u32, None)
+            .unwrap();
+        for chunk in data.chunks(ShaUpdateReq::DATA_MAX_SIZE) {
+            model.sha_update(chunk).unwrap();
+        }
+        assert_eq!(model.sha_final().unwrap(), expected);
+    }
+
+    // The digest can also be extended into a SoC PCR.
+    model
+        .sha_init(
+            ShaDigestReq::HASH_ALGORITHM_SHA384,
+            data.len() as u32,
+            Some(6),
+        )
+        .unwrap();
+    for chunk in data.chunks(ShaUpdateReq::DATA_MAX_SIZE) {
+        model.sha_update(chunk).unwrap();
+    }
+    let digest = model.sha_final().unwrap();
+    assert_eq!(digest, sha384(&data));
+
+    let mut hasher = Sha384::new();
+    hasher.update(&[0u8; 48]);
+    hasher.update(&digest);
+    let quote = model.get_pcr_quote([0u8; 32]).unwrap();
+    assert_eq!(quote.pcrs[6], hasher.finish());
+}
+
+#[test]
+fn test_sha_stream_errors() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    let not_started = || {
+        Some(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_STREAM_NOT_STARTED.into(),
+        ))
+    };
+    assert_eq!(model.sha_update(b"abcd").err(), not_started());
+    assert_eq!(model.sha_final().err(), not_started());
+
+    model
+        .sha_init(ShaDigestReq::HASH_ALGORITHM_SHA384, 8, None)
+        .unwrap();
+
+    // The runtime holds the SHA accelerator until SHA_FINAL.
+    assert_eq!(
+        model.sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"abc", None),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_ACC_LOCK_UNAVAILABLE.into()
+        ))
+    );
+
+    assert_eq!(
+        model.sha_update(b"abc"),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_STREAM_UNALIGNED_UPDATE.into()
+        ))
+    );
+    assert_eq!(
+        model.sha_update(&[0u8; 12]),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_STREAM_LENGTH_MISMATCH.into()
+        ))
+    );
+    model.sha_update(b"abcd").unwrap();
+    assert_eq!(
+        model.sha_final(),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_STREAM_LENGTH_MISMATCH.into()
+        ))
+    );
+
+    // A failed SHA_FINAL ends the stream and releases the accelerator.
+    assert_eq!(model.sha_final().err(), not_started());
+    assert_eq!(
+        model
+            .sha_digest(ShaDigestReq::HASH_ALGORITHM_SHA384, b"abc", None)
+            .unwrap(),
+        sha384(b"abc")
+    );
+}
+
+#[test]
+// SW emulator does not support pauser
+#[cfg(feature = "verilator")]
+fn test_sha_stream_owned_by_pauser() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| {
+        m.soc_ifc().cptra_boot_status().read() == u32::from(RtBootStatus::RtReadyForCommands)
+    });
+
+    // Allow a second PAUSER to use the mailbox (using index 1)
+    model
+        .soc_ifc()
+        .cptra_mbox_valid_pauser()
+        .at(1)
+        .write(|_| 0x2);
+    model
+        .soc_ifc()
+        .cptra_mbox_pauser_lock()
+        .at(1)
+        .write(|w| w.lock(true));
+
+    model.set_apb_pauser(0x1);
+    model
+        .sha_init(ShaDigestReq::HASH_ALGORITHM_SHA384, 8, None)
+        .unwrap();
+    model.sha_update(b"abcd").unwrap();
+
+    // Another PAUSER can't update, finalize or restart the stream.
+    model.set_apb_pauser(0x2);
+    let mismatch = || {
+        Some(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_SHA_STREAM_PAUSER_MISMATCH.into(),
+        ))
+    };
+    assert_eq!(model.sha_update(b"efgh").err(), mismatch());
+    assert_eq!(model.sha_final().err(), mismatch());
+    assert_eq!(
+        model
+            .sha_init(ShaDigestReq::HASH_ALGORITHM_SHA384, 8, None)
+            .err(),
+        mismatch()
+    );
+
+    model.set_apb_pauser(0x1);
+    model.sha_update(b"efgh").unwrap();
+    assert_eq!(model.sha_final().unwrap(), sha384(b"abcdefgh"));
+}
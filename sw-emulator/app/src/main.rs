@@ -17,11 +17,11 @@ use caliptra_emu_cpu::{Cpu, RvInstr, StepAction};
 use caliptra_emu_periph::soc_reg::DebugManufService;
 use caliptra_emu_periph::{
     CaliptraRootBus, CaliptraRootBusArgs, DownloadIdevidCsrCb, MailboxInternal, ReadyForFwCb,
-    TbServicesCb, UploadUpdateFwCb,
+    SocToCaliptraBus, TbServicesCb, UploadUpdateFwCb,
 };
 use caliptra_hw_model::BusMmio;
-use caliptra_hw_model_types::{DeviceLifecycle, SecurityState};
-use clap::{arg, value_parser, ArgAction};
+use caliptra_hw_model_types::{DeviceLifecycle, Fuses, SecurityState};
+use clap::{arg, value_parser, ArgAction, ValueSource};
 use std::fs::File;
 use std::io;
 use std::io::{Read, Write};
@@ -128,6 +128,11 @@ fn main() -> io::Result<()> {
                 .value_parser(value_parser!(PathBuf))
                 .default_value("/tmp")
         )
+        .arg(
+            arg!(--"fuses" <FILE> "Fuse values file [TOML, or JSON with a .json extension]")
+                .required(false)
+                .value_parser(value_parser!(PathBuf))
+        )
         .arg(
             arg!(--"mfg-pk-hash" ... "Hash of the four Manufacturer Public Keys")
                 .required(false)
@@ -176,6 +181,9 @@ fn main() -> io::Result<()> {
         }
     };
     let args_device_lifecycle = args.get_one::<String>("device-lifecycle").unwrap();
+    let fuses = args
+        .get_one::<PathBuf>("fuses")
+        .map(|path| load_fuses(path.as_path()));
 
     if !Path::new(&args_rom).exists() {
         println!("ROM File {:?} does not exist", args_rom);
@@ -248,6 +256,12 @@ fn main() -> io::Result<()> {
             }
         },
     );
+    // The lifecycle passed on the command line overrides the fuse file
+    if let Some(fuses) = &fuses {
+        if args.value_source("device-lifecycle") == Some(ValueSource::DefaultValue) {
+            security_state.set_device_lifecycle(fuses.life_cycle);
+        }
+    }
 
     let bus_args = CaliptraRootBusArgs {
         rom: rom_buffer,
@@ -287,6 +301,11 @@ fn main() -> io::Result<()> {
         )
     };
 
+    // The hashes passed on the command line override the fuse file
+    if let Some(fuses) = &fuses {
+        write_fuses(&soc_ifc, fuses);
+    }
+
     if !mfg_pk_hash.is_empty() {
         let mfg_pk_hash = words_from_bytes_le(
             &mfg_pk_hash
@@ -369,7 +388,8 @@ fn main() -> io::Result<()> {
             .write(|_| (*wdt_timeout >> 32) as u32);
     }
 
-    let cpu = Cpu::new(root_bus, clock);
+    let pic = root_bus.pic.clone();
+    let cpu = Cpu::new(root_bus, clock, pic);
 
     // Check if Optional GDB Port is passed
     match args.get_one::<String>("gdb-port") {
@@ -397,6 +417,65 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
+/// Load the fuse values file at `path`
+fn load_fuses(path: &Path) -> Fuses {
+    let fuses_str = match std::fs::read_to_string(path) {
+        Ok(fuses_str) => fuses_str,
+        Err(err) => {
+            println!("Failed to read fuses file {:?}: {}", path, err);
+            exit(-1);
+        }
+    };
+    let fuses = if path.extension().map_or(false, |ext| ext == "json") {
+        serde_json::from_str(&fuses_str).map_err(|err| err.to_string())
+    } else {
+        toml::from_str(&fuses_str).map_err(|err| err.to_string())
+    };
+    match fuses {
+        Ok(fuses) => fuses,
+        Err(err) => {
+            println!("Failed to parse fuses file {:?}: {}", path, err);
+            exit(-1);
+        }
+    }
+}
+
+/// Write the fuse values that are not otherwise provided by the emulator
+///
+/// The UDS seed, field entropy and IDevID certificate attributes keep the
+/// values set up by the emulator; the lifecycle is part of the security state.
+fn write_fuses(
+    soc_ifc: &caliptra_registers::soc_ifc::RegisterBlock<BusMmio<SocToCaliptraBus>>,
+    fuses: &Fuses,
+) {
+    soc_ifc
+        .fuse_key_manifest_pk_hash()
+        .write(&fuses.key_manifest_pk_hash);
+    soc_ifc
+        .fuse_key_manifest_pk_hash_mask()
+        .write(|w| w.mask(fuses.key_manifest_pk_hash_mask.into()));
+    soc_ifc.fuse_owner_pk_hash().write(&fuses.owner_pk_hash);
+    soc_ifc
+        .fuse_fmc_key_manifest_svn()
+        .write(|_| fuses.fmc_key_manifest_svn);
+    soc_ifc.fuse_runtime_svn().write(&fuses.runtime_svn);
+    soc_ifc
+        .fuse_anti_rollback_disable()
+        .write(|w| w.dis(fuses.anti_rollback_disable));
+    soc_ifc
+        .fuse_idevid_manuf_hsm_id()
+        .write(&fuses.idevid_manuf_hsm_id);
+    soc_ifc
+        .fuse_lms_verify()
+        .write(|w| w.lms_verify(fuses.lms_verify));
+    soc_ifc
+        .fuse_lms_revocation()
+        .write(|_| fuses.fuse_lms_revocation);
+    soc_ifc
+        .fuse_fw_recovery_retry_count()
+        .write(|_| fuses.fw_recovery_retry_count);
+}
+
 fn change_dword_endianess(data: &mut Vec<u8>) {
     for idx in (0..data.len()).step_by(4) {
         data.swap(idx, idx + 3);
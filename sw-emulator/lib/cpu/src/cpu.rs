@@ -14,7 +14,8 @@ Abstract:
 
 use crate::csr_file::{Csr, CsrFile};
 use crate::instr::Instr;
-use crate::types::{RvInstr, RvMStatus};
+use crate::pic::Pic;
+use crate::types::{RvInstr, RvMInterrupts, RvMStatus};
 use crate::xreg_file::{XReg, XRegFile};
 use bit_vec::BitVec;
 use caliptra_emu_bus::{Bus, BusError, Clock, TimerAction};
@@ -108,6 +109,9 @@ pub struct Cpu<TBus: Bus> {
     // The bus the CPU uses to talk to memory and peripherals.
     pub bus: TBus,
 
+    /// The programmable interrupt controller external interrupts are taken from.
+    pic: Pic,
+
     pub clock: Clock,
 
     // Track if Execution is in progress
@@ -136,14 +140,22 @@ impl<TBus: Bus> Cpu<TBus> {
     /// Default Program counter reset value
     const PC_RESET_VAL: RvData = 0;
 
+    /// Machine external interrupt cause
+    const MCAUSE_MACHINE_EXTERNAL_INTERRUPT: u32 = 0x8000_000B;
+
+    /// NMI cause of a fast interrupt redirect that failed to read the
+    /// external interrupt vector table (VeeR-specific)
+    const MCAUSE_FAST_INT_NON_DCCM: u32 = 0xF000_1002;
+
     /// Create a new RISCV CPU
-    pub fn new(bus: TBus, clock: Clock) -> Self {
+    pub fn new(bus: TBus, clock: Clock, pic: Pic) -> Self {
         Self {
             xregs: XRegFile::new(),
             csrs: CsrFile::new(),
             pc: Self::PC_RESET_VAL,
             next_pc: Self::PC_RESET_VAL,
             bus,
+            pic,
             clock,
             is_execute_instr: false,
             watch_ptr_cfg: WatchPtrCfg::new(),
@@ -233,7 +245,22 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
     pub fn read_csr(&self, csr: RvAddr) -> Result<RvData, RvException> {
-        self.csrs.read(csr)
+        match csr {
+            // The cycle counter follows the simulated clock; writes to it are
+            // ignored.
+            Csr::MCYCLE => Ok(self.clock.now() as RvData),
+            Csr::MCYCLEH => Ok((self.clock.now() >> 32) as RvData),
+            // The external interrupt pending bit is driven by the PIC.
+            Csr::MIP => {
+                let mut mip = RvMInterrupts(self.csrs.read(csr)?);
+                mip.set_mei(self.pending_external_interrupt()?.is_some().into());
+                Ok(mip.0)
+            }
+            // The handler address pointer points in the vector table at
+            // the entry of the claimed interrupt.
+            Csr::MEIHAP => Ok(self.csrs.read(Csr::MEIVT)? | self.csrs.read(csr)?),
+            _ => self.csrs.read(csr),
+        }
     }
 
     /// Write the specified Configuration status register
@@ -247,7 +274,41 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
     pub fn write_csr(&mut self, csr: RvAddr, val: RvData) -> Result<(), RvException> {
-        self.csrs.write(csr, val)
+        match csr {
+            Csr::MEICPCT => self.claim_external_interrupt(),
+            _ => self.csrs.write(csr, val),
+        }
+    }
+
+    /// Return the ID and priority of the highest priority pending external
+    /// interrupt whose priority is above the priority threshold and the
+    /// current priority level (VeeR-specific)
+    ///
+    /// # Error
+    ///
+    /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
+    fn pending_external_interrupt(&self) -> Result<Option<(u8, u8)>, RvException> {
+        let Some((id, priority)) = self.pic.highest_priority_irq() else {
+            return Ok(None);
+        };
+        let meipt = self.csrs.read(Csr::MEIPT)? as u8;
+        let meicurpl = self.csrs.read(Csr::MEICURPL)? as u8;
+        Ok(self
+            .pic
+            .exceeds_threshold(priority, meipt, meicurpl)
+            .then_some((id, priority)))
+    }
+
+    /// Capture the ID of the highest priority pending external interrupt in
+    /// meihap and its priority in meicidpl (VeeR-specific)
+    ///
+    /// # Error
+    ///
+    /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
+    fn claim_external_interrupt(&mut self) -> Result<(), RvException> {
+        let (id, priority) = self.pic.highest_priority_irq().unwrap_or((0, 0));
+        self.csrs.write_unmasked(Csr::MEIHAP, u32::from(id) << 2)?;
+        self.csrs.write(Csr::MEICIDPL, priority.into())
     }
 
     /// Read from bus
@@ -373,6 +434,10 @@ impl<TBus: Bus> Cpu<TBus> {
             }
         }
 
+        if let Some(action) = self.handle_external_interrupt() {
+            return action;
+        }
+
         match self.exec_instr(instr_tracer) {
             Ok(result) => result,
             Err(exception) => self.handle_exception(exception),
@@ -404,6 +469,39 @@ impl<TBus: Bus> Cpu<TBus> {
         }
     }
 
+    /// Handle external interrupt (VeeR-specific)
+    ///
+    /// The interrupt is taken if external interrupts are enabled and it is
+    /// above the priority threshold. It is claimed on entry, and the CPU jumps
+    /// straight to the handler read from the external interrupt vector table
+    /// (fast interrupt redirect).
+    fn handle_external_interrupt(&mut self) -> Option<StepAction> {
+        // Cannot panic; mstatus, mie, meicpct and meihap are valid CSRs
+        let status = RvMStatus(self.read_csr(Csr::MSTATUS).unwrap());
+        let mie = RvMInterrupts(self.read_csr(Csr::MIE).unwrap());
+        if status.mie() == 0 || mie.mei() == 0 {
+            return None;
+        }
+        self.pending_external_interrupt().unwrap()?;
+        self.claim_external_interrupt().unwrap();
+
+        let meihap = self.read_csr(Csr::MEIHAP).unwrap();
+        let Ok(handler) = self.bus.read(RvSize::Word, meihap) else {
+            return Some(self.handle_nmi(Self::MCAUSE_FAST_INT_NON_DCCM, 0));
+        };
+        let ret = self.handle_trap(
+            true,
+            self.read_pc(),
+            Self::MCAUSE_MACHINE_EXTERNAL_INTERRUPT,
+            0,
+            handler,
+        );
+        match ret {
+            Ok(_) => Some(StepAction::Continue),
+            Err(_) => Some(StepAction::Fatal),
+        }
+    }
+
     /// Handle synchronous & asynchronous trap
     ///
     /// # Error
@@ -417,10 +515,9 @@ impl<TBus: Bus> Cpu<TBus> {
         info: u32,
         next_pc: u32,
     ) -> Result<(), RvException> {
-        // TODO: Implement Interrupt Handling
-        // 1. Support for vectored asynchronous interrupts
-        // 2. Veer fast external interrupt support
-        assert!(!_intr);
+        // TODO: Support for vectored asynchronous interrupts. External
+        // interrupts are always taken through the VeeR fast interrupt
+        // redirect, which bypasses mtvec.
 
         self.write_csr(Csr::MEPC, pc)?;
         self.write_csr(Csr::MCAUSE, cause)?;
@@ -467,24 +564,24 @@ impl<TBus: Bus> Cpu<TBus> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use caliptra_emu_bus::{testing::FakeBus, DynamicBus, Rom, Timer};
+    use caliptra_emu_bus::{testing::FakeBus, DynamicBus, Ram, Rom, Timer};
 
     #[test]
     fn test_new() {
-        let cpu = Cpu::new(DynamicBus::new(), Clock::new());
+        let cpu = Cpu::new(DynamicBus::new(), Clock::new(), Pic::new());
         assert_eq!(cpu.read_pc(), 0);
     }
 
     #[test]
     fn test_pc() {
-        let mut cpu = Cpu::new(DynamicBus::new(), Clock::new());
+        let mut cpu = Cpu::new(DynamicBus::new(), Clock::new(), Pic::new());
         cpu.write_pc(0xFF);
         assert_eq!(cpu.read_pc(), 0xFF);
     }
 
     #[test]
     fn test_xreg() {
-        let mut cpu = Cpu::new(DynamicBus::new(), Clock::new());
+        let mut cpu = Cpu::new(DynamicBus::new(), Clock::new(), Pic::new());
         for reg in 1..32u32 {
             assert_eq!(cpu.write_xreg(reg.into(), 0xFF).ok(), Some(()));
             assert_eq!(cpu.read_xreg(reg.into()).ok(), Some(0xFF));
@@ -514,7 +611,7 @@ mod tests {
 
         let mut action0 = Some(timer.schedule_poll_in(31));
 
-        let mut cpu = Cpu::new(bus, clock);
+        let mut cpu = Cpu::new(bus, clock, Pic::new());
         for i in 0..30 {
             assert_eq!(cpu.clock.now(), i);
             assert_eq!(cpu.step(None), StepAction::Continue);
@@ -529,6 +626,90 @@ mod tests {
         assert_eq!(cpu.read_pc(), 31 * 4);
     }
 
+    #[test]
+    fn test_mcycle_follows_clock() {
+        const RV32_NO_OP: u32 = 0x00000013;
+
+        let clock = Clock::new();
+        let mut bus = DynamicBus::new();
+        let rom = Rom::new(
+            std::iter::repeat(RV32_NO_OP)
+                .take(256)
+                .flat_map(u32::to_le_bytes)
+                .collect(),
+        );
+        bus.attach_dev("ROM", 0..=0x3ff, Box::new(rom)).unwrap();
+
+        let mut cpu = Cpu::new(bus, clock, Pic::new());
+        for _ in 0..10 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        assert_eq!(cpu.read_csr(Csr::MCYCLE).ok(), Some(10));
+        assert_eq!(cpu.read_csr(Csr::MCYCLEH).ok(), Some(0));
+
+        cpu.write_csr(Csr::MCYCLE, 0).unwrap();
+        assert_eq!(cpu.read_csr(Csr::MCYCLE).ok(), Some(10));
+    }
+
+    #[test]
+    fn test_external_interrupt() {
+        const RV32_NO_OP: u32 = 0x00000013;
+
+        let clock = Clock::new();
+        let pic = Pic::new();
+        let mut pic_regs = pic.mmio_regs();
+        let irq = pic.register_irq(5);
+        let mut bus = DynamicBus::new();
+        let rom = Rom::new(
+            std::iter::repeat(RV32_NO_OP)
+                .take(256)
+                .flat_map(u32::to_le_bytes)
+                .collect(),
+        );
+        bus.attach_dev("ROM", 0..=0x3ff, Box::new(rom)).unwrap();
+
+        // External interrupt vector table; the handler of interrupt 5 is at 0x200
+        let mut vector_table = vec![0; 0x400];
+        vector_table[5 * 4..6 * 4].copy_from_slice(&0x200u32.to_le_bytes());
+        bus.attach_dev("DCCM", 0x1000..=0x13ff, Box::new(Ram::new(vector_table)))
+            .unwrap();
+
+        let mut cpu = Cpu::new(bus, clock, pic);
+        cpu.write_csr(Csr::MEIVT, 0x1000).unwrap();
+        cpu.write_csr(Csr::MEIPT, 3).unwrap();
+        cpu.write_csr(Csr::MSTATUS, 0x8).unwrap();
+        cpu.write_csr(Csr::MIE, 0x800).unwrap();
+
+        // Priority 3, enabled
+        pic_regs.write(RvSize::Word, 0x0014, 3).unwrap();
+        pic_regs.write(RvSize::Word, 0x2014, 1).unwrap();
+
+        irq.set_level(true);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_pc(), 4);
+        assert_eq!(cpu.read_csr(Csr::MIP).ok(), Some(0));
+
+        // Lower the priority threshold below the interrupt priority
+        cpu.write_csr(Csr::MEIPT, 2).unwrap();
+        assert_eq!(cpu.read_csr(Csr::MIP).ok(), Some(0x800));
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_pc(), 0x200);
+        assert_eq!(cpu.read_csr(Csr::MEPC).ok(), Some(4));
+        assert_eq!(cpu.read_csr(Csr::MCAUSE).ok(), Some(0x8000_000B));
+        assert_eq!(cpu.read_csr(Csr::MEIHAP).ok(), Some(0x1014));
+        assert_eq!(cpu.read_csr(Csr::MEICIDPL).ok(), Some(3));
+        assert_eq!(cpu.read_csr(Csr::MSTATUS).ok(), Some(0x1800_0080));
+
+        // Interrupts are disabled in the handler
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_pc(), 0x204);
+
+        // Claiming with no pending interrupt captures ID 0
+        irq.set_level(false);
+        cpu.write_csr(Csr::MEICPCT, 0).unwrap();
+        assert_eq!(cpu.read_csr(Csr::MEIHAP).ok(), Some(0x1000));
+    }
+
     pub fn count_executed(coverage: &CodeCoverage) -> usize {
         coverage.bit_vec.iter().filter(|&executed| executed).count()
     }
@@ -76,6 +76,24 @@ impl Csr {
     /// Instruction Retired High Counter CSR
     pub const MINSTRETH: RvAddr = 0xB82;
 
+    /// External Interrupt Vector Table CSR (VeeR-specific)
+    pub const MEIVT: RvAddr = 0xBC8;
+
+    /// External Interrupt Priority Threshold CSR (VeeR-specific)
+    pub const MEIPT: RvAddr = 0xBC9;
+
+    /// External Interrupt Claim ID / Priority Level Capture Trigger CSR (VeeR-specific)
+    pub const MEICPCT: RvAddr = 0xBCA;
+
+    /// External Interrupt Claim ID's Priority Level CSR (VeeR-specific)
+    pub const MEICIDPL: RvAddr = 0xBCB;
+
+    /// External Interrupt Current Priority Level CSR (VeeR-specific)
+    pub const MEICURPL: RvAddr = 0xBCC;
+
+    /// External Interrupt Handler Address Pointer CSR (VeeR-specific)
+    pub const MEIHAP: RvAddr = 0xFC8;
+
     /// Create a new Configurations and Status register
     ///
     /// # Arguments
@@ -128,6 +146,12 @@ impl CsrFile {
         self.csrs[Csr::MCYCLEH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRET as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRETH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+        self.csrs[Csr::MEIVT as usize] = Csr::new(0x0000_0000, 0xFFFF_FC00);
+        self.csrs[Csr::MEIPT as usize] = Csr::new(0x0000_0000, 0x0000_000F);
+        self.csrs[Csr::MEICPCT as usize] = Csr::new(0x0000_0000, 0x0000_0000);
+        self.csrs[Csr::MEICIDPL as usize] = Csr::new(0x0000_0000, 0x0000_000F);
+        self.csrs[Csr::MEICURPL as usize] = Csr::new(0x0000_0000, 0x0000_000F);
+        self.csrs[Csr::MEIHAP as usize] = Csr::new(0x0000_0000, 0x0000_0000);
     }
 
     /// Read the specified configuration status register
@@ -174,6 +198,30 @@ impl CsrFile {
             _ => Err(RvException::illegal_register()),
         }
     }
+
+    /// Write the specified Configuration status register, ignoring its write
+    /// mask. Used for fields that are read-only to software but updated by
+    /// the hardware.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - Configuration  status register to write
+    /// * `val` - Value to write
+    ///
+    /// # Error
+    ///
+    /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
+    pub fn write_unmasked(&mut self, addr: RvAddr, val: RvData) -> Result<(), RvException> {
+        let addr = addr as usize;
+        const CSR_MAX: usize = CsrFile::CSR_COUNT - 1;
+        match addr {
+            0..=CSR_MAX => {
+                self.csrs[addr].val = val;
+                Ok(())
+            }
+            _ => Err(RvException::illegal_register()),
+        }
+    }
 }
 
 #[cfg(test)]
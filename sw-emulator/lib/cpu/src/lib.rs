@@ -15,6 +15,7 @@ Abstract:
 pub mod cpu;
 mod csr_file;
 mod instr;
+mod pic;
 mod types;
 pub mod xreg_file;
 
@@ -22,4 +23,5 @@ pub use cpu::StepAction;
 pub use cpu::WatchPtrHit;
 pub use cpu::WatchPtrKind;
 pub use cpu::{Cpu, InstrTracer};
+pub use pic::{Irq, Pic, PicMmioRegisters};
 pub use types::RvInstr;
@@ -0,0 +1,415 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    pic.rs
+
+Abstract:
+
+    File contains the implementation of the VeeR Programmable Interrupt
+    Controller (PIC).
+
+--*/
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use caliptra_emu_bus::{Bus, BusError};
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+
+/// VeeR Programmable Interrupt Controller
+///
+/// Peripherals drive their interrupt lines through [`Irq`] handles returned
+/// by [`Pic::register_irq`], firmware configures the PIC through the memory
+/// mapped registers returned by [`Pic::mmio_regs`], and the CPU takes the
+/// highest priority pending interrupt.
+#[derive(Clone)]
+pub struct Pic {
+    pic: Rc<PicImpl>,
+}
+
+impl Pic {
+    /// Number of interrupt sources; source 0 is reserved.
+    pub const SOURCE_COUNT: usize = 32;
+
+    /// Create a new PIC
+    pub fn new() -> Self {
+        Self {
+            pic: Rc::new(PicImpl::new()),
+        }
+    }
+
+    /// Return the handle of the interrupt line of source `id`
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Interrupt source ID, between 1 and 31
+    pub fn register_irq(&self, id: u8) -> Irq {
+        assert!(
+            id != 0 && usize::from(id) < Self::SOURCE_COUNT,
+            "Invalid interrupt source id {id}"
+        );
+        Irq {
+            pic: self.pic.clone(),
+            id,
+        }
+    }
+
+    /// Return the memory mapped registers of the PIC
+    pub fn mmio_regs(&self) -> PicMmioRegisters {
+        PicMmioRegisters {
+            pic: self.pic.clone(),
+        }
+    }
+
+    /// Return the ID and priority of the highest priority interrupt that is
+    /// pending and enabled, ignoring interrupts that are masked by their
+    /// priority.
+    pub(crate) fn highest_priority_irq(&self) -> Option<(u8, u8)> {
+        self.pic.highest_priority_irq()
+    }
+
+    /// Check if an interrupt of `priority` is above the priority threshold
+    /// `meipt` and the current priority level `meicurpl`.
+    pub(crate) fn exceeds_threshold(&self, priority: u8, meipt: u8, meicurpl: u8) -> bool {
+        self.pic.level(priority) > self.pic.level(meipt).max(self.pic.level(meicurpl))
+    }
+}
+
+impl Default for Pic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Interrupt line of a PIC interrupt source
+pub struct Irq {
+    pic: Rc<PicImpl>,
+    id: u8,
+}
+
+impl Irq {
+    /// Return the interrupt source ID
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Drive the interrupt line
+    ///
+    /// Whether the line is active high or active low, and level or edge
+    /// triggered, is configured by firmware in the gateway of the source.
+    pub fn set_level(&self, is_high: bool) {
+        self.pic.set_level(self.id, is_high);
+    }
+}
+
+/// Memory mapped registers of the PIC
+pub struct PicMmioRegisters {
+    pic: Rc<PicImpl>,
+}
+
+impl PicMmioRegisters {
+    /// External Interrupt Priority Level Registers
+    const MEIPL_OFFSET: RvAddr = 0x0000;
+
+    /// External Interrupt Pending Register
+    const MEIP_OFFSET: RvAddr = 0x1000;
+
+    /// External Interrupt Enable Registers
+    const MEIE_OFFSET: RvAddr = 0x2000;
+
+    /// PIC Configuration Register
+    const MPICCFG_OFFSET: RvAddr = 0x3000;
+
+    /// External Interrupt Gateway Configuration Registers
+    const MEIGWCTRL_OFFSET: RvAddr = 0x4000;
+
+    /// External Interrupt Gateway Clear Registers
+    const MEIGWCLR_OFFSET: RvAddr = 0x5000;
+
+    /// Return the index of the per-source register at `addr` in the register
+    /// array starting at `offset`.
+    fn source_index(addr: RvAddr, offset: RvAddr) -> Option<usize> {
+        let index = (addr.checked_sub(offset)? / 4) as usize;
+        (index < Pic::SOURCE_COUNT).then_some(index)
+    }
+}
+
+impl Bus for PicMmioRegisters {
+    fn read(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, BusError> {
+        if size != RvSize::Word || addr & 0x3 != 0 {
+            return Err(BusError::LoadAccessFault);
+        }
+        let pic = &self.pic;
+        match addr & 0xf000 {
+            Self::MEIPL_OFFSET => Self::source_index(addr, Self::MEIPL_OFFSET)
+                .map(|index| pic.meipl[index].get().into()),
+            Self::MEIP_OFFSET if addr == Self::MEIP_OFFSET => Some(pic.pending()),
+            Self::MEIE_OFFSET => Self::source_index(addr, Self::MEIE_OFFSET)
+                .map(|index| (pic.meie.get() >> index) & 1),
+            Self::MPICCFG_OFFSET if addr == Self::MPICCFG_OFFSET => Some(pic.priord.get().into()),
+            Self::MEIGWCTRL_OFFSET => {
+                Self::source_index(addr, Self::MEIGWCTRL_OFFSET).map(|index| {
+                    ((pic.polarity.get() >> index) & 1) | (((pic.edge.get() >> index) & 1) << 1)
+                })
+            }
+            Self::MEIGWCLR_OFFSET => Self::source_index(addr, Self::MEIGWCLR_OFFSET).map(|_| 0),
+            _ => None,
+        }
+        .ok_or(BusError::LoadAccessFault)
+    }
+
+    fn write(&mut self, size: RvSize, addr: RvAddr, val: RvData) -> Result<(), BusError> {
+        if size != RvSize::Word || addr & 0x3 != 0 {
+            return Err(BusError::StoreAccessFault);
+        }
+        let pic = &self.pic;
+        let index = |offset| Self::source_index(addr, offset).ok_or(BusError::StoreAccessFault);
+        match addr & 0xf000 {
+            Self::MEIPL_OFFSET => {
+                pic.meipl[index(Self::MEIPL_OFFSET)?].set((val & 0xf) as u8);
+            }
+            Self::MEIP_OFFSET if addr == Self::MEIP_OFFSET => {}
+            Self::MEIE_OFFSET => {
+                let index = index(Self::MEIE_OFFSET)?;
+                pic.meie.set(set_bit(pic.meie.get(), index, val & 1 != 0));
+            }
+            Self::MPICCFG_OFFSET if addr == Self::MPICCFG_OFFSET => {
+                pic.priord.set(val & 1 != 0);
+            }
+            Self::MEIGWCTRL_OFFSET => {
+                let index = index(Self::MEIGWCTRL_OFFSET)?;
+                pic.polarity
+                    .set(set_bit(pic.polarity.get(), index, val & 1 != 0));
+                pic.edge.set(set_bit(pic.edge.get(), index, val & 2 != 0));
+            }
+            Self::MEIGWCLR_OFFSET => {
+                let index = index(Self::MEIGWCLR_OFFSET)?;
+                pic.edge_latch
+                    .set(set_bit(pic.edge_latch.get(), index, false));
+            }
+            _ => return Err(BusError::StoreAccessFault),
+        }
+        Ok(())
+    }
+
+    fn warm_reset(&mut self) {
+        self.pic.reset();
+    }
+
+    fn update_reset(&mut self) {
+        self.pic.reset();
+    }
+}
+
+/// Return `val` with bit `index` set to `bit`
+fn set_bit(val: u32, index: usize, bit: bool) -> u32 {
+    if bit {
+        val | (1 << index)
+    } else {
+        val & !(1 << index)
+    }
+}
+
+struct PicImpl {
+    /// Priority level of every source
+    meipl: [Cell<u8>; Pic::SOURCE_COUNT],
+
+    /// Enabled sources
+    meie: Cell<u32>,
+
+    /// Reverse priority order
+    priord: Cell<bool>,
+
+    /// Sources with active low interrupt lines
+    polarity: Cell<u32>,
+
+    /// Sources with edge triggered interrupt lines
+    edge: Cell<u32>,
+
+    /// Levels of the interrupt lines
+    levels: Cell<u32>,
+
+    /// Latched edges of edge triggered sources
+    edge_latch: Cell<u32>,
+}
+
+impl PicImpl {
+    fn new() -> Self {
+        Self {
+            meipl: Default::default(),
+            meie: Cell::new(0),
+            priord: Cell::new(false),
+            polarity: Cell::new(0),
+            edge: Cell::new(0),
+            levels: Cell::new(0),
+            edge_latch: Cell::new(0),
+        }
+    }
+
+    /// Reset the configuration; the levels of the interrupt lines are driven
+    /// by the peripherals and are left untouched.
+    fn reset(&self) {
+        for meipl in self.meipl.iter() {
+            meipl.set(0);
+        }
+        self.meie.set(0);
+        self.priord.set(false);
+        self.polarity.set(0);
+        self.edge.set(0);
+        self.edge_latch.set(0);
+    }
+
+    fn set_level(&self, id: u8, is_high: bool) {
+        let was_active = self.active();
+        self.levels
+            .set(set_bit(self.levels.get(), id.into(), is_high));
+        let rising = self.active() & !was_active & (1 << id);
+        self.edge_latch.set(self.edge_latch.get() | rising);
+    }
+
+    /// Sources whose interrupt line is at its active level
+    fn active(&self) -> u32 {
+        self.levels.get() ^ self.polarity.get()
+    }
+
+    /// Sources with a pending interrupt at the output of their gateway
+    fn pending(&self) -> u32 {
+        let edge = self.edge.get();
+        ((self.active() & !edge) | (self.edge_latch.get() & edge)) & !1
+    }
+
+    /// Map a priority to a level that grows with the priority, whatever the
+    /// priority order.
+    fn level(&self, priority: u8) -> u8 {
+        if self.priord.get() {
+            15 - (priority & 0xf)
+        } else {
+            priority & 0xf
+        }
+    }
+
+    fn highest_priority_irq(&self) -> Option<(u8, u8)> {
+        let candidates = self.pending() & self.meie.get();
+        let mut highest: Option<(u8, u8)> = None;
+        for id in 1..Pic::SOURCE_COUNT as u8 {
+            if candidates & (1 << id) == 0 {
+                continue;
+            }
+            let priority = self.meipl[usize::from(id)].get();
+            let level = self.level(priority);
+            // Level 0 never interrupts; on ties the lowest ID wins.
+            if level > highest.map_or(0, |(_, p)| self.level(p)) {
+                highest = Some((id, priority));
+            }
+        }
+        highest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(regs: &mut PicMmioRegisters, addr: RvAddr, val: RvData) {
+        regs.write(RvSize::Word, addr, val).unwrap();
+    }
+
+    #[test]
+    fn test_highest_priority_irq() {
+        let pic = Pic::new();
+        let mut regs = pic.mmio_regs();
+        let irq3 = pic.register_irq(3);
+        let irq5 = pic.register_irq(5);
+        let irq7 = pic.register_irq(7);
+
+        write(&mut regs, 0x000c, 4);
+        write(&mut regs, 0x0014, 9);
+        write(&mut regs, 0x001c, 9);
+        irq3.set_level(true);
+        irq5.set_level(true);
+        irq7.set_level(true);
+
+        assert_eq!(regs.read(RvSize::Word, 0x1000).unwrap(), 0xa8);
+        assert_eq!(pic.highest_priority_irq(), None);
+
+        write(&mut regs, 0x200c, 1);
+        assert_eq!(pic.highest_priority_irq(), Some((3, 4)));
+
+        write(&mut regs, 0x2014, 1);
+        write(&mut regs, 0x201c, 1);
+        assert_eq!(pic.highest_priority_irq(), Some((5, 9)));
+        assert!(pic.exceeds_threshold(9, 8, 0));
+        assert!(!pic.exceeds_threshold(9, 0, 9));
+
+        irq5.set_level(false);
+        assert_eq!(pic.highest_priority_irq(), Some((7, 9)));
+
+        // Reverse priority order
+        write(&mut regs, 0x3000, 1);
+        assert_eq!(pic.highest_priority_irq(), Some((3, 4)));
+        assert!(pic.exceeds_threshold(4, 15, 15));
+        assert!(!pic.exceeds_threshold(4, 3, 15));
+    }
+
+    #[test]
+    fn test_priority_zero_never_interrupts() {
+        let pic = Pic::new();
+        let mut regs = pic.mmio_regs();
+        pic.register_irq(1).set_level(true);
+        write(&mut regs, 0x2004, 1);
+
+        assert_eq!(regs.read(RvSize::Word, 0x1000).unwrap(), 0x2);
+        assert_eq!(pic.highest_priority_irq(), None);
+    }
+
+    #[test]
+    fn test_gateway() {
+        let pic = Pic::new();
+        let mut regs = pic.mmio_regs();
+        let irq = pic.register_irq(2);
+        write(&mut regs, 0x0008, 1);
+        write(&mut regs, 0x2008, 1);
+
+        // Active low, level triggered
+        write(&mut regs, 0x4008, 1);
+        assert_eq!(regs.read(RvSize::Word, 0x4008).unwrap(), 1);
+        assert_eq!(pic.highest_priority_irq(), Some((2, 1)));
+        irq.set_level(true);
+        assert_eq!(pic.highest_priority_irq(), None);
+
+        // Active high, edge triggered
+        write(&mut regs, 0x4008, 2);
+        irq.set_level(false);
+        assert_eq!(pic.highest_priority_irq(), None);
+        irq.set_level(true);
+        irq.set_level(false);
+        assert_eq!(pic.highest_priority_irq(), Some((2, 1)));
+        write(&mut regs, 0x5008, 0);
+        assert_eq!(pic.highest_priority_irq(), None);
+    }
+
+    #[test]
+    fn test_access_faults() {
+        let pic = Pic::new();
+        let mut regs = pic.mmio_regs();
+        assert_eq!(
+            regs.read(RvSize::HalfWord, 0x0004).err(),
+            Some(BusError::LoadAccessFault)
+        );
+        assert_eq!(
+            regs.read(RvSize::Word, 0x0080).err(),
+            Some(BusError::LoadAccessFault)
+        );
+        assert_eq!(
+            regs.write(RvSize::Word, 0x1004, 0).err(),
+            Some(BusError::StoreAccessFault)
+        );
+        assert_eq!(
+            regs.write(RvSize::Word, 0x6000, 0).err(),
+            Some(BusError::StoreAccessFault)
+        );
+    }
+}
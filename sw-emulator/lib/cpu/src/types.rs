@@ -629,3 +629,12 @@ bitfield! {
     /// Machine Mode Previous Interrupt Enable
     pub u32, mpie, set_mpie: 7, 7;
 }
+
+bitfield! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    /// RISCV Machine Mode Interrupt Enable & Pending Registers
+    pub struct RvMInterrupts(u32);
+
+    /// Machine Mode External Interrupt
+    pub u32, mei, set_mei: 11, 11;
+}
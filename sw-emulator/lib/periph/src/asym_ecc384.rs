@@ -13,11 +13,13 @@ Abstract:
 --*/
 
 use crate::helpers::{bytes_from_words_le, words_from_bytes_le};
-use crate::{HashSha512, KeyUsage, KeyVault};
+use crate::{HashSha512, IntrBlock, KeyUsage, KeyVault};
 use caliptra_emu_bus::{ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer};
+use caliptra_emu_cpu::Irq;
 use caliptra_emu_crypto::{Ecc384, Ecc384PubKey, Ecc384Signature};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvData, RvSize};
+use caliptra_registers::ecc::regs::NotifIntrTWriteVal;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
 use tock_registers::register_bitfields;
 use tock_registers::registers::InMemoryRegister;
@@ -204,6 +206,10 @@ pub struct AsymEcc384 {
     #[register(offset = 0x0000_0614)]
     key_write_status: ReadOnlyRegister<u32, KeyWriteStatus::Register>,
 
+    /// Interrupt register block
+    #[peripheral(offset = 0x0000_0800, mask = 0x0000_00ff)]
+    intr: IntrBlock,
+
     /// Key Vault
     key_vault: KeyVault,
 
@@ -239,7 +245,13 @@ impl AsymEcc384 {
     const VERSION1_VAL: RvData = 0x00000000;
 
     /// Create a new instance of ECC-384 Engine
-    pub fn new(clock: &Clock, key_vault: KeyVault, hash_sha512: HashSha512) -> Self {
+    pub fn new(
+        clock: &Clock,
+        key_vault: KeyVault,
+        hash_sha512: HashSha512,
+        error_irq: Irq,
+        notif_irq: Irq,
+    ) -> Self {
         Self {
             name0: ReadOnlyRegister::new(Self::NAME0_VAL),
             name1: ReadOnlyRegister::new(Self::NAME1_VAL),
@@ -265,6 +277,7 @@ impl AsymEcc384 {
             seed_read_status: ReadOnlyRegister::new(KeyReadStatus::READY::SET.value),
             key_write_ctrl: ReadWriteRegister::new(0),
             key_write_status: ReadOnlyRegister::new(KeyWriteStatus::READY::SET.value),
+            intr: IntrBlock::new(error_irq, notif_irq),
             key_vault,
             hash_sha512,
             timer: Timer::new(clock),
@@ -478,6 +491,9 @@ impl AsymEcc384 {
         self.status
             .reg
             .modify(Status::READY::SET + Status::VALID::SET);
+
+        self.intr
+            .set_notif(NotifIntrTWriteVal::from(0).notif_cmd_done_sts(true).into());
     }
 
     fn key_read_complete(&mut self) {
@@ -668,7 +684,9 @@ impl AsymEcc384 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::CaliptraRootBus;
     use caliptra_emu_bus::Bus;
+    use caliptra_emu_cpu::Pic;
     use caliptra_emu_crypto::EndianessTransform;
     use caliptra_emu_types::RvAddr;
     use tock_registers::registers::InMemoryRegister;
@@ -730,6 +748,23 @@ mod tests {
         0x85, 0xf8, 0x7d,
     ];
 
+    fn new_ecc(clock: &Clock, key_vault: KeyVault) -> AsymEcc384 {
+        let pic = Pic::new();
+        let sha512 = HashSha512::new(
+            clock,
+            key_vault.clone(),
+            pic.register_irq(CaliptraRootBus::SHA512_ERROR_INT),
+            pic.register_irq(CaliptraRootBus::SHA512_NOTIF_INT),
+        );
+        AsymEcc384::new(
+            clock,
+            key_vault,
+            sha512,
+            pic.register_irq(CaliptraRootBus::ECC_ERROR_INT),
+            pic.register_irq(CaliptraRootBus::ECC_NOTIF_INT),
+        )
+    }
+
     fn make_word(idx: usize, arr: &[u8]) -> RvData {
         let mut res: RvData = 0;
         for i in 0..4 {
@@ -742,9 +777,7 @@ mod tests {
     fn test_name() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
 
         let name0 = ecc.read(RvSize::Word, OFFSET_NAME0).unwrap();
         let name0 = String::from_utf8_lossy(&name0.to_be_bytes()).to_string();
@@ -759,9 +792,7 @@ mod tests {
     fn test_version() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
 
         let version0 = ecc.read(RvSize::Word, OFFSET_VERSION0).unwrap();
         let version0 = String::from_utf8_lossy(&version0.to_le_bytes()).to_string();
@@ -776,9 +807,7 @@ mod tests {
     fn test_control() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
         assert_eq!(ecc.read(RvSize::Word, OFFSET_CONTROL).unwrap(), 0);
     }
 
@@ -786,9 +815,7 @@ mod tests {
     fn test_status() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
         assert_eq!(ecc.read(RvSize::Word, OFFSET_STATUS).unwrap(), 1);
     }
 
@@ -796,9 +823,7 @@ mod tests {
     fn test_gen_key() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
 
         let mut seed = [0u8; 48];
         seed.to_big_endian(); // Change DWORDs to big-endian.
@@ -872,8 +897,7 @@ mod tests {
                 .write_key(key_id, &seed, u32::from(key_usage))
                 .unwrap();
 
-            let sha512 = HashSha512::new(&clock, key_vault.clone());
-            let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+            let mut ecc = new_ecc(&clock, key_vault);
 
             // Instruct seed to be read from key-vault.
             let seed_ctrl = InMemoryRegister::<u32, KeyReadControl::Register>::new(0);
@@ -942,9 +966,7 @@ mod tests {
             seed.to_big_endian(); // Change DWORDs to big-endian.
 
             let key_vault = KeyVault::new();
-            let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-            let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+            let mut ecc = new_ecc(&clock, key_vault);
 
             for i in (0..seed.len()).step_by(4) {
                 assert_eq!(
@@ -1023,9 +1045,7 @@ mod tests {
     fn test_sign() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
 
         let mut hash = [0u8; KeyVault::KEY_SIZE];
         hash.to_big_endian(); // Change DWORDs to big-endian.
@@ -1096,8 +1116,7 @@ mod tests {
                 .write_key(key_id, &priv_key, u32::from(key_usage))
                 .unwrap();
 
-            let sha512 = HashSha512::new(&clock, key_vault.clone());
-            let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+            let mut ecc = new_ecc(&clock, key_vault);
 
             let mut hash = [0u8; 48];
             hash.to_big_endian(); // Change DWORDs to big-endian.
@@ -1178,8 +1197,7 @@ mod tests {
                 .write_key(key_id, &priv_key, !(u32::from(key_usage)))
                 .unwrap();
 
-            let sha512 = HashSha512::new(&clock, key_vault.clone());
-            let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+            let mut ecc = new_ecc(&clock, key_vault);
 
             let mut hash = [0u8; 48];
             hash.to_big_endian(); // Change DWORDs to big-endian.
@@ -1224,9 +1242,7 @@ mod tests {
     fn test_verify() {
         let clock = Clock::new();
         let key_vault = KeyVault::new();
-        let sha512 = HashSha512::new(&clock, key_vault.clone());
-
-        let mut ecc = AsymEcc384::new(&clock, key_vault, sha512);
+        let mut ecc = new_ecc(&clock, key_vault);
 
         let hash = [0u8; KeyVault::KEY_SIZE];
         for i in (0..hash.len()).step_by(4) {
@@ -212,7 +212,10 @@ impl Doe {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CaliptraRootBusArgs, Iccm, KeyUsage, MailboxInternal, MailboxRam};
+    use crate::{
+        intr_block::new_soc_ifc_intr, CaliptraRootBusArgs, Iccm, KeyUsage, MailboxInternal,
+        MailboxRam,
+    };
     use caliptra_emu_bus::Bus;
     use caliptra_emu_crypto::EndianessTransform;
     use caliptra_emu_types::RvAddr;
@@ -248,10 +251,12 @@ mod tests {
 
         let clock = Clock::new();
         let key_vault = KeyVault::new();
+        let intr = new_soc_ifc_intr();
         let soc_reg = SocRegistersInternal::new(
             &clock,
-            MailboxInternal::new(MailboxRam::new()),
+            MailboxInternal::new(MailboxRam::new(), intr.clone()),
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs {
                 security_state: *SecurityState::default().set_debug_locked(true),
                 ..CaliptraRootBusArgs::default()
@@ -309,10 +314,12 @@ mod tests {
         ];
         let clock = Clock::new();
         let key_vault = KeyVault::new();
+        let intr = new_soc_ifc_intr();
         let soc_reg = SocRegistersInternal::new(
             &clock,
-            MailboxInternal::new(MailboxRam::new()),
+            MailboxInternal::new(MailboxRam::new(), intr.clone()),
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs {
                 security_state: *SecurityState::default().set_debug_locked(true),
                 ..CaliptraRootBusArgs::default()
@@ -370,10 +377,12 @@ mod tests {
         let expected_fe = [0u8; 32];
         let clock = Clock::new();
         let key_vault = KeyVault::new();
+        let intr = new_soc_ifc_intr();
         let soc_reg = SocRegistersInternal::new(
             &clock,
-            MailboxInternal::new(MailboxRam::new()),
+            MailboxInternal::new(MailboxRam::new(), intr.clone()),
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs {
                 security_state: *SecurityState::default().set_debug_locked(true),
                 ..CaliptraRootBusArgs::default()
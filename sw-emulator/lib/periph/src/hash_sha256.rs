@@ -12,13 +12,16 @@ Abstract:
 
 --*/
 
+use crate::IntrBlock;
 use caliptra_emu_bus::{
     ActionHandle, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister, ReadWriteMemory,
     ReadWriteRegister, Timer,
 };
+use caliptra_emu_cpu::Irq;
 use caliptra_emu_crypto::{Sha256, Sha256Mode};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvData, RvSize};
+use caliptra_registers::sha256::regs::NotifIntrTWriteVal;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
 use tock_registers::register_bitfields;
 
@@ -92,6 +95,10 @@ pub struct HashSha256 {
     #[peripheral(offset = 0x0000_0100, mask = 0x0000_00ff)]
     hash: ReadOnlyMemory<SHA256_HASH_SIZE>,
 
+    /// Interrupt register block
+    #[peripheral(offset = 0x0000_0800, mask = 0x0000_00ff)]
+    intr: IntrBlock,
+
     /// SHA256 engine
     sha256: Sha256,
 
@@ -114,7 +121,7 @@ impl HashSha256 {
     const VERSION1_VAL: RvData = 0x00000000;
 
     /// Create a new instance of SHA-512 Engine
-    pub fn new(clock: &Clock) -> Self {
+    pub fn new(clock: &Clock, error_irq: Irq, notif_irq: Irq) -> Self {
         Self {
             sha256: Sha256::new(Sha256Mode::Sha256), // Default SHA256 mode
             name0: ReadOnlyRegister::new(Self::NAME0_VAL),
@@ -125,6 +132,7 @@ impl HashSha256 {
             status: ReadOnlyRegister::new(Status::READY::SET.value),
             block: ReadWriteMemory::new(),
             hash: ReadOnlyMemory::new(),
+            intr: IntrBlock::new(error_irq, notif_irq),
             timer: Timer::new(clock),
             op_complete_action: None,
         }
@@ -203,6 +211,9 @@ impl HashSha256 {
             self.status
                 .reg
                 .modify(Status::READY::SET + Status::VALID::SET);
+
+            self.intr
+                .set_notif(NotifIntrTWriteVal::from(0).notif_cmd_done_sts(true).into());
         }
     }
 
@@ -229,7 +240,9 @@ impl HashSha256 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::CaliptraRootBus;
     use caliptra_emu_bus::Bus;
+    use caliptra_emu_cpu::Pic;
     use caliptra_emu_crypto::EndianessTransform;
     use caliptra_emu_types::RvAddr;
     use tock_registers::registers::InMemoryRegister;
@@ -243,9 +256,18 @@ mod tests {
     const OFFSET_BLOCK: RvAddr = 0x80;
     const OFFSET_HASH: RvAddr = 0x100;
 
+    fn new_sha256(clock: &Clock) -> HashSha256 {
+        let pic = Pic::new();
+        HashSha256::new(
+            clock,
+            pic.register_irq(CaliptraRootBus::SHA256_ERROR_INT),
+            pic.register_irq(CaliptraRootBus::SHA256_NOTIF_INT),
+        )
+    }
+
     #[test]
     fn test_name_read() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
 
         let name0 = sha256.read(RvSize::Word, OFFSET_NAME0).unwrap();
         let mut name0 = String::from_utf8_lossy(&name0.to_le_bytes()).to_string();
@@ -259,7 +281,7 @@ mod tests {
 
     #[test]
     fn test_version_read() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
 
         let version0 = sha256.read(RvSize::Word, OFFSET_VERSION0).unwrap();
         let version0 = String::from_utf8_lossy(&version0.to_le_bytes()).to_string();
@@ -272,19 +294,19 @@ mod tests {
 
     #[test]
     fn test_control_read() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
         assert_eq!(sha256.read(RvSize::Word, OFFSET_CONTROL).unwrap(), 0);
     }
 
     #[test]
     fn test_status_read() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
         assert_eq!(sha256.read(RvSize::Word, OFFSET_STATUS).unwrap(), 1);
     }
 
     #[test]
     fn test_block_read_write() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
         for addr in (OFFSET_BLOCK..(OFFSET_BLOCK + SHA256_BLOCK_SIZE as u32)).step_by(4) {
             assert_eq!(sha256.write(RvSize::Word, addr, u32::MAX).ok(), Some(()));
             assert_eq!(sha256.read(RvSize::Word, addr).ok(), Some(u32::MAX));
@@ -293,7 +315,7 @@ mod tests {
 
     #[test]
     fn test_hash_read_write() {
-        let mut sha256 = HashSha256::new(&Clock::new());
+        let mut sha256 = new_sha256(&Clock::new());
         for addr in (OFFSET_HASH..(OFFSET_HASH + SHA256_HASH_SIZE as u32)).step_by(4) {
             assert_eq!(sha256.read(RvSize::Word, addr).ok(), Some(0));
             assert_eq!(
@@ -328,7 +350,7 @@ mod tests {
         block_arr.to_big_endian();
 
         let clock = Clock::new();
-        let mut sha256 = HashSha256::new(&clock);
+        let mut sha256 = new_sha256(&clock);
 
         // Process each block via the SHA engine.
         for idx in 0..totalblocks {
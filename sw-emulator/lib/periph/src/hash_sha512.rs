@@ -14,15 +14,17 @@ Abstract:
 
 use crate::helpers::words_from_bytes_le;
 use crate::key_vault::KeyUsage;
-use crate::KeyVault;
+use crate::{IntrBlock, KeyVault};
 use caliptra_emu_bus::{
     ActionHandle, Bus, BusError, Clock, ReadOnlyMemory, ReadOnlyRegister, ReadWriteRegister, Timer,
     WriteOnlyRegister,
 };
+use caliptra_emu_cpu::Irq;
 use caliptra_emu_crypto::EndianessTransform;
 use caliptra_emu_crypto::{Sha512, Sha512Mode};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
+use caliptra_registers::sha512::regs::NotifIntrTWriteVal;
 use std::cell::RefCell;
 use std::rc::Rc;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
@@ -205,6 +207,10 @@ pub struct HashSha512Regs {
     #[register_array(offset = 0x0000_0638, write_fn = write_access_fault)]
     pcr_hash_digest: [u32; SHA384_HASH_SIZE / 4],
 
+    /// Interrupt register block
+    #[peripheral(offset = 0x0000_0800, mask = 0x0000_00ff)]
+    intr: IntrBlock,
+
     /// SHA512 engine
     sha512: Sha512,
 
@@ -242,7 +248,7 @@ impl HashSha512Regs {
     const VERSION1_VAL: RvData = 0x00000000;
 
     /// Create a new instance of SHA-512 Engine
-    pub fn new(clock: &Clock, key_vault: KeyVault) -> Self {
+    pub fn new(clock: &Clock, key_vault: KeyVault, error_irq: Irq, notif_irq: Irq) -> Self {
         Self {
             sha512: Sha512::new(Sha512Mode::Sha512), // Default SHA512 mode
             name0: ReadOnlyRegister::new(Self::NAME0_VAL),
@@ -259,6 +265,7 @@ impl HashSha512Regs {
             pcr_hash_control: WriteOnlyRegister::new(0),
             pcr_hash_status: ReadOnlyRegister::new(PcrHashStatus::READY::SET.value),
             pcr_hash_digest: Default::default(),
+            intr: IntrBlock::new(error_irq, notif_irq),
             block: Default::default(),
             hash: ReadOnlyMemory::new(),
             key_vault,
@@ -548,6 +555,9 @@ impl HashSha512Regs {
         self.status
             .reg
             .modify(Status::READY::SET + Status::VALID::SET);
+
+        self.intr
+            .set_notif(NotifIntrTWriteVal::from(0).notif_cmd_done_sts(true).into());
     }
 
     fn block_read_complete(&mut self) {
@@ -694,9 +704,11 @@ pub struct HashSha512 {
 
 impl HashSha512 {
     /// Create a new instance of Hash SHA-512
-    pub fn new(clock: &Clock, key_vault: KeyVault) -> Self {
+    pub fn new(clock: &Clock, key_vault: KeyVault, error_irq: Irq, notif_irq: Irq) -> Self {
         Self {
-            regs: Rc::new(RefCell::new(HashSha512Regs::new(clock, key_vault))),
+            regs: Rc::new(RefCell::new(HashSha512Regs::new(
+                clock, key_vault, error_irq, notif_irq,
+            ))),
         }
     }
 
@@ -725,13 +737,22 @@ impl Bus for HashSha512 {
     fn poll(&mut self) {
         self.regs.borrow_mut().poll();
     }
+
+    fn warm_reset(&mut self) {
+        Bus::warm_reset(&mut *self.regs.borrow_mut());
+    }
+
+    fn update_reset(&mut self) {
+        Bus::update_reset(&mut *self.regs.borrow_mut());
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::key_vault;
+    use crate::{key_vault, CaliptraRootBus};
     use caliptra_emu_bus::Bus;
+    use caliptra_emu_cpu::Pic;
     use caliptra_emu_crypto::EndianessTransform;
     use caliptra_emu_types::RvAddr;
     use tock_registers::registers::InMemoryRegister;
@@ -750,11 +771,21 @@ mod tests {
     const OFFSET_HASH_CONTROL: RvAddr = 0x608;
     const OFFSET_HASH_STATUS: RvAddr = 0x60c;
 
+    fn new_sha512(clock: &Clock, key_vault: KeyVault) -> HashSha512Regs {
+        let pic = Pic::new();
+        HashSha512Regs::new(
+            clock,
+            key_vault,
+            pic.register_irq(CaliptraRootBus::SHA512_ERROR_INT),
+            pic.register_irq(CaliptraRootBus::SHA512_NOTIF_INT),
+        )
+    }
+
     const SHA384_HASH_SIZE: usize = 48;
 
     #[test]
     fn test_name_read() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
 
         let name0 = sha512.read(RvSize::Word, OFFSET_NAME0).unwrap();
         let mut name0 = String::from_utf8_lossy(&name0.to_le_bytes()).to_string();
@@ -768,7 +799,7 @@ mod tests {
 
     #[test]
     fn test_version_read() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
 
         let version0 = sha512.read(RvSize::Word, OFFSET_VERSION0).unwrap();
         let version0 = String::from_utf8_lossy(&version0.to_le_bytes()).to_string();
@@ -781,19 +812,19 @@ mod tests {
 
     #[test]
     fn test_control_read() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
         assert_eq!(sha512.read(RvSize::Word, OFFSET_CONTROL).unwrap(), 0);
     }
 
     #[test]
     fn test_status_read() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
         assert_eq!(sha512.read(RvSize::Word, OFFSET_STATUS).unwrap(), 1);
     }
 
     #[test]
     fn test_block_read_write() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
         for addr in (OFFSET_BLOCK..(OFFSET_BLOCK + SHA512_BLOCK_SIZE as u32)).step_by(4) {
             assert_eq!(sha512.write(RvSize::Word, addr, u32::MAX).ok(), Some(()));
             assert_eq!(sha512.read(RvSize::Word, addr).ok(), Some(u32::MAX));
@@ -802,7 +833,7 @@ mod tests {
 
     #[test]
     fn test_hash_read_write() {
-        let mut sha512 = HashSha512Regs::new(&Clock::new(), KeyVault::new());
+        let mut sha512 = new_sha512(&Clock::new(), KeyVault::new());
         for addr in (OFFSET_HASH..(OFFSET_HASH + SHA512_HASH_SIZE as u32)).step_by(4) {
             assert_eq!(sha512.read(RvSize::Word, addr).ok(), Some(0));
             assert_eq!(
@@ -946,7 +977,7 @@ mod tests {
             );
         }
 
-        let mut sha512 = HashSha512Regs::new(&clock, key_vault);
+        let mut sha512 = new_sha512(&clock, key_vault);
 
         if hash_to_kv {
             // Instruct hash to be written to the key-vault.
@@ -1330,7 +1361,7 @@ mod tests {
         assert!(key_vault.write_pcr(pcr_id, pcr_data).is_ok());
         pcr_data.change_endianess();
 
-        let mut sha512 = HashSha512Regs::new(&clock, key_vault);
+        let mut sha512 = new_sha512(&clock, key_vault);
         // Enable pcr hash extend.
         let block_ctrl = InMemoryRegister::<u32, BlockReadControl::Register>::new(0);
         block_ctrl.modify(
@@ -13,12 +13,14 @@ Abstract:
 --*/
 
 use crate::helpers::bytes_from_words_le;
-use crate::{KeyUsage, KeyVault};
+use crate::{IntrBlock, KeyUsage, KeyVault};
 use caliptra_emu_bus::{ActionHandle, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Timer};
+use caliptra_emu_cpu::Irq;
 use caliptra_emu_crypto::EndianessTransform;
 use caliptra_emu_crypto::{Hmac512, Hmac512Mode};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvData, RvSize};
+use caliptra_registers::hmac::regs::NotifIntrTWriteVal;
 use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
 use tock_registers::register_bitfields;
 use tock_registers::registers::InMemoryRegister;
@@ -175,6 +177,10 @@ pub struct HmacSha384 {
     #[register(offset = 0x0000_0614)]
     tag_write_status: ReadOnlyRegister<u32, TagWriteStatus::Register>,
 
+    /// Interrupt register block
+    #[peripheral(offset = 0x0000_0800, mask = 0x0000_00ff)]
+    intr: IntrBlock,
+
     // True if the current key was read from the key-vault
     key_from_kv: bool,
 
@@ -225,11 +231,13 @@ impl HmacSha384 {
     ///
     /// * `clock` - Clock
     /// * `key_vault` - Key Vault
+    /// * `error_irq` - Error interrupt line
+    /// * `notif_irq` - Notification interrupt line
     ///
     /// # Returns
     ///
     /// * `Self` - Instance of HMAC-SHA-384 Engine
-    pub fn new(clock: &Clock, key_vault: KeyVault) -> Self {
+    pub fn new(clock: &Clock, key_vault: KeyVault, error_irq: Irq, notif_irq: Irq) -> Self {
         Self {
             hmac: Hmac512::<HMAC_KEY_SIZE>::new(Hmac512Mode::Sha384),
             name0: ReadOnlyRegister::new(Self::NAME0_VAL),
@@ -248,6 +256,7 @@ impl HmacSha384 {
             block_read_status: ReadOnlyRegister::new(KeyReadStatus::READY::SET.value),
             tag_write_ctrl: ReadWriteRegister::new(0),
             tag_write_status: ReadOnlyRegister::new(TagWriteStatus::READY::SET.value),
+            intr: IntrBlock::new(error_irq, notif_irq),
             key_vault,
             timer: Timer::new(clock),
             key_from_kv: false,
@@ -508,6 +517,9 @@ impl HmacSha384 {
         self.status
             .reg
             .modify(Status::READY::SET + Status::VALID::SET);
+
+        self.intr
+            .set_notif(NotifIntrTWriteVal::from(0).notif_cmd_done_sts(true).into());
     }
 
     fn key_read_complete(&mut self) {
@@ -641,8 +653,9 @@ impl HmacSha384 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::key_vault;
+    use crate::{key_vault, CaliptraRootBus};
     use caliptra_emu_bus::Bus;
+    use caliptra_emu_cpu::Pic;
     use caliptra_emu_crypto::EndianessTransform;
     use caliptra_emu_types::RvAddr;
     use tock_registers::registers::InMemoryRegister;
@@ -663,10 +676,21 @@ mod tests {
     const OFFSET_BLOCK_STATUS: RvAddr = 0x60c;
     const OFFSET_TAG_CONTROL: RvAddr = 0x610;
     const OFFSET_TAG_STATUS: RvAddr = 0x614;
+    const OFFSET_NOTIF_INTERNAL_INTR: RvAddr = 0x818;
+
+    fn new_hmac(clock: &Clock, key_vault: KeyVault) -> HmacSha384 {
+        let pic = Pic::new();
+        HmacSha384::new(
+            clock,
+            key_vault,
+            pic.register_irq(CaliptraRootBus::HMAC_ERROR_INT),
+            pic.register_irq(CaliptraRootBus::HMAC_NOTIF_INT),
+        )
+    }
 
     #[test]
     fn test_name() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
 
         let name0 = hmac.read(RvSize::Word, OFFSET_NAME0).unwrap();
         let name0 = String::from_utf8_lossy(&name0.to_le_bytes()).to_string();
@@ -679,7 +703,7 @@ mod tests {
 
     #[test]
     fn test_version() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
 
         let version0 = hmac.read(RvSize::Word, OFFSET_VERSION0).unwrap();
         let version0 = String::from_utf8_lossy(&version0.to_le_bytes()).to_string();
@@ -692,19 +716,19 @@ mod tests {
 
     #[test]
     fn test_control() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
         assert_eq!(hmac.read(RvSize::Word, OFFSET_CONTROL).unwrap(), 0);
     }
 
     #[test]
     fn test_status() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
         assert_eq!(hmac.read(RvSize::Word, OFFSET_STATUS).unwrap(), 1);
     }
 
     #[test]
     fn test_key() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
         for addr in (OFFSET_KEY..(OFFSET_KEY + HMAC_KEY_SIZE as u32)).step_by(4) {
             assert_eq!(hmac.write(RvSize::Word, addr, 0xFF).ok(), Some(()));
             assert_eq!(
@@ -716,7 +740,7 @@ mod tests {
 
     #[test]
     fn test_block() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
         for addr in (OFFSET_BLOCK..(OFFSET_BLOCK + HMAC_BLOCK_SIZE as u32)).step_by(4) {
             assert_eq!(hmac.write(RvSize::Word, addr, u32::MAX).ok(), Some(()));
             assert_eq!(
@@ -728,7 +752,7 @@ mod tests {
 
     #[test]
     fn test_tag() {
-        let mut hmac = HmacSha384::new(&Clock::new(), KeyVault::new());
+        let mut hmac = new_hmac(&Clock::new(), KeyVault::new());
         for addr in (OFFSET_TAG..(OFFSET_TAG + HMAC_TAG_SIZE as u32)).step_by(4) {
             assert_eq!(hmac.read(RvSize::Word, addr).ok(), Some(0));
             assert_eq!(
@@ -738,6 +762,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_notif_intr() {
+        let clock = Clock::new();
+        let mut hmac = new_hmac(&clock, KeyVault::new());
+        assert_eq!(
+            hmac.read(RvSize::Word, OFFSET_NOTIF_INTERNAL_INTR).ok(),
+            Some(0)
+        );
+
+        assert_eq!(
+            hmac.write(RvSize::Word, OFFSET_CONTROL, Control::INIT::SET.into())
+                .ok(),
+            Some(())
+        );
+        loop {
+            let status = InMemoryRegister::<u32, Status::Register>::new(
+                hmac.read(RvSize::Word, OFFSET_STATUS).unwrap(),
+            );
+            if status.is_set(Status::VALID) && status.is_set(Status::READY) {
+                break;
+            }
+            clock.increment_and_process_timer_actions(1, &mut hmac);
+        }
+
+        assert_eq!(
+            hmac.read(RvSize::Word, OFFSET_NOTIF_INTERNAL_INTR).ok(),
+            Some(1)
+        );
+    }
+
     enum KeyVaultAction {
         KeyFromVault(u32),
         BlockFromVault(u32),
@@ -924,7 +978,7 @@ mod tests {
             );
         }
 
-        let mut hmac = HmacSha384::new(&clock, key_vault);
+        let mut hmac = new_hmac(&clock, key_vault);
 
         if tag_to_kv {
             // Instruct tag to be read from key-vault.
@@ -0,0 +1,278 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    intr_block.rs
+
+Abstract:
+
+    File contains the implementation of the interrupt register block shared
+    by the Caliptra peripherals.
+
+--*/
+
+use caliptra_emu_bus::{Bus, BusError};
+use caliptra_emu_cpu::Irq;
+use caliptra_emu_derive::Bus;
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+use std::{cell::RefCell, rc::Rc};
+
+/// Interrupt register block (`intr_block_rf`) of a peripheral
+///
+/// The block aggregates the error and notification interrupt status bits of
+/// the peripheral into its error and notification interrupt lines.
+#[derive(Clone)]
+pub struct IntrBlock {
+    regs: Rc<RefCell<IntrBlockRegs>>,
+}
+
+impl IntrBlock {
+    /// Error interrupt enable bit of GLOBAL_INTR_EN_R
+    const ERROR_EN: u32 = 1 << 0;
+
+    /// Notification interrupt enable bit of GLOBAL_INTR_EN_R
+    const NOTIF_EN: u32 = 1 << 1;
+
+    /// Create a new interrupt register block
+    ///
+    /// # Arguments
+    ///
+    /// * `error_irq` - Error interrupt line
+    /// * `notif_irq` - Notification interrupt line
+    pub fn new(error_irq: Irq, notif_irq: Irq) -> Self {
+        Self {
+            regs: Rc::new(RefCell::new(IntrBlockRegs::new(error_irq, notif_irq))),
+        }
+    }
+
+    /// Set the error interrupt status bits in `mask`
+    pub fn set_error(&self, mask: u32) {
+        let mut regs = self.regs.borrow_mut();
+        regs.error_internal_intr |= mask;
+        regs.update();
+    }
+
+    /// Clear the error interrupt status bits in `mask`
+    pub fn clear_error(&self, mask: u32) {
+        let mut regs = self.regs.borrow_mut();
+        regs.error_internal_intr &= !mask;
+        regs.update();
+    }
+
+    /// Set the notification interrupt status bits in `mask`
+    pub fn set_notif(&self, mask: u32) {
+        let mut regs = self.regs.borrow_mut();
+        regs.notif_internal_intr |= mask;
+        regs.update();
+    }
+}
+
+/// Create an interrupt register block wired to the SoC interface interrupt
+/// lines of a standalone PIC, for peripheral tests
+#[cfg(test)]
+pub(crate) fn new_soc_ifc_intr() -> IntrBlock {
+    let pic = caliptra_emu_cpu::Pic::new();
+    IntrBlock::new(
+        pic.register_irq(crate::CaliptraRootBus::SOC_IFC_ERROR_INT),
+        pic.register_irq(crate::CaliptraRootBus::SOC_IFC_NOTIF_INT),
+    )
+}
+
+impl Bus for IntrBlock {
+    /// Read data of specified size from given address
+    fn read(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, BusError> {
+        self.regs.borrow_mut().read(size, addr)
+    }
+
+    /// Write data of specified size to given address
+    fn write(&mut self, size: RvSize, addr: RvAddr, val: RvData) -> Result<(), BusError> {
+        self.regs.borrow_mut().write(size, addr, val)
+    }
+
+    fn warm_reset(&mut self) {
+        self.regs.borrow_mut().reset();
+    }
+}
+
+#[derive(Bus)]
+struct IntrBlockRegs {
+    /// GLOBAL_INTR_EN_R Register
+    #[register(offset = 0x0000, write_fn = on_write_global_intr_en)]
+    global_intr_en: u32,
+
+    /// ERROR_INTR_EN_R Register
+    #[register(offset = 0x0004, write_fn = on_write_error_intr_en)]
+    error_intr_en: u32,
+
+    /// NOTIF_INTR_EN_R Register
+    #[register(offset = 0x0008, write_fn = on_write_notif_intr_en)]
+    notif_intr_en: u32,
+
+    /// ERROR_GLOBAL_INTR_R Register
+    #[register(offset = 0x000c, write_fn = write_disabled)]
+    error_global_intr: u32,
+
+    /// NOTIF_GLOBAL_INTR_R Register
+    #[register(offset = 0x0010, write_fn = write_disabled)]
+    notif_global_intr: u32,
+
+    /// ERROR_INTERNAL_INTR_R Register
+    #[register(offset = 0x0014, write_fn = on_write_error_internal_intr)]
+    error_internal_intr: u32,
+
+    /// NOTIF_INTERNAL_INTR_R Register
+    #[register(offset = 0x0018, write_fn = on_write_notif_internal_intr)]
+    notif_internal_intr: u32,
+
+    /// ERROR_INTR_TRIG_R Register
+    #[register(offset = 0x001c, write_fn = on_write_error_intr_trig)]
+    error_intr_trig: u32,
+
+    /// NOTIF_INTR_TRIG_R Register
+    #[register(offset = 0x0020, write_fn = on_write_notif_intr_trig)]
+    notif_intr_trig: u32,
+
+    /// Error interrupt line
+    error_irq: Irq,
+
+    /// Notification interrupt line
+    notif_irq: Irq,
+}
+
+impl IntrBlockRegs {
+    fn new(error_irq: Irq, notif_irq: Irq) -> Self {
+        Self {
+            global_intr_en: 0,
+            error_intr_en: 0,
+            notif_intr_en: 0,
+            error_global_intr: 0,
+            notif_global_intr: 0,
+            error_internal_intr: 0,
+            notif_internal_intr: 0,
+            error_intr_trig: 0,
+            notif_intr_trig: 0,
+            error_irq,
+            notif_irq,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.global_intr_en = 0;
+        self.error_intr_en = 0;
+        self.notif_intr_en = 0;
+        self.error_internal_intr = 0;
+        self.notif_internal_intr = 0;
+        self.update();
+    }
+
+    /// Update the aggregated status registers and the interrupt lines
+    fn update(&mut self) {
+        self.error_global_intr = u32::from(self.error_internal_intr & self.error_intr_en != 0);
+        self.notif_global_intr = u32::from(self.notif_internal_intr & self.notif_intr_en != 0);
+        self.error_irq.set_level(
+            self.error_global_intr != 0 && self.global_intr_en & IntrBlock::ERROR_EN != 0,
+        );
+        self.notif_irq.set_level(
+            self.notif_global_intr != 0 && self.global_intr_en & IntrBlock::NOTIF_EN != 0,
+        );
+    }
+
+    fn write_disabled(&mut self, _size: RvSize, _val: RvData) -> Result<(), BusError> {
+        Ok(())
+    }
+
+    fn on_write_global_intr_en(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.global_intr_en = val & (IntrBlock::ERROR_EN | IntrBlock::NOTIF_EN);
+        self.update();
+        Ok(())
+    }
+
+    fn on_write_error_intr_en(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.error_intr_en = val;
+        self.update();
+        Ok(())
+    }
+
+    fn on_write_notif_intr_en(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.notif_intr_en = val;
+        self.update();
+        Ok(())
+    }
+
+    /// The status bits are cleared by writing 1 to them
+    fn on_write_error_internal_intr(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.error_internal_intr &= !val;
+        self.update();
+        Ok(())
+    }
+
+    /// The status bits are cleared by writing 1 to them
+    fn on_write_notif_internal_intr(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.notif_internal_intr &= !val;
+        self.update();
+        Ok(())
+    }
+
+    /// Writing 1 to a trigger bit sets the matching status bit, for testing
+    /// interrupt handlers
+    fn on_write_error_intr_trig(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.error_internal_intr |= val;
+        self.update();
+        Ok(())
+    }
+
+    /// Writing 1 to a trigger bit sets the matching status bit, for testing
+    /// interrupt handlers
+    fn on_write_notif_intr_trig(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
+        self.notif_internal_intr |= val;
+        self.update();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_emu_cpu::{Pic, PicMmioRegisters};
+
+    const GLOBAL_INTR_EN_R: RvAddr = 0x00;
+    const NOTIF_INTR_EN_R: RvAddr = 0x08;
+    const NOTIF_GLOBAL_INTR_R: RvAddr = 0x10;
+    const NOTIF_INTERNAL_INTR_R: RvAddr = 0x18;
+    const NOTIF_INTR_TRIG_R: RvAddr = 0x20;
+
+    fn meip(pic_regs: &mut PicMmioRegisters) -> u32 {
+        pic_regs.read(RvSize::Word, 0x1000).unwrap()
+    }
+
+    #[test]
+    fn test_notif_intr() {
+        let pic = Pic::new();
+        let mut pic_regs = pic.mmio_regs();
+        let mut intr = IntrBlock::new(pic.register_irq(1), pic.register_irq(2));
+
+        intr.set_notif(1);
+        assert_eq!(intr.read(RvSize::Word, NOTIF_INTERNAL_INTR_R).unwrap(), 1);
+        assert_eq!(intr.read(RvSize::Word, NOTIF_GLOBAL_INTR_R).unwrap(), 0);
+        assert_eq!(meip(&mut pic_regs), 0);
+
+        intr.write(RvSize::Word, NOTIF_INTR_EN_R, 1).unwrap();
+        assert_eq!(intr.read(RvSize::Word, NOTIF_GLOBAL_INTR_R).unwrap(), 1);
+        assert_eq!(meip(&mut pic_regs), 0);
+
+        intr.write(RvSize::Word, GLOBAL_INTR_EN_R, 2).unwrap();
+        assert_eq!(meip(&mut pic_regs), 1 << 2);
+
+        // Write 1 to clear
+        intr.write(RvSize::Word, NOTIF_INTERNAL_INTR_R, 1).unwrap();
+        assert_eq!(intr.read(RvSize::Word, NOTIF_INTERNAL_INTR_R).unwrap(), 0);
+        assert_eq!(meip(&mut pic_regs), 0);
+
+        intr.write(RvSize::Word, NOTIF_INTR_TRIG_R, 1).unwrap();
+        assert_eq!(intr.read(RvSize::Word, NOTIF_INTR_TRIG_R).unwrap(), 0);
+        assert_eq!(intr.read(RvSize::Word, NOTIF_INTERNAL_INTR_R).unwrap(), 1);
+        assert_eq!(meip(&mut pic_regs), 1 << 2);
+    }
+}
@@ -23,6 +23,7 @@ mod hash_sha512;
 mod helpers;
 mod hmac_sha384;
 mod iccm;
+mod intr_block;
 mod key_vault;
 mod mailbox;
 mod root_bus;
@@ -38,6 +39,7 @@ pub use hash_sha256::HashSha256;
 pub use hash_sha512::HashSha512;
 pub use hmac_sha384::HmacSha384;
 pub use iccm::Iccm;
+pub use intr_block::IntrBlock;
 pub use key_vault::KeyUsage;
 pub use key_vault::KeyVault;
 pub use mailbox::{MailboxExternal, MailboxInternal, MailboxRam};
@@ -11,12 +11,14 @@ Abstract:
     File contains MAILBOX implementation
 
 --*/
+use crate::IntrBlock;
 use smlang::statemachine;
 
 use caliptra_emu_bus::{Bus, BusMmio, Ram};
 use caliptra_emu_bus::{BusError, ReadOnlyRegister, ReadWriteRegister, WriteOnlyRegister};
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
+use caliptra_registers::soc_ifc::regs::NotifIntrTWriteVal;
 use std::{cell::RefCell, rc::Rc};
 use tock_registers::interfaces::Writeable;
 use tock_registers::{register_bitfields, LocalRegisterCopy};
@@ -131,9 +133,9 @@ pub struct MailboxInternal {
 /// Mailbox Peripheral
 
 impl MailboxInternal {
-    pub fn new(ram: MailboxRam) -> Self {
+    pub fn new(ram: MailboxRam, soc_ifc_intr: IntrBlock) -> Self {
         Self {
-            regs: Rc::new(RefCell::new(MailboxRegs::new(ram))),
+            regs: Rc::new(RefCell::new(MailboxRegs::new(ram, soc_ifc_intr))),
         }
     }
 
@@ -229,6 +231,9 @@ pub struct MailboxRegs {
     state_machine: StateMachine<Context>,
 
     pub requester: MailboxRequester,
+
+    /// SoC interface interrupt block, notified when a command is available
+    soc_ifc_intr: IntrBlock,
 }
 
 impl MailboxRegs {
@@ -244,7 +249,7 @@ impl MailboxRegs {
     const UNLOCK_VAL: RvData = 0x0;
 
     /// Create a new instance of Mailbox registers
-    pub fn new(ram: MailboxRam) -> Self {
+    pub fn new(ram: MailboxRam, soc_ifc_intr: IntrBlock) -> Self {
         Self {
             lock: ReadOnlyRegister::new(Self::LOCK_VAL),
             user: ReadOnlyRegister::new(Self::USER_VAL),
@@ -257,6 +262,7 @@ impl MailboxRegs {
             _unlock: ReadWriteRegister::new(Self::UNLOCK_VAL),
             state_machine: StateMachine::new(Context::new(ram)),
             requester: MailboxRequester::Caliptra,
+            soc_ifc_intr,
         }
     }
     pub fn set_request(&mut self, requester: MailboxRequester) {
@@ -349,15 +355,26 @@ impl MailboxRegs {
             }
         };
 
-        let _ = self.state_machine.process_event(event);
+        self.process_handover_event(event);
         self.execute.reg.set(val);
         Ok(())
     }
 
+    /// Send an event that may hand the mailbox over to the uC, raising the
+    /// command available interrupt if it does
+    fn process_handover_event(&mut self, event: Events) {
+        let was_exec_uc = matches!(self.state_machine.state(), States::ExecUc);
+        let _ = self.state_machine.process_event(event);
+        if !was_exec_uc && matches!(self.state_machine.state(), States::ExecUc) {
+            self.soc_ifc_intr
+                .set_notif(NotifIntrTWriteVal::from(0).notif_cmd_avail_sts(true).into());
+        }
+    }
+
     // Todo: Implement write status callback fn
     pub fn write_status(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
         // Send event to state machine.
-        let _ = self.state_machine.process_event(Events::SetStatus);
+        self.process_handover_event(Events::SetStatus);
 
         let val = LocalRegisterCopy::<u32, Status::Register>::new(val);
         self.state_machine
@@ -614,10 +631,13 @@ impl Fifo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::intr_block::new_soc_ifc_intr;
+
+    const OFFSET_NOTIF_INTERNAL_INTR: RvAddr = 0x18;
 
     pub fn get_mailbox() -> MailboxInternal {
         // Acquire lock
-        MailboxInternal::new(MailboxRam::new())
+        MailboxInternal::new(MailboxRam::new(), new_soc_ifc_intr())
     }
 
     #[test]
@@ -684,7 +704,7 @@ mod tests {
 
     #[test]
     fn test_soc_to_caliptra_lock() {
-        let mut caliptra = MailboxInternal::new(MailboxRam::new());
+        let mut caliptra = MailboxInternal::new(MailboxRam::new(), new_soc_ifc_intr());
         let mut soc = caliptra.as_external();
         let soc_regs = soc.regs();
 
@@ -701,7 +721,8 @@ mod tests {
     fn test_send_receive() {
         let request_to_send: [u32; 4] = [0x1111_1111, 0x2222_2222, 0x3333_3333, 0x4444_4444];
 
-        let mut caliptra = MailboxInternal::new(MailboxRam::new());
+        let mut soc_ifc_intr = new_soc_ifc_intr();
+        let mut caliptra = MailboxInternal::new(MailboxRam::new(), soc_ifc_intr.clone());
         let mut soc = caliptra.as_external();
         let soc_regs = soc.regs();
         let uc_regs = caliptra.regs();
@@ -743,6 +764,14 @@ mod tests {
             States::ExecUc
         ));
 
+        // The command available interrupt is raised
+        assert_eq!(
+            soc_ifc_intr
+                .read(RvSize::Word, OFFSET_NOTIF_INTERNAL_INTR)
+                .unwrap(),
+            1
+        );
+
         assert_eq!(
             u32::from(uc_regs.status().read()),
             (Status::STATUS::DATA_READY + Status::MBOX_FSM_PS::MBOX_EXECUTE_UC).value
@@ -16,10 +16,11 @@ use crate::{
     helpers::words_from_bytes_be,
     iccm::Iccm,
     soc_reg::{DebugManufService, SocRegistersExternal},
-    AsymEcc384, Csrng, Doe, EmuCtrl, HashSha256, HashSha512, HmacSha384, KeyVault, MailboxExternal,
-    MailboxInternal, MailboxRam, Sha512Accelerator, SocRegistersInternal, Uart,
+    AsymEcc384, Csrng, Doe, EmuCtrl, HashSha256, HashSha512, HmacSha384, IntrBlock, KeyVault,
+    MailboxExternal, MailboxInternal, MailboxRam, Sha512Accelerator, SocRegistersInternal, Uart,
 };
 use caliptra_emu_bus::{Clock, Ram, Rom};
+use caliptra_emu_cpu::{Pic, PicMmioRegisters};
 use caliptra_emu_derive::Bus;
 use caliptra_hw_model_types::{EtrngResponse, RandomEtrngResponses, RandomNibbles, SecurityState};
 use std::path::PathBuf;
@@ -252,7 +253,7 @@ pub struct CaliptraRootBus {
     #[peripheral(offset = 0x1000_8000, mask = 0x0000_7fff)]
     pub ecc384: AsymEcc384,
 
-    #[peripheral(offset = 0x1001_0000, mask = 0x0000_07ff)]
+    #[peripheral(offset = 0x1001_0000, mask = 0x0000_0fff)]
     pub hmac: HmacSha384,
 
     #[peripheral(offset = 0x1001_8000, mask = 0x0000_7fff)]
@@ -290,6 +291,12 @@ pub struct CaliptraRootBus {
 
     #[peripheral(offset = 0x5000_0000, mask = 0x0fff_ffff)]
     pub dccm: Ram,
+
+    #[peripheral(offset = 0x6000_0000, mask = 0x0000_7fff)]
+    pub pic_regs: PicMmioRegisters,
+
+    /// Interrupt controller the peripheral interrupt lines are wired to
+    pub pic: Pic,
 }
 
 impl CaliptraRootBus {
@@ -297,30 +304,68 @@ impl CaliptraRootBus {
     pub const ICCM_SIZE: usize = 128 * 1024;
     pub const DCCM_SIZE: usize = 128 * 1024;
 
+    // External interrupt ids of the peripherals, as wired in caliptra_top
+    pub const ECC_ERROR_INT: u8 = 3;
+    pub const ECC_NOTIF_INT: u8 = 4;
+    pub const HMAC_ERROR_INT: u8 = 5;
+    pub const HMAC_NOTIF_INT: u8 = 6;
+    pub const SHA512_ERROR_INT: u8 = 9;
+    pub const SHA512_NOTIF_INT: u8 = 10;
+    pub const SHA256_ERROR_INT: u8 = 11;
+    pub const SHA256_NOTIF_INT: u8 = 12;
+    pub const SOC_IFC_ERROR_INT: u8 = 19;
+    pub const SOC_IFC_NOTIF_INT: u8 = 20;
+
     pub fn new(clock: &Clock, mut args: CaliptraRootBusArgs) -> Self {
+        let pic = Pic::new();
         let mut key_vault = KeyVault::new();
+        let soc_ifc_intr = IntrBlock::new(
+            pic.register_irq(Self::SOC_IFC_ERROR_INT),
+            pic.register_irq(Self::SOC_IFC_NOTIF_INT),
+        );
         let mailbox_ram = MailboxRam::new();
-        let mailbox = MailboxInternal::new(mailbox_ram.clone());
+        let mailbox = MailboxInternal::new(mailbox_ram.clone(), soc_ifc_intr.clone());
         let rom = Rom::new(std::mem::take(&mut args.rom));
         let iccm = Iccm::new(clock);
         let itrng_nibbles = args.itrng_nibbles.take();
-        let soc_reg = SocRegistersInternal::new(clock, mailbox.clone(), iccm.clone(), args);
+        let soc_reg =
+            SocRegistersInternal::new(clock, mailbox.clone(), iccm.clone(), soc_ifc_intr, args);
         if !soc_reg.is_debug_locked() {
             // When debug is possible, the key-vault is initialized with a debug value...
             // This is necessary to match the behavior of the RTL.
             key_vault.clear_keys_with_debug_values(false);
         }
 
-        let sha512 = HashSha512::new(clock, key_vault.clone());
+        let sha512 = HashSha512::new(
+            clock,
+            key_vault.clone(),
+            pic.register_irq(Self::SHA512_ERROR_INT),
+            pic.register_irq(Self::SHA512_NOTIF_INT),
+        );
 
         Self {
             rom,
             doe: Doe::new(clock, key_vault.clone(), soc_reg.clone()),
-            ecc384: AsymEcc384::new(clock, key_vault.clone(), sha512.clone()),
-            hmac: HmacSha384::new(clock, key_vault.clone()),
+            ecc384: AsymEcc384::new(
+                clock,
+                key_vault.clone(),
+                sha512.clone(),
+                pic.register_irq(Self::ECC_ERROR_INT),
+                pic.register_irq(Self::ECC_NOTIF_INT),
+            ),
+            hmac: HmacSha384::new(
+                clock,
+                key_vault.clone(),
+                pic.register_irq(Self::HMAC_ERROR_INT),
+                pic.register_irq(Self::HMAC_NOTIF_INT),
+            ),
             key_vault: key_vault.clone(),
             sha512,
-            sha256: HashSha256::new(clock),
+            sha256: HashSha256::new(
+                clock,
+                pic.register_irq(Self::SHA256_ERROR_INT),
+                pic.register_irq(Self::SHA256_NOTIF_INT),
+            ),
             iccm,
             dccm: Ram::new(vec![0; Self::DCCM_SIZE]),
             uart: Uart::new(),
@@ -330,6 +375,8 @@ impl CaliptraRootBus {
             mailbox,
             sha512_acc: Sha512Accelerator::new(clock, mailbox_ram),
             csrng: Csrng::new(itrng_nibbles.unwrap()),
+            pic_regs: pic.mmio_regs(),
+            pic,
         }
     }
 
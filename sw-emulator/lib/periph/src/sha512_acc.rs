@@ -300,8 +300,10 @@ impl Sha512AcceleratorRegs {
     ///
     /// * `BusError` - Exception with cause `BusError::StoreAccessFault` or `BusError::StoreAddrMisaligned`
     pub fn on_write_dlen(&mut self, size: RvSize, dlen: RvData) -> Result<(), BusError> {
-        // Writes have to be Word aligned
-        if size != RvSize::Word || dlen > (MAX_MAILBOX_CAPACITY_BYTES as RvData) {
+        // Writes have to be Word aligned. The length is checked against the
+        // mailbox size when a mailbox digest is executed, as streamed data
+        // can be any length.
+        if size != RvSize::Word {
             Err(BusError::StoreAccessFault)?
         }
 
@@ -351,7 +353,12 @@ impl Sha512AcceleratorRegs {
 
         if self.execute.reg.read(Execute::EXECUTE) == 1 {
             let mode = self.mode.reg.read(ShaMode::MODE);
-            if mode == ShaMode::MODE::SHA512_ACC_MODE_MBOX_384.value {
+            if mode == ShaMode::MODE::SHA512_ACC_MODE_MBOX_384.value
+                || mode == ShaMode::MODE::SHA512_ACC_MODE_SHA_MBOX_512.value
+            {
+                if self.dlen.reg.get() > (MAX_MAILBOX_CAPACITY_BYTES as RvData) {
+                    Err(BusError::StoreAccessFault)?
+                }
                 self.compute_mbox_hash();
 
                 // Schedule a future call to poll() complete the operation.
@@ -437,7 +444,14 @@ impl Sha512AcceleratorRegs {
         block_arr[totalbytes - 16..].copy_from_slice(&len.to_be_bytes());
         block_arr.to_big_endian();
 
-        let mut sha = Sha512::new(Sha512Mode::Sha384);
+        let sha_mode = if self.mode.reg.read(ShaMode::MODE)
+            == ShaMode::MODE::SHA512_ACC_MODE_SHA_MBOX_512.value
+        {
+            Sha512Mode::Sha512
+        } else {
+            Sha512Mode::Sha384
+        };
+        let mut sha = Sha512::new(sha_mode);
         for block_count in 0..totalblocks {
             sha.update(array_ref![
                 block_arr,
@@ -625,10 +639,20 @@ mod tests {
     const OFFSET_MODE: RvAddr = 0x08;
     const OFFSET_START_ADDRESS: RvAddr = 0x0c;
     const OFFSET_DLEN: RvAddr = 0x10;
+    const OFFSET_DATAIN: RvAddr = 0x14;
     const OFFSET_EXECUTE: RvAddr = 0x18;
     const OFFSET_STATUS: RvAddr = 0x1c;
 
     fn test_sha_accelerator(data: &[u8], expected: &[u8], start_address: usize) {
+        test_sha_accelerator_mode(
+            ShaMode::MODE::SHA512_ACC_MODE_MBOX_384.value,
+            data,
+            expected,
+            start_address,
+        );
+    }
+
+    fn test_sha_accelerator_mode(mode: u32, data: &[u8], expected: &[u8], start_address: usize) {
         // Write to the mailbox.
         let mut mb_ram = MailboxRam::new();
         if !data.is_empty() {
@@ -665,13 +689,12 @@ mod tests {
         assert_eq!(lock, 1);
 
         // Set the mode.
-        let mode = InMemoryRegister::<u32, ShaMode::Register>::new(0);
-        mode.write(
-            ShaMode::MODE.val(ShaMode::MODE::SHA512_ACC_MODE_MBOX_384.value)
-                + ShaMode::ENDIAN_TOGGLE.val(1),
-        );
+        let mode_reg = InMemoryRegister::<u32, ShaMode::Register>::new(0);
+        mode_reg.write(ShaMode::MODE.val(mode) + ShaMode::ENDIAN_TOGGLE.val(1));
         assert_eq!(
-            sha_accl.write(RvSize::Word, OFFSET_MODE, mode.get()).ok(),
+            sha_accl
+                .write(RvSize::Word, OFFSET_MODE, mode_reg.get())
+                .ok(),
             Some(())
         );
 
@@ -726,7 +749,7 @@ mod tests {
         assert_eq!(sha_accl.write(RvSize::Word, OFFSET_LOCK, 1).ok(), Some(()));
 
         hash.to_little_endian();
-        assert_eq!(&hash[..SHA384_HASH_SIZE], expected);
+        assert_eq!(&hash[..expected.len()], expected);
     }
 
     #[test]
@@ -825,6 +848,71 @@ mod tests {
         test_sha_accelerator(&data, &expected, 0);
     }
 
+    #[test]
+    fn test_accelerator_sha512_mbox() {
+        let data = "abc".as_bytes();
+        let expected: [u8; SHA512_HASH_SIZE] = [
+            0xDD, 0xAF, 0x35, 0xA1, 0x93, 0x61, 0x7A, 0xBA, 0xCC, 0x41, 0x73, 0x49, 0xAE, 0x20,
+            0x41, 0x31, 0x12, 0xE6, 0xFA, 0x4E, 0x89, 0xA9, 0x7E, 0xA2, 0x0A, 0x9E, 0xEE, 0xE6,
+            0x4B, 0x55, 0xD3, 0x9A, 0x21, 0x92, 0x99, 0x2A, 0x27, 0x4F, 0xC1, 0xA8, 0x36, 0xBA,
+            0x3C, 0x23, 0xA3, 0xFE, 0xEB, 0xBD, 0x45, 0x4D, 0x44, 0x23, 0x64, 0x3C, 0xE8, 0x0E,
+            0x2A, 0x9A, 0xC9, 0x4F, 0xA5, 0x4C, 0xA4, 0x9F,
+        ];
+        test_sha_accelerator_mode(
+            ShaMode::MODE::SHA512_ACC_MODE_SHA_MBOX_512.value,
+            data,
+            &expected,
+            4,
+        );
+    }
+
+    #[test]
+    fn test_accelerator_sha384_stream() {
+        let expected: [u8; SHA384_HASH_SIZE] = [
+            0xCB, 0x00, 0x75, 0x3F, 0x45, 0xA3, 0x5E, 0x8B, 0xB5, 0xA0, 0x3D, 0x69, 0x9A, 0xC6,
+            0x50, 0x07, 0x27, 0x2C, 0x32, 0xAB, 0x0E, 0xDE, 0xD1, 0x63, 0x1A, 0x8B, 0x60, 0x5A,
+            0x43, 0xFF, 0x5B, 0xED, 0x80, 0x86, 0x07, 0x2B, 0xA1, 0xE7, 0xCC, 0x23, 0x58, 0xBA,
+            0xEC, 0xA1, 0x34, 0xC8, 0x25, 0xA7,
+        ];
+
+        let clock = Clock::new();
+        let mut sha_accl = Sha512Accelerator::new(&clock, MailboxRam::new());
+        // Unlock the initial state and acquire the lock.
+        sha_accl.write(RvSize::Word, OFFSET_LOCK, 1).unwrap();
+        assert_eq!(sha_accl.read(RvSize::Word, OFFSET_LOCK).unwrap(), 0);
+
+        // Set the mode before the length, as it restarts the stream.
+        let mode = InMemoryRegister::<u32, ShaMode::Register>::new(0);
+        mode.write(ShaMode::MODE.val(ShaMode::MODE::SHA512_ACC_MODE_SHA_STREAM_384.value));
+        sha_accl
+            .write(RvSize::Word, OFFSET_MODE, mode.get())
+            .unwrap();
+        sha_accl.write(RvSize::Word, OFFSET_DLEN, 3).unwrap();
+
+        // Stream "abc"; the unused byte of the last DWORD is ignored.
+        sha_accl
+            .write(RvSize::Word, OFFSET_DATAIN, u32::from_be_bytes(*b"abc\0"))
+            .unwrap();
+
+        let execute = InMemoryRegister::<u32, Execute::Register>::new(0);
+        execute.write(Execute::EXECUTE.val(1));
+        sha_accl
+            .write(RvSize::Word, OFFSET_EXECUTE, execute.get())
+            .unwrap();
+
+        let status = InMemoryRegister::<u32, Status::Register>::new(
+            sha_accl.read(RvSize::Word, OFFSET_STATUS).unwrap(),
+        );
+        assert!(status.is_set(Status::VALID));
+
+        let mut hash: [u8; SHA512_HASH_SIZE] = [0; SHA512_HASH_SIZE];
+        sha_accl.regs.borrow().copy_hash(&mut hash);
+        hash.to_little_endian();
+        assert_eq!(&hash[..SHA384_HASH_SIZE], expected);
+
+        assert_eq!(sha_accl.write(RvSize::Word, OFFSET_LOCK, 1).ok(), Some(()));
+    }
+
     #[test]
     fn test_sm_lock() {
         let clock = Clock::new();
@@ -14,7 +14,7 @@ Abstract:
 
 use crate::helpers::{bytes_from_words_be, words_from_bytes_be};
 use crate::root_bus::ReadyForFwCbArgs;
-use crate::{CaliptraRootBusArgs, Iccm, MailboxInternal};
+use crate::{CaliptraRootBusArgs, Iccm, IntrBlock, MailboxInternal};
 use caliptra_emu_bus::BusError::{LoadAccessFault, StoreAccessFault};
 use caliptra_emu_bus::{
     ActionHandle, Bus, BusError, Clock, ReadOnlyRegister, ReadWriteRegister, Register, Timer,
@@ -23,7 +23,7 @@ use caliptra_emu_bus::{
 use caliptra_emu_derive::Bus;
 use caliptra_emu_types::{RvAddr, RvData, RvSize};
 use caliptra_hw_model_types::EtrngResponse;
-use caliptra_registers::soc_ifc::regs::CptraHwConfigReadVal;
+use caliptra_registers::soc_ifc::regs::{CptraHwConfigReadVal, ErrorIntrTWriteVal};
 use caliptra_registers::soc_ifc_trng::regs::{CptraTrngStatusReadVal, CptraTrngStatusWriteVal};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -103,6 +103,7 @@ mod constants {
     pub const INTERNAL_FW_UPDATE_RESET_START: u32 = 0x624;
     pub const INTERNAL_FW_UPDATE_RESET_WAIT_CYCLES_START: u32 = 0x628;
     pub const INTERNAL_NMI_VECTOR_START: u32 = 0x62c;
+    pub const ERROR_INTERNAL_INTR_R_START: u32 = 0x814;
 }
 use constants::*;
 
@@ -201,19 +202,6 @@ register_bitfields! [
         LMS_VERIFY OFFSET(0) NUMBITS(1) [],
         RSVD OFFSET(1) NUMBITS(31) [],
     ],
-
-    /// ErrorIntrT
-    ErrorIntrT [
-        ERROR_INTERNAL_STS OFFSET(0) NUMBITS(1) [],
-        ERROR_INV_DEV_STS OFFSET(1) NUMBITS(1) [],
-        ERROR_CMD_FAIL_STS OFFSET(2) NUMBITS(1) [],
-        ERROR_BAD_FUSE_STS OFFSET(3) NUMBITS(1) [],
-        ERROR_ICCM_BLOCKED_STS OFFSET(4) NUMBITS(1) [],
-        ERROR_MBOX_ECC_UNC_STS OFFSET(5) NUMBITS(1) [],
-        ERROR_WDT_TIMER1_TIMEOUT_STS OFFSET(6) NUMBITS(1) [],
-        ERROR_WDT_TIMER2_TIMEOUT_STS OFFSET(7) NUMBITS(1) [],
-        RSVD OFFSET(8) NUMBITS(24) [],
-    ]
 ];
 
 /// SOC Register peripheral
@@ -226,12 +214,12 @@ pub struct SocRegistersInternal {
 const CALIPTRA_REG_START_ADDR: u32 = 0x00;
 
 /// Caliptra Register End Address
-const CALIPTRA_REG_END_ADDR: u32 = 0x81c;
+const CALIPTRA_REG_END_ADDR: u32 = 0x820;
 
 /// Caliptra Fuse start address
 const FUSE_START_ADDR: u32 = 0x200;
 /// Caliptra Fuse end address
-const FUSE_END_ADDR: u32 = 0x340;
+const FUSE_END_ADDR: u32 = 0x34c;
 
 impl SocRegistersInternal {
     /// Create an instance of SOC register peripheral
@@ -239,11 +227,12 @@ impl SocRegistersInternal {
         clock: &Clock,
         mailbox: MailboxInternal,
         iccm: Iccm,
+        intr: IntrBlock,
         args: CaliptraRootBusArgs,
     ) -> Self {
         Self {
             regs: Rc::new(RefCell::new(SocRegistersImpl::new(
-                clock, mailbox, iccm, args,
+                clock, mailbox, iccm, intr, args,
             ))),
         }
     }
@@ -546,6 +535,9 @@ struct SocRegistersImpl {
     #[register(offset = 0x344)]
     fuse_lms_revocation: u32,
 
+    #[register(offset = 0x34c)]
+    fuse_fw_recovery_retry_count: u32,
+
     /// INTERNAL_OBF_KEY Register
     internal_obf_key: [u32; 8],
 
@@ -565,29 +557,9 @@ struct SocRegistersImpl {
     #[register(offset = 0x062c, write_fn = on_write_internal_nmi_vector)]
     internal_nmi_vector: ReadWriteRegister<u32>,
 
-    /// GLOBAL_INTR_EN_R Register
-    #[register(offset = 0x0800)]
-    global_intr_en_r: ReadWriteRegister<u32>,
-
-    /// ERROR_INTR_EN_R Register
-    #[register(offset = 0x0804)]
-    error_intr_en_r: ReadWriteRegister<u32>,
-
-    /// NOTIF_INTR_EN_R Register
-    #[register(offset = 0x0808)]
-    notif_intr_en_r: ReadWriteRegister<u32>,
-
-    /// ERROR_GLOBAL_INTR_R Register
-    #[register(offset = 0x080c)]
-    error_global_intr_r: ReadWriteRegister<u32>,
-
-    /// NOTIF_GLOBAL_INTR_R Register
-    #[register(offset = 0x0810)]
-    notif_global_intr_r: ReadWriteRegister<u32>,
-
-    /// ERROR_INTERNAL_INTR_R Register
-    #[register(offset = 0x0814)]
-    error_internal_intr_r: ReadWriteRegister<u32, ErrorIntrT::Register>,
+    /// Interrupt register block
+    #[peripheral(offset = 0x0800, mask = 0x00ff)]
+    intr: IntrBlock,
 
     /// Mailbox
     mailbox: MailboxInternal,
@@ -655,6 +627,7 @@ impl SocRegistersImpl {
         clock: &Clock,
         mailbox: MailboxInternal,
         iccm: Iccm,
+        intr: IntrBlock,
         mut args: CaliptraRootBusArgs,
     ) -> Self {
         let flow_status = InMemoryRegister::<u32, FlowStatus::Register>::new(0);
@@ -702,17 +675,13 @@ impl SocRegistersImpl {
             fuse_life_cycle: Default::default(),
             fuse_lms_verify: ReadWriteRegister::new(0),
             fuse_lms_revocation: Default::default(),
+            fuse_fw_recovery_retry_count: Default::default(),
             internal_obf_key: args.cptra_obf_key,
             internal_iccm_lock: ReadWriteRegister::new(0),
             internal_fw_update_reset: ReadWriteRegister::new(0),
             internal_fw_update_reset_wait_cycles: ReadWriteRegister::new(5),
             internal_nmi_vector: ReadWriteRegister::new(0),
-            global_intr_en_r: ReadWriteRegister::new(0),
-            error_intr_en_r: ReadWriteRegister::new(0),
-            notif_intr_en_r: ReadWriteRegister::new(0),
-            error_global_intr_r: ReadWriteRegister::new(0),
-            notif_global_intr_r: ReadWriteRegister::new(0),
-            error_internal_intr_r: ReadWriteRegister::new(0),
+            intr,
             mailbox,
             iccm,
             timer: Timer::new(clock),
@@ -1067,18 +1036,22 @@ impl SocRegistersImpl {
 
         if self.timer.fired(&mut self.op_wdt_timer1_expired_action) {
             self.cptra_wdt_status.reg.modify(WdtStatus::T1_TIMEOUT::SET);
-            self.error_internal_intr_r
-                .reg
-                .modify(ErrorIntrT::ERROR_WDT_TIMER1_TIMEOUT_STS::SET);
+            self.intr.set_error(
+                ErrorIntrTWriteVal::from(0)
+                    .error_wdt_timer1_timeout_sts(true)
+                    .into(),
+            );
 
             // If WDT2 is disabled, schedule a callback on it's expiry.
             if !self.cptra_wdt_timer2_en.reg.is_set(WdtEnable::TIMER_EN) {
                 self.cptra_wdt_status
                     .reg
                     .modify(WdtStatus::T2_TIMEOUT::CLEAR);
-                self.error_internal_intr_r
-                    .reg
-                    .modify(ErrorIntrT::ERROR_WDT_TIMER2_TIMEOUT_STS::CLEAR);
+                self.intr.clear_error(
+                    ErrorIntrTWriteVal::from(0)
+                        .error_wdt_timer2_timeout_sts(true)
+                        .into(),
+                );
 
                 let timer_period: u64 = (self.cptra_wdt_timer2_timeout_period[1] as u64) << 32
                     | self.cptra_wdt_timer2_timeout_period[0] as u64;
@@ -1093,9 +1066,11 @@ impl SocRegistersImpl {
             // If WDT2 was not scheduled due to WDT1 expiry (i.e WDT2 is disabled), schedule an NMI.
             // Else, do nothing.
             if self.cptra_wdt_timer2_en.reg.is_set(WdtEnable::TIMER_EN) {
-                self.error_internal_intr_r
-                    .reg
-                    .modify(ErrorIntrT::ERROR_WDT_TIMER2_TIMEOUT_STS::SET);
+                self.intr.set_error(
+                    ErrorIntrTWriteVal::from(0)
+                        .error_wdt_timer2_timeout_sts(true)
+                        .into(),
+                );
                 return;
             }
 
@@ -1121,6 +1096,8 @@ impl SocRegistersImpl {
             .reg
             .write(ResetReason::WARM_RESET::SET);
 
+        self.intr.warm_reset();
+
         self.reset_common();
     }
 
@@ -1141,7 +1118,7 @@ impl SocRegistersImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{root_bus::TbServicesCb, MailboxRam};
+    use crate::{intr_block::new_soc_ifc_intr, root_bus::TbServicesCb, MailboxRam};
     use std::{
         fs::File,
         io::{Read, Write},
@@ -1240,13 +1217,14 @@ mod tests {
         ];
         let clock = Clock::new();
         let mailbox_ram = MailboxRam::new();
-        let mut mailbox = MailboxInternal::new(mailbox_ram);
+        let intr = new_soc_ifc_intr();
+        let mut mailbox = MailboxInternal::new(mailbox_ram, intr.clone());
         let mut log_dir = PathBuf::new();
         log_dir.push("/tmp");
         let args = CaliptraRootBusArgs::default();
         let args = CaliptraRootBusArgs { log_dir, ..args };
         let mut soc_reg: SocRegistersInternal =
-            SocRegistersInternal::new(&clock, mailbox.clone(), Iccm::new(&clock), args);
+            SocRegistersInternal::new(&clock, mailbox.clone(), Iccm::new(&clock), intr, args);
 
         soc_reg
             .write(RvSize::Word, CPTRA_DBG_MANUF_SERVICE_REG_START, 1)
@@ -1302,13 +1280,14 @@ mod tests {
         ];
         let clock = Clock::new();
         let mailbox_ram = MailboxRam::new();
-        let mut mailbox = MailboxInternal::new(mailbox_ram);
+        let intr = new_soc_ifc_intr();
+        let mut mailbox = MailboxInternal::new(mailbox_ram, intr.clone());
         let mut log_dir = PathBuf::new();
         log_dir.push("/tmp");
         let args = CaliptraRootBusArgs::default();
         let args = CaliptraRootBusArgs { log_dir, ..args };
         let mut soc_reg: SocRegistersInternal =
-            SocRegistersInternal::new(&clock, mailbox.clone(), Iccm::new(&clock), args);
+            SocRegistersInternal::new(&clock, mailbox.clone(), Iccm::new(&clock), intr, args);
         soc_reg
             .write(RvSize::Word, CPTRA_DBG_MANUF_SERVICE_REG_START, 2)
             .unwrap();
@@ -1362,13 +1341,14 @@ mod tests {
 
         let clock = Clock::new();
         let mailbox_ram = MailboxRam::new();
-        let mailbox = MailboxInternal::new(mailbox_ram);
+        let intr = new_soc_ifc_intr();
+        let mailbox = MailboxInternal::new(mailbox_ram, intr.clone());
         let args = CaliptraRootBusArgs {
             tb_services_cb: TbServicesCb::new(move |ch| output2.borrow_mut().push(ch)),
             ..Default::default()
         };
         let mut soc_reg: SocRegistersInternal =
-            SocRegistersInternal::new(&clock, mailbox, Iccm::new(&clock), args);
+            SocRegistersInternal::new(&clock, mailbox, Iccm::new(&clock), intr, args);
 
         let _ = soc_reg.write(RvSize::Word, CPTRA_GENERIC_OUTPUT_WIRES_START, b'h'.into());
 
@@ -1383,10 +1363,12 @@ mod tests {
     fn test_secrets_when_debug_not_locked() {
         use caliptra_hw_model_types::SecurityState;
         let clock = Clock::new();
+        let intr = new_soc_ifc_intr();
         let soc = SocRegistersInternal::new(
             &clock,
-            MailboxInternal::new(MailboxRam::new()),
+            MailboxInternal::new(MailboxRam::new(), intr.clone()),
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs {
                 security_state: *SecurityState::default().set_debug_locked(false),
                 ..CaliptraRootBusArgs::default()
@@ -1402,10 +1384,12 @@ mod tests {
     fn test_secrets_when_debug_locked() {
         use caliptra_hw_model_types::SecurityState;
         let clock = Clock::new();
+        let intr = new_soc_ifc_intr();
         let soc = SocRegistersInternal::new(
             &clock,
-            MailboxInternal::new(MailboxRam::new()),
+            MailboxInternal::new(MailboxRam::new(), intr.clone()),
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs {
                 security_state: *SecurityState::default().set_debug_locked(true),
                 ..CaliptraRootBusArgs::default()
@@ -1430,12 +1414,14 @@ mod tests {
     fn test_wdt() {
         let clock = Clock::new();
         let mailbox_ram = MailboxRam::new();
-        let mailbox = MailboxInternal::new(mailbox_ram);
+        let intr = new_soc_ifc_intr();
+        let mailbox = MailboxInternal::new(mailbox_ram, intr.clone());
 
         let mut soc_reg: SocRegistersInternal = SocRegistersInternal::new(
             &clock,
             mailbox,
             Iccm::new(&clock),
+            intr,
             CaliptraRootBusArgs::default(),
         );
         soc_reg
@@ -1465,6 +1451,14 @@ mod tests {
             clock.increment_and_process_timer_actions(1, &mut soc_reg);
         }
 
+        // Only the timer1 timeout is reported when timer2 is disabled
+        assert_eq!(
+            soc_reg
+                .read(RvSize::Word, ERROR_INTERNAL_INTR_R_START)
+                .unwrap(),
+            1 << 6
+        );
+
         assert_eq!(
             next_action(&clock),
             Some(TimerAction::Nmi {
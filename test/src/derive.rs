@@ -238,7 +238,23 @@ impl LDevId {
             swap_word_bytes(&doe_output.field_entropy[0..8]).as_bytes(),
         ));
         swap_word_bytes_inplace(&mut cdi);
+        Self::from_cdi(cdi)
+    }
+
+    /// Derive the identity the fake ROM derives from `seed`, the value of
+    /// bits 18-29 of CPTRA_DBG_MANUF_SERVICE_REG.
+    pub fn derive_from_fake_rom_seed(seed: u32) -> Self {
+        let mut key = [0u32; 12];
+        key[0] = seed;
+        let mut cdi: [u32; 12] = transmute!(hmac384(
+            swap_word_bytes(&key).as_bytes(),
+            b"fake_ldevid_cdi",
+        ));
+        swap_word_bytes_inplace(&mut cdi);
+        Self::from_cdi(cdi)
+    }
 
+    fn from_cdi(cdi: [u32; 12]) -> Self {
         let mut priv_key_seed: [u32; 12] = transmute!(hmac384_kdf(
             swap_word_bytes(&cdi).as_bytes(),
             b"ldevid_keygen",
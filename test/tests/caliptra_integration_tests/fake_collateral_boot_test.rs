@@ -7,10 +7,10 @@ use caliptra_builder::{
 use caliptra_common::mailbox_api::{
     CommandId, GetLdevCertResp, MailboxReqHeader, MailboxRespHeader, TestGetFmcAliasCertResp,
 };
-use caliptra_hw_model::{BootParams, HwModel, InitParams, SecurityState};
+use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams, SecurityState};
 use caliptra_hw_model_types::Fuses;
 use caliptra_test::{
-    derive::{DoeInput, DoeOutput, LDevId},
+    derive::{DoeInput, DoeOutput, FmcAliasKey, LDevId, Pcr0, Pcr0Input},
     swap_word_bytes, swap_word_bytes_inplace,
     x509::{DiceFwid, DiceTcbInfo},
 };
@@ -24,6 +24,11 @@ const FMC_CANNED_DIGEST: [u32; 12] = [
     0xf2db4f76, 0x5b671453, 0xa256de5d, 0xa490d7c8,
 ];
 
+// Bit 30 of CPTRA_DBG_MANUF_SERVICE_REG makes the fake ROM derive the device
+// identity from the seed in bits 18-29.
+const DBG_MANUF_FAKE_ROM_DERIVE_IDENTITY: u32 = 0x1 << 30;
+const DBG_MANUF_FAKE_ROM_SEED_SHIFT: u32 = 18;
+
 #[track_caller]
 fn assert_output_contains(haystack: &str, needle: &str) {
     assert!(
@@ -103,18 +108,12 @@ fn fake_boot_test() {
     );
 
     let payload = MailboxReqHeader {
-        chksum: caliptra_common::checksum::calc_checksum(
-            u32::from(CommandId::TEST_ONLY_GET_LDEV_CERT),
-            &[],
-        ),
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_LDEV_CERT), &[]),
     };
 
     // Execute the command
     let resp = hw
-        .mailbox_execute(
-            u32::from(CommandId::TEST_ONLY_GET_LDEV_CERT),
-            payload.as_bytes(),
-        )
+        .mailbox_execute(u32::from(CommandId::GET_LDEV_CERT), payload.as_bytes())
         .unwrap()
         .unwrap();
 
@@ -287,3 +286,143 @@ fn fake_boot_test() {
 
     // TODO: Validate the rest of the fmc_alias certificate fields
 }
+
+fn get_ldev_cert(hw: &mut DefaultHwModel) -> openssl::x509::X509 {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_LDEV_CERT), &[]),
+    };
+    let resp = hw
+        .mailbox_execute(u32::from(CommandId::GET_LDEV_CERT), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    assert!(resp.len() <= std::mem::size_of::<GetLdevCertResp>());
+    let mut ldev_cert_resp = GetLdevCertResp::default();
+    ldev_cert_resp.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
+    openssl::x509::X509::from_der(&ldev_cert_resp.data[..(ldev_cert_resp.data_size as usize)])
+        .unwrap()
+}
+
+fn get_fmc_alias_cert(hw: &mut DefaultHwModel) -> openssl::x509::X509 {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(
+            u32::from(CommandId::TEST_ONLY_GET_FMC_ALIAS_CERT),
+            &[],
+        ),
+    };
+    let resp = hw
+        .mailbox_execute(
+            u32::from(CommandId::TEST_ONLY_GET_FMC_ALIAS_CERT),
+            payload.as_bytes(),
+        )
+        .unwrap()
+        .unwrap();
+    assert!(resp.len() <= std::mem::size_of::<TestGetFmcAliasCertResp>());
+    let mut fmc_alias_cert_resp = TestGetFmcAliasCertResp::default();
+    fmc_alias_cert_resp.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
+    openssl::x509::X509::from_der(
+        &fmc_alias_cert_resp.data[..(fmc_alias_cert_resp.data_size as usize)],
+    )
+    .unwrap()
+}
+
+#[test]
+fn fake_boot_derived_identity_test() {
+    let security_state = *SecurityState::default().set_debug_locked(true);
+
+    let rom = caliptra_builder::build_firmware_rom(&ROM_FAKE_WITH_UART).unwrap();
+    let image = caliptra_builder::build_and_sign_image(
+        &FMC_FAKE_WITH_UART,
+        &APP_WITH_UART,
+        ImageOptions {
+            fmc_min_svn: 5,
+            fmc_svn: 9,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let vendor_pk_hash =
+        bytes_to_be_words_48(&sha384(image.manifest.preamble.vendor_pub_keys.as_bytes()));
+    let owner_pk_hash =
+        bytes_to_be_words_48(&sha384(image.manifest.preamble.owner_pub_keys.as_bytes()));
+
+    let mut ldev_pubkeys: Vec<openssl::pkey::PKey<openssl::pkey::Public>> = vec![];
+    let mut fmc_alias_pubkeys: Vec<openssl::pkey::PKey<openssl::pkey::Public>> = vec![];
+    for seed in [0, 1, 0xfff] {
+        let mut hw = caliptra_hw_model::new(BootParams {
+            init_params: InitParams {
+                rom: &rom,
+                security_state,
+                ..Default::default()
+            },
+            fuses: Fuses {
+                key_manifest_pk_hash: vendor_pk_hash,
+                owner_pk_hash,
+                fmc_key_manifest_svn: 0b1111111,
+                lms_verify: true,
+                ..Default::default()
+            },
+            fw_image: Some(&image.to_bytes().unwrap()),
+            initial_dbg_manuf_service_reg: DBG_MANUF_FAKE_ROM_DERIVE_IDENTITY
+                | (seed << DBG_MANUF_FAKE_ROM_SEED_SHIFT),
+            ..Default::default()
+        })
+        .unwrap();
+
+        hw.step_until_output_contains("Caliptra RT listening for mailbox commands...\n")
+            .unwrap();
+        let output = hw.output().take(usize::MAX);
+        assert_output_contains(&output, "[fake-rom-cold-reset]");
+        assert_output_contains(&output, "[ldev] ++");
+        assert_output_contains(&output, "[afmc] ++");
+        // The IDEVID layer is skipped
+        assert!(!output.contains("[idev]"));
+
+        let ldev_cert = get_ldev_cert(&mut hw);
+        let ldev_pubkey = ldev_cert.public_key().unwrap();
+        let fmc_alias_cert = get_fmc_alias_cert(&mut hw);
+        let fmc_alias_pubkey = fmc_alias_cert.public_key().unwrap();
+
+        let expected_ldevid_key = LDevId::derive_from_fake_rom_seed(seed);
+        assert!(expected_ldevid_key
+            .derive_public_key()
+            .public_eq(&ldev_pubkey));
+        assert!(
+            ldev_cert.verify(&ldev_pubkey).unwrap(),
+            "ldev cert failed to validate with ldev pubkey"
+        );
+
+        let expected_fmc_alias_key = FmcAliasKey::derive(
+            &Pcr0::derive(&Pcr0Input {
+                security_state,
+                fuse_anti_rollback_disable: false,
+                vendor_pub_key_hash: vendor_pk_hash,
+                owner_pub_key_hash: owner_pk_hash,
+                owner_pub_key_hash_from_fuses: true,
+                ecc_vendor_pub_key_index: image.manifest.preamble.vendor_ecc_pub_key_idx,
+                fmc_digest: image.manifest.fmc.digest,
+                fmc_svn: image.manifest.fmc.svn,
+                // This is from the SVN in the fuses (7 bits set)
+                fmc_fuse_svn: 7,
+                lms_vendor_pub_key_index: image.manifest.header.vendor_lms_pub_key_idx,
+                rom_verify_config: 1, // RomVerifyConfig::EcdsaAndLms
+            }),
+            &expected_ldevid_key,
+        );
+        assert!(expected_fmc_alias_key
+            .derive_public_key()
+            .public_eq(&fmc_alias_pubkey));
+
+        assert!(
+            fmc_alias_cert.verify(&ldev_pubkey).unwrap(),
+            "fmc_alias cert failed to validate with ldev pubkey"
+        );
+
+        // Every seed must yield a distinct identity
+        assert!(!ldev_pubkeys.iter().any(|k| k.public_eq(&ldev_pubkey)));
+        assert!(!fmc_alias_pubkeys
+            .iter()
+            .any(|k| k.public_eq(&fmc_alias_pubkey)));
+        ldev_pubkeys.push(ldev_pubkey);
+        fmc_alias_pubkeys.push(fmc_alias_pubkey);
+    }
+}
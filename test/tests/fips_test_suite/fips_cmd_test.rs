@@ -5,7 +5,8 @@ use caliptra_builder::firmware::ROM_FAKE_WITH_UART;
 use caliptra_builder::{firmware, ImageOptions};
 use caliptra_common::fips::FipsVersionCmd;
 use caliptra_common::mailbox_api::{
-    CommandId, FipsVersionResp, MailboxReqHeader, MailboxRespHeader,
+    CommandId, FipsVersionResp, MailboxReqHeader, MailboxRespHeader, SelfTestGetResultsResp,
+    SelfTestResult, SelfTestStartReq,
 };
 use caliptra_drivers::CaliptraError;
 use caliptra_hw_model::{BootParams, HwModel, InitParams, ModelError, SecurityState};
@@ -23,6 +24,46 @@ fn bytes_to_be_words_48(buf: &[u8; 48]) -> [u32; 12] {
     result
 }
 
+/// Waits for the self test to complete and returns its per-KAT results.
+fn get_self_test_results<T: HwModel>(hw: &mut T) -> SelfTestGetResultsResp {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(
+            u32::from(CommandId::SELF_TEST_GET_RESULTS),
+            &[],
+        ),
+    };
+
+    loop {
+        // Get self test results
+        match hw.mailbox_execute(
+            u32::from(CommandId::SELF_TEST_GET_RESULTS),
+            payload.as_bytes(),
+        ) {
+            Ok(Some(resp_bytes)) => {
+                let mut resp = SelfTestGetResultsResp::default();
+                resp.as_bytes_mut()[..resp_bytes.len()].copy_from_slice(&resp_bytes);
+                // Verify checksum and FIPS status
+                assert!(caliptra_common::checksum::verify_checksum(
+                    resp.hdr.chksum,
+                    0x0,
+                    &resp_bytes[core::mem::size_of_val(&resp.hdr.chksum)..],
+                ));
+                if resp.hdr.fips_status == MailboxRespHeader::FIPS_STATUS_APPROVED {
+                    return resp;
+                }
+            }
+            _ => {
+                // Give FW time to run
+                let mut cycle_count = 10000;
+                hw.step_until(|_| -> bool {
+                    cycle_count -= 1;
+                    cycle_count == 0
+                });
+            }
+        }
+    }
+}
+
 fn test_fips_cmds<T: HwModel>(hw: &mut T, fmc_version: u32, app_version: u32) {
     // VERSION
     let payload = MailboxReqHeader {
@@ -81,40 +122,20 @@ fn test_fips_cmds<T: HwModel>(hw: &mut T, fmc_version: u32, app_version: u32) {
         .unwrap_err();
 
     // SELF_TEST_GET_RESULTS
-    let payload = MailboxReqHeader {
-        chksum: caliptra_common::checksum::calc_checksum(
-            u32::from(CommandId::SELF_TEST_GET_RESULTS),
-            &[],
-        ),
-    };
-
-    loop {
-        // Get self test results
-        match hw.mailbox_execute(
-            u32::from(CommandId::SELF_TEST_GET_RESULTS),
-            payload.as_bytes(),
-        ) {
-            Ok(Some(resp)) => {
-                let resp = MailboxRespHeader::read_from(resp.as_slice()).unwrap();
-                // Verify checksum and FIPS status
-                assert!(caliptra_common::checksum::verify_checksum(
-                    resp.chksum,
-                    0x0,
-                    &resp.as_bytes()[core::mem::size_of_val(&resp.chksum)..],
-                ));
-                if resp.fips_status == MailboxRespHeader::FIPS_STATUS_APPROVED {
-                    break;
-                }
-            }
-            _ => {
-                // Give FW time to run
-                let mut cycle_count = 10000;
-                hw.step_until(|_| -> bool {
-                    cycle_count -= 1;
-                    cycle_count == 0
-                });
-            }
+    let resp = get_self_test_results(hw);
+    let results = resp.results();
+    assert_eq!(results.len(), 11);
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result.kat_id, i as u32);
+        // The DRBG KAT only applies to the internal TRNG.
+        if result.kat_id == SelfTestStartReq::KAT_DRBG
+            && result.status == SelfTestResult::STATUS_SKIPPED
+        {
+            continue;
         }
+        assert_eq!(result.status, SelfTestResult::STATUS_PASSED, "KAT {i}");
+        assert_eq!(result.error, 0, "KAT {i}");
+        assert!(result.cycles > 0, "KAT {i}");
     }
 
     // SHUTDOWN
@@ -233,6 +254,51 @@ pub fn fips_cmd_test_rt() {
     test_fips_cmds(&mut hw, FMC_VERSION, APP_VERSION);
 }
 
+#[test]
+pub fn fips_cmd_test_rt_kat_selection() {
+    let mut hw = fips_test_init_to_rt(None);
+
+    while !hw.soc_ifc().cptra_flow_status().read().ready_for_runtime() {
+        hw.step();
+    }
+
+    let mut payload = SelfTestStartReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        kats: (1 << SelfTestStartReq::KAT_HMAC384_KDF) | (1 << SelfTestStartReq::KAT_ECC384_PCT),
+    };
+    payload.hdr.chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::SELF_TEST_START),
+        &payload.as_bytes()[core::mem::size_of_val(&payload.hdr.chksum)..],
+    );
+    hw.mailbox_execute(u32::from(CommandId::SELF_TEST_START), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+
+    let resp = get_self_test_results(&mut hw);
+    for result in resp.results() {
+        let expected = match result.kat_id {
+            SelfTestStartReq::KAT_HMAC384_KDF | SelfTestStartReq::KAT_ECC384_PCT => {
+                SelfTestResult::STATUS_PASSED
+            }
+            _ => SelfTestResult::STATUS_NOT_RUN,
+        };
+        assert_eq!(result.status, expected, "KAT {}", result.kat_id);
+    }
+
+    // Unknown KATs are rejected.
+    payload.kats = 1 << 31;
+    payload.hdr.chksum = caliptra_common::checksum::calc_checksum(
+        u32::from(CommandId::SELF_TEST_START),
+        &payload.as_bytes()[core::mem::size_of_val(&payload.hdr.chksum)..],
+    );
+    assert_eq!(
+        hw.mailbox_execute(u32::from(CommandId::SELF_TEST_START), payload.as_bytes()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS.into()
+        ))
+    );
+}
+
 #[test]
 pub fn fips_cmd_bad_params_rom() {
     let mut hw = fips_test_init_to_rom(None);